@@ -0,0 +1,86 @@
+//! Benchmarking setup for the Axelar gas service pallet
+
+// ----------------------------------------------------------------------------
+// Module imports and re-exports
+// ----------------------------------------------------------------------------
+
+use super::*;
+use frame_benchmarking::{account, benchmarks, impl_benchmark_test_suite, whitelisted_caller};
+use frame_support::traits::EnsureOrigin;
+use frame_system::RawOrigin as SystemOrigin;
+use sp_core::H256;
+use sp_runtime::traits::Bounded;
+
+// ----------------------------------------------------------------------------
+// Helpers
+// ----------------------------------------------------------------------------
+
+/// Gives `who` enough balance to pay for gas.
+fn fund<T: Config>(who: &T::AccountId) {
+    T::Currency::make_free_balance_be(who, BalanceOf::<T>::max_value() / 2u32.into());
+}
+
+/// Gas amount used in the benchmarks, well above any existential deposit.
+fn amount<T: Config>() -> BalanceOf<T> {
+    T::Currency::minimum_balance() * 100u32.into()
+}
+
+// ----------------------------------------------------------------------------
+// Benchmarks
+// ----------------------------------------------------------------------------
+
+benchmarks! {
+    pay_native_gas_for_contract_call {
+        let caller: T::AccountId = whitelisted_caller();
+        fund::<T>(&caller);
+        let payload_hash = H256::repeat_byte(1);
+    }: _(
+        SystemOrigin::Signed(caller.clone()),
+        1,
+        String::from("ethereum"),
+        String::from("0x5f927395213ee6b95de97bddcb1b2b1c0f16844d"),
+        payload_hash,
+        amount::<T>(),
+        caller
+    )
+    verify {
+        assert_eq!(GasPaid::<T>::get(1).unwrap().amount, amount::<T>());
+    }
+
+    // Adding to an existing payment, which has its payload hash checked
+    add_native_gas {
+        let caller: T::AccountId = whitelisted_caller();
+        fund::<T>(&caller);
+        let payload_hash = H256::repeat_byte(1);
+        GasPaid::<T>::insert(1, GasPayment { payload_hash, amount: amount::<T>() });
+    }: _(SystemOrigin::Signed(caller.clone()), 1, H256::repeat_byte(2), 1, payload_hash, amount::<T>(), caller)
+    verify {
+        assert_eq!(GasPaid::<T>::get(1).unwrap().amount, amount::<T>() * 2u32.into());
+    }
+
+    refund {
+        let receiver: T::AccountId = account("receiver", 0, 0);
+        let payload_hash = H256::repeat_byte(1);
+        fund::<T>(&Pallet::<T>::account_id());
+        GasPaid::<T>::insert(1, GasPayment { payload_hash, amount: amount::<T>() * 2u32.into() });
+        let origin = T::GasCollectorOrigin::successful_origin();
+    }: _<T::RuntimeOrigin>(origin, 1, H256::repeat_byte(2), 1, payload_hash, receiver, amount::<T>())
+    verify {
+        assert_eq!(GasPaid::<T>::get(1).unwrap().amount, amount::<T>());
+    }
+
+    collect_fees {
+        let receiver: T::AccountId = account("receiver", 0, 0);
+        fund::<T>(&Pallet::<T>::account_id());
+        let origin = T::GasCollectorOrigin::successful_origin();
+    }: _<T::RuntimeOrigin>(origin, receiver.clone(), amount::<T>())
+    verify {
+        assert_eq!(T::Currency::free_balance(&receiver), amount::<T>());
+    }
+}
+
+impl_benchmark_test_suite!(
+    Pallet,
+    crate::mock::ExtBuilder::default().build(),
+    crate::mock::Runtime
+);
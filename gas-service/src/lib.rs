@@ -0,0 +1,360 @@
+//! # Axelar Gas Service
+//!
+//! Companion pallet of the Axelar gateway mirroring Axelar's `AxelarGasService` contract.
+//!
+//! Axelar relayers only deliver a `ContractCall` to its destination chain once its
+//! destination gas has been prepaid. Senders pay it in the native currency, either along
+//! the call with `pay_native_gas_for_contract_call` or afterwards with `add_native_gas`.
+//! Payments are kept per contract call, identified by the `nonce` of its gateway
+//! `ContractCall` event, along with its `payload_hash`. Unspent gas is refunded by Axelar
+//! through the gateway.
+
+// Ensure we're `no_std` when compiling for WebAssembly.
+#![cfg_attr(not(feature = "std"), no_std)]
+
+// ----------------------------------------------------------------------------
+// Module imports and re-exports
+// ----------------------------------------------------------------------------
+
+// Pallet extrinsics weight information (placeholders until benchmarked)
+pub mod weights;
+
+// Re-export pallet components in crate namespace (for runtime construction)
+pub use crate::weights::WeightInfo;
+use codec::{Decode, Encode, MaxEncodedLen};
+use frame_support::traits::Currency;
+pub use pallet::*;
+use scale_info::TypeInfo;
+use sp_core::H256;
+use sp_runtime::RuntimeDebug;
+
+#[cfg(test)]
+mod mock;
+#[cfg(test)]
+mod tests;
+
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
+
+/// Balance of the currency gas is paid with
+pub type BalanceOf<T> =
+    <<T as Config>::Currency as Currency<<T as frame_system::Config>::AccountId>>::Balance;
+
+/// Gas paid for a contract call and not refunded yet
+#[derive(Clone, PartialEq, Eq, Encode, Decode, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct GasPayment<Balance> {
+    /// Payload hash of the contract call, which every payment and refund must match
+    pub payload_hash: H256,
+    pub amount: Balance,
+}
+
+// ----------------------------------------------------------------------------
+// Pallet module
+// ----------------------------------------------------------------------------
+
+// Axelar gas service pallet module
+//
+// The name of the pallet is provided by `construct_runtime` and is used as
+// the unique identifier for the pallet's storage. It is not defined in the
+// pallet itself.
+#[frame_support::pallet]
+pub mod pallet {
+    use frame_support::pallet_prelude::*;
+    use frame_support::traits::ExistenceRequirement;
+    use frame_support::PalletId;
+    use frame_system::pallet_prelude::*;
+    use sp_runtime::traits::{AccountIdConversion, Saturating, Zero};
+
+    use super::*;
+
+    // Gas service pallet type declaration.
+    //
+    // This structure is a placeholder for traits and functions implementation
+    // for the pallet.
+    #[pallet::pallet]
+    #[pallet::generate_store(pub(super) trait Store)]
+    pub struct Pallet<T>(_);
+
+    // ------------------------------------------------------------------------
+    // Pallet configuration
+    // ------------------------------------------------------------------------
+
+    /// Gas service pallet's configuration trait.
+    #[pallet::config]
+    pub trait Config: frame_system::Config {
+        /// The overarching event type.
+        type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
+
+        /// Currency gas is paid with
+        type Currency: Currency<Self::AccountId>;
+
+        /// Identifier of the account holding the paid gas
+        #[pallet::constant]
+        type PalletId: Get<PalletId>;
+
+        /// Origin allowed to refund gas and collect the fees, usually
+        /// `axelar_cgp::EnsureGateway` so only calls approved by Axelar can
+        type GasCollectorOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+
+        /// Maximum length of a destination chain name
+        #[pallet::constant]
+        type MaxChainNameLen: Get<u32>;
+
+        /// Maximum length of a destination contract address
+        #[pallet::constant]
+        type MaxAddressLen: Get<u32>;
+
+        /// Weight information for extrinsics in this pallet
+        type WeightInfo: WeightInfo;
+    }
+
+    // ------------------------------------------------------------------------
+    // Pallet events
+    // ------------------------------------------------------------------------
+
+    // Named and shaped after the `AxelarGasService` events, which Axelar relayers watch
+    #[pallet::event]
+    #[pallet::generate_deposit(pub(super) fn deposit_event)]
+    pub enum Event<T: Config> {
+        NativeGasPaidForContractCall {
+            nonce: u64,
+            source_address: T::AccountId,
+            destination_chain: String,
+            destination_address: String,
+            payload_hash: H256,
+            gas_fee_amount: BalanceOf<T>,
+            refund_address: T::AccountId,
+        },
+        NativeGasAdded {
+            nonce: u64,
+            tx_hash: H256,
+            log_index: u32,
+            payload_hash: H256,
+            gas_fee_amount: BalanceOf<T>,
+            refund_address: T::AccountId,
+        },
+        Refunded {
+            nonce: u64,
+            tx_hash: H256,
+            log_index: u32,
+            payload_hash: H256,
+            receiver: T::AccountId,
+            amount: BalanceOf<T>,
+        },
+        FeesCollected {
+            receiver: T::AccountId,
+            amount: BalanceOf<T>,
+        },
+    }
+
+    // ------------------------------------------------------------------------
+    // Pallet storage
+    // ------------------------------------------------------------------------
+
+    #[pallet::storage]
+    #[pallet::getter(fn gas_paid)]
+    pub(super) type GasPaid<T: Config> = StorageMap<
+        _,
+        Twox64Concat,
+        // Nonce of the gateway `ContractCall` event of the contract call
+        u64,
+        // Gas paid and not refunded yet
+        GasPayment<BalanceOf<T>>,
+        OptionQuery,
+    >;
+
+    // ------------------------------------------------------------------------
+    // Pallet errors
+    // ------------------------------------------------------------------------
+
+    #[pallet::error]
+    pub enum Error<T> {
+        ZeroAmount,
+        ChainNameTooLong,
+        AddressTooLong,
+        RefundExceedsPaidGas,
+        PayloadHashMismatch,
+    }
+
+    // ------------------------------------------------------------------------
+    // Pallet dispatchable functions
+    // ------------------------------------------------------------------------
+
+    #[pallet::call]
+    impl<T: Config> Pallet<T> {
+        /// Pays the destination gas of the contract call sent with `nonce` and `payload_hash`,
+        /// to be sent along with it
+        #[pallet::call_index(0)]
+        #[pallet::weight(<T as pallet::Config>::WeightInfo::pay_native_gas_for_contract_call())]
+        pub fn pay_native_gas_for_contract_call(
+            origin: OriginFor<T>,
+            nonce: u64,
+            destination_chain: String,
+            destination_address: String,
+            payload_hash: H256,
+            #[pallet::compact] amount: BalanceOf<T>,
+            refund_address: T::AccountId,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+
+            ensure!(
+                destination_chain.len() <= T::MaxChainNameLen::get() as usize,
+                Error::<T>::ChainNameTooLong
+            );
+            ensure!(
+                destination_address.len() <= T::MaxAddressLen::get() as usize,
+                Error::<T>::AddressTooLong
+            );
+            Self::pay(&who, nonce, payload_hash, amount)?;
+
+            Self::deposit_event(Event::NativeGasPaidForContractCall {
+                nonce,
+                source_address: who,
+                destination_chain,
+                destination_address,
+                payload_hash,
+                gas_fee_amount: amount,
+                refund_address,
+            });
+
+            Ok(())
+        }
+
+        /// Adds destination gas to the contract call sent with `nonce`, emitted as the event
+        /// `log_index` of the extrinsic `tx_hash`
+        #[pallet::call_index(1)]
+        #[pallet::weight(<T as pallet::Config>::WeightInfo::add_native_gas())]
+        pub fn add_native_gas(
+            origin: OriginFor<T>,
+            nonce: u64,
+            tx_hash: H256,
+            log_index: u32,
+            payload_hash: H256,
+            #[pallet::compact] amount: BalanceOf<T>,
+            refund_address: T::AccountId,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+
+            Self::pay(&who, nonce, payload_hash, amount)?;
+
+            Self::deposit_event(Event::NativeGasAdded {
+                nonce,
+                tx_hash,
+                log_index,
+                payload_hash,
+                gas_fee_amount: amount,
+                refund_address,
+            });
+
+            Ok(())
+        }
+
+        /// Refunds gas paid for the contract call sent with `nonce` and left unspent
+        #[pallet::call_index(2)]
+        #[pallet::weight(<T as pallet::Config>::WeightInfo::refund())]
+        pub fn refund(
+            origin: OriginFor<T>,
+            nonce: u64,
+            tx_hash: H256,
+            log_index: u32,
+            payload_hash: H256,
+            receiver: T::AccountId,
+            #[pallet::compact] amount: BalanceOf<T>,
+        ) -> DispatchResult {
+            T::GasCollectorOrigin::ensure_origin(origin)?;
+
+            ensure!(!amount.is_zero(), Error::<T>::ZeroAmount);
+            let mut payment = GasPaid::<T>::get(nonce).ok_or(Error::<T>::RefundExceedsPaidGas)?;
+            ensure!(
+                payment.payload_hash == payload_hash,
+                Error::<T>::PayloadHashMismatch
+            );
+            ensure!(amount <= payment.amount, Error::<T>::RefundExceedsPaidGas);
+
+            T::Currency::transfer(
+                &Self::account_id(),
+                &receiver,
+                amount,
+                ExistenceRequirement::AllowDeath,
+            )?;
+            payment.amount -= amount;
+            if payment.amount.is_zero() {
+                GasPaid::<T>::remove(nonce);
+            } else {
+                GasPaid::<T>::insert(nonce, payment);
+            }
+
+            Self::deposit_event(Event::Refunded {
+                nonce,
+                tx_hash,
+                log_index,
+                payload_hash,
+                receiver,
+                amount,
+            });
+
+            Ok(())
+        }
+
+        /// Transfers gas spent by Axelar relayers to `receiver`
+        #[pallet::call_index(3)]
+        #[pallet::weight(<T as pallet::Config>::WeightInfo::collect_fees())]
+        pub fn collect_fees(
+            origin: OriginFor<T>,
+            receiver: T::AccountId,
+            #[pallet::compact] amount: BalanceOf<T>,
+        ) -> DispatchResult {
+            T::GasCollectorOrigin::ensure_origin(origin)?;
+
+            ensure!(!amount.is_zero(), Error::<T>::ZeroAmount);
+            T::Currency::transfer(
+                &Self::account_id(),
+                &receiver,
+                amount,
+                ExistenceRequirement::AllowDeath,
+            )?;
+
+            Self::deposit_event(Event::FeesCollected { receiver, amount });
+
+            Ok(())
+        }
+    }
+
+    impl<T: Config> Pallet<T> {
+        /// Account holding the paid gas
+        pub fn account_id() -> T::AccountId {
+            T::PalletId::get().into_account_truncating()
+        }
+
+        /// Moves `amount` from `who` to the gas account, on behalf of the call sent with
+        /// `nonce` and `payload_hash`
+        fn pay(
+            who: &T::AccountId,
+            nonce: u64,
+            payload_hash: H256,
+            amount: BalanceOf<T>,
+        ) -> DispatchResult {
+            ensure!(!amount.is_zero(), Error::<T>::ZeroAmount);
+
+            GasPaid::<T>::try_mutate(nonce, |payment| {
+                let payment = payment.get_or_insert(GasPayment {
+                    payload_hash,
+                    amount: Zero::zero(),
+                });
+                ensure!(
+                    payment.payload_hash == payload_hash,
+                    Error::<T>::PayloadHashMismatch
+                );
+                payment.amount = payment.amount.saturating_add(amount);
+
+                T::Currency::transfer(
+                    who,
+                    &Self::account_id(),
+                    amount,
+                    ExistenceRequirement::KeepAlive,
+                )
+            })
+        }
+    }
+}
+// end of 'pallet' module
@@ -0,0 +1,170 @@
+use axelar_cgp::traits::{LocalCallForwarder, Secp256k1Verifier};
+use axelar_cgp::{AddressFormat, EnsureGateway, ProofVersion};
+use frame_support::{
+    construct_runtime, parameter_types,
+    traits::{ConstU128, ConstU32, ConstU64, Everything, GenesisBuild},
+    PalletId,
+};
+use frame_system::EnsureRoot;
+use sp_core::H256;
+use sp_runtime::{testing::Header, traits::IdentityLookup};
+
+use crate::{self as axelar_gas_service, Config};
+
+pub type AccountId = u64;
+pub type BlockNumber = u64;
+pub type Balance = u128;
+
+impl frame_system::Config for Runtime {
+    type RuntimeOrigin = RuntimeOrigin;
+    type Index = u64;
+    type BlockNumber = BlockNumber;
+    type RuntimeCall = RuntimeCall;
+    type Hash = H256;
+    type Hashing = ::sp_runtime::traits::BlakeTwo256;
+    type AccountId = AccountId;
+    type Lookup = IdentityLookup<Self::AccountId>;
+    type Header = Header;
+    type RuntimeEvent = RuntimeEvent;
+    type BlockHashCount = ConstU64<250>;
+    type BlockWeights = ();
+    type BlockLength = ();
+    type Version = ();
+    type PalletInfo = PalletInfo;
+    type AccountData = pallet_balances::AccountData<Balance>;
+    type OnNewAccount = ();
+    type OnKilledAccount = ();
+    type DbWeight = ();
+    type BaseCallFilter = Everything;
+    type SystemWeightInfo = ();
+    type SS58Prefix = ();
+    type OnSetCode = ();
+    type MaxConsumers = ConstU32<16>;
+}
+
+impl pallet_balances::Config for Runtime {
+    type MaxLocks = ();
+    type MaxReserves = ();
+    type ReserveIdentifier = [u8; 8];
+    type Balance = Balance;
+    type RuntimeEvent = RuntimeEvent;
+    type DustRemoval = ();
+    type ExistentialDeposit = ConstU128<1>;
+    type AccountStore = System;
+    type WeightInfo = ();
+}
+
+parameter_types! {
+    pub const ChainId: u16 = 36;
+    pub const FeeDestination: Option<AccountId> = None;
+    pub const GatewayProofVersion: ProofVersion = ProofVersion::Legacy;
+    pub const DomainSeparator: H256 = H256([0xd0; 32]);
+}
+
+impl axelar_cgp::Config for Runtime {
+    type RuntimeEvent = RuntimeEvent;
+    type RuntimeOrigin = RuntimeOrigin;
+    type RuntimeCall = RuntimeCall;
+    type ChainId = ChainId;
+    type OperatorRetention = ConstU64<{ axelar_cgp::OLD_KEY_RETENTION }>;
+    type ProofVersion = GatewayProofVersion;
+    type DomainSeparator = DomainSeparator;
+    type SignatureVerifier = Secp256k1Verifier;
+    type MaxChainNameLen = ConstU32<32>;
+    type MaxAddressLen = ConstU32<128>;
+    type MaxPayloadLen = ConstU32<{ 16 * 1024 }>;
+    type MaxProofLen = ConstU32<{ 16 * 1024 }>;
+    type MaxInputLen = ConstU32<{ 128 * 1024 }>;
+    type MaxOperators = ConstU32<100>;
+    type MaxBatchSize = ConstU32<100>;
+    type Currency = Balances;
+    type BaseFee = ConstU128<0>;
+    type ByteFee = ConstU128<0>;
+    type FeeDestination = FeeDestination;
+    type OutboundRetention = ConstU64<100>;
+    type ApprovedCallForwarder = LocalCallForwarder;
+    type AdminOrigin = EnsureRoot<AccountId>;
+    type WeightInfo = ();
+}
+
+parameter_types! {
+    pub const GasServicePalletId: PalletId = PalletId(*b"axl/gass");
+}
+
+impl Config for Runtime {
+    type RuntimeEvent = RuntimeEvent;
+    type Currency = Balances;
+    type PalletId = GasServicePalletId;
+    type GasCollectorOrigin = EnsureGateway;
+    type MaxChainNameLen = ConstU32<32>;
+    type MaxAddressLen = ConstU32<128>;
+    type WeightInfo = ();
+}
+
+type UncheckedExtrinsic = frame_system::mocking::MockUncheckedExtrinsic<Runtime>;
+type Block = frame_system::mocking::MockBlock<Runtime>;
+
+construct_runtime!(
+    pub enum Runtime where
+        Block = Block,
+        NodeBlock = Block,
+        UncheckedExtrinsic = UncheckedExtrinsic,
+    {
+        System: frame_system::{Pallet, Call, Config, Storage, Event<T>},
+        Balances: pallet_balances::{Pallet, Call, Storage, Config<T>, Event<T>},
+        AxelarGateway: axelar_cgp::{Pallet, Call, Storage, Config, Origin, Event<T>} = 2,
+        AxelarGasService: axelar_gas_service::{Pallet, Call, Storage, Event<T>} = 3,
+    }
+);
+
+pub const ALICE: AccountId = 1;
+pub const BOB: AccountId = 2;
+
+pub struct ExtBuilder {
+    balances: Vec<(AccountId, Balance)>,
+}
+
+impl Default for ExtBuilder {
+    fn default() -> Self {
+        ExtBuilder {
+            balances: vec![(ALICE, 1_000)],
+        }
+    }
+}
+
+impl ExtBuilder {
+    pub fn build(self) -> sp_io::TestExternalities {
+        let mut t = frame_system::GenesisConfig::default()
+            .build_storage::<Runtime>()
+            .unwrap();
+
+        pallet_balances::GenesisConfig::<Runtime> {
+            balances: self.balances,
+        }
+        .assimilate_storage(&mut t)
+        .unwrap();
+
+        GenesisBuild::<Runtime>::assimilate_storage(
+            &axelar_cgp::GenesisConfig {
+                destination_chains: vec![(String::from("ethereum"), AddressFormat::EvmHex)],
+                ..Default::default()
+            },
+            &mut t,
+        )
+        .unwrap();
+
+        let mut ext = sp_io::TestExternalities::new(t);
+        ext.execute_with(|| System::set_block_number(1));
+        ext
+    }
+}
+
+pub fn event_exists<E: Into<RuntimeEvent>>(e: E) {
+    let actual: Vec<RuntimeEvent> = frame_system::Pallet::<Runtime>::events()
+        .iter()
+        .map(|e| e.event.clone())
+        .collect();
+
+    let e: RuntimeEvent = e.into();
+    assert!(actual.contains(&e));
+}
@@ -0,0 +1,270 @@
+use super::*;
+use frame_support::traits::Currency;
+use frame_support::{assert_noop, assert_ok};
+use mock::*;
+use sp_core::H256;
+use sp_runtime::traits::BadOrigin;
+
+fn pay(nonce: u64, payload_hash: H256, amount: Balance) -> sp_runtime::DispatchResult {
+    AxelarGasService::pay_native_gas_for_contract_call(
+        RuntimeOrigin::signed(ALICE),
+        nonce,
+        String::from("ethereum"),
+        String::from("0x5f927395213ee6b95de97bddcb1b2b1c0f16844d"),
+        payload_hash,
+        amount,
+        ALICE,
+    )
+}
+
+#[test]
+fn pay_native_gas_for_contract_call() {
+    ExtBuilder::default().build().execute_with(|| {
+        let payload_hash = H256::repeat_byte(1);
+
+        assert_ok!(pay(7, payload_hash, 100));
+        event_exists(Event::<Runtime>::NativeGasPaidForContractCall {
+            nonce: 7,
+            source_address: ALICE,
+            destination_chain: String::from("ethereum"),
+            destination_address: String::from("0x5f927395213ee6b95de97bddcb1b2b1c0f16844d"),
+            payload_hash,
+            gas_fee_amount: 100,
+            refund_address: ALICE,
+        });
+        assert_eq!(
+            AxelarGasService::gas_paid(7),
+            Some(GasPayment {
+                payload_hash,
+                amount: 100
+            })
+        );
+        assert_eq!(Balances::free_balance(AxelarGasService::account_id()), 100);
+        assert_eq!(Balances::free_balance(ALICE), 900);
+
+        // Zero amount
+        assert_noop!(pay(7, payload_hash, 0), Error::<Runtime>::ZeroAmount);
+
+        // Another call with the same payload is paid for separately
+        assert_ok!(pay(8, payload_hash, 50));
+        assert_eq!(AxelarGasService::gas_paid(7).unwrap().amount, 100);
+        assert_eq!(AxelarGasService::gas_paid(8).unwrap().amount, 50);
+
+        // A call only has one payload
+        assert_noop!(
+            pay(7, H256::repeat_byte(2), 100),
+            Error::<Runtime>::PayloadHashMismatch
+        );
+
+        // Destination chain name too long
+        assert_noop!(
+            AxelarGasService::pay_native_gas_for_contract_call(
+                RuntimeOrigin::signed(ALICE),
+                7,
+                "a".repeat(33),
+                String::from("0x5f927395213ee6b95de97bddcb1b2b1c0f16844d"),
+                payload_hash,
+                100,
+                ALICE,
+            ),
+            Error::<Runtime>::ChainNameTooLong,
+        );
+
+        // Destination address too long
+        assert_noop!(
+            AxelarGasService::pay_native_gas_for_contract_call(
+                RuntimeOrigin::signed(ALICE),
+                7,
+                String::from("ethereum"),
+                "a".repeat(129),
+                payload_hash,
+                100,
+                ALICE,
+            ),
+            Error::<Runtime>::AddressTooLong,
+        );
+
+        // The payer must stay alive
+        assert!(pay(7, payload_hash, 850).is_err());
+    });
+}
+
+#[test]
+fn add_native_gas() {
+    ExtBuilder::default().build().execute_with(|| {
+        let payload_hash = H256::repeat_byte(1);
+        let tx_hash = H256::repeat_byte(2);
+
+        assert_ok!(pay(7, payload_hash, 100));
+        assert_ok!(AxelarGasService::add_native_gas(
+            RuntimeOrigin::signed(ALICE),
+            7,
+            tx_hash,
+            3,
+            payload_hash,
+            50,
+            BOB,
+        ));
+        event_exists(Event::<Runtime>::NativeGasAdded {
+            nonce: 7,
+            tx_hash,
+            log_index: 3,
+            payload_hash,
+            gas_fee_amount: 50,
+            refund_address: BOB,
+        });
+        assert_eq!(AxelarGasService::gas_paid(7).unwrap().amount, 150);
+        assert_eq!(Balances::free_balance(AxelarGasService::account_id()), 150);
+
+        assert_noop!(
+            AxelarGasService::add_native_gas(
+                RuntimeOrigin::signed(ALICE),
+                7,
+                tx_hash,
+                3,
+                H256::repeat_byte(3),
+                50,
+                BOB,
+            ),
+            Error::<Runtime>::PayloadHashMismatch,
+        );
+        assert_noop!(
+            AxelarGasService::add_native_gas(
+                RuntimeOrigin::signed(ALICE),
+                7,
+                tx_hash,
+                3,
+                payload_hash,
+                0,
+                BOB,
+            ),
+            Error::<Runtime>::ZeroAmount,
+        );
+    });
+}
+
+#[test]
+fn refund() {
+    ExtBuilder::default().build().execute_with(|| {
+        let payload_hash = H256::repeat_byte(1);
+        let tx_hash = H256::repeat_byte(2);
+        assert_ok!(pay(7, payload_hash, 100));
+        // Same payload, different call
+        assert_ok!(pay(8, payload_hash, 100));
+
+        // Only the gateway can refund
+        assert_noop!(
+            AxelarGasService::refund(
+                RuntimeOrigin::signed(ALICE),
+                7,
+                tx_hash,
+                0,
+                payload_hash,
+                BOB,
+                40
+            ),
+            BadOrigin,
+        );
+
+        assert_ok!(AxelarGasService::refund(
+            axelar_cgp::RawOrigin::Bridge.into(),
+            7,
+            tx_hash,
+            0,
+            payload_hash,
+            BOB,
+            40,
+        ));
+        event_exists(Event::<Runtime>::Refunded {
+            nonce: 7,
+            tx_hash,
+            log_index: 0,
+            payload_hash,
+            receiver: BOB,
+            amount: 40,
+        });
+        assert_eq!(AxelarGasService::gas_paid(7).unwrap().amount, 60);
+        assert_eq!(AxelarGasService::gas_paid(8).unwrap().amount, 100);
+        assert_eq!(Balances::free_balance(BOB), 40);
+
+        // Can't refund more than what is left for the call, whatever other calls paid
+        assert_noop!(
+            AxelarGasService::refund(
+                axelar_cgp::RawOrigin::Bridge.into(),
+                7,
+                tx_hash,
+                0,
+                payload_hash,
+                BOB,
+                61,
+            ),
+            Error::<Runtime>::RefundExceedsPaidGas,
+        );
+        assert_noop!(
+            AxelarGasService::refund(
+                axelar_cgp::RawOrigin::Bridge.into(),
+                7,
+                tx_hash,
+                0,
+                H256::repeat_byte(3),
+                BOB,
+                10,
+            ),
+            Error::<Runtime>::PayloadHashMismatch,
+        );
+        assert_noop!(
+            AxelarGasService::refund(
+                axelar_cgp::RawOrigin::Bridge.into(),
+                9,
+                tx_hash,
+                0,
+                payload_hash,
+                BOB,
+                10,
+            ),
+            Error::<Runtime>::RefundExceedsPaidGas,
+        );
+
+        // Refunding the rest clears the payment
+        assert_ok!(AxelarGasService::refund(
+            axelar_cgp::RawOrigin::Bridge.into(),
+            7,
+            tx_hash,
+            0,
+            payload_hash,
+            BOB,
+            60,
+        ));
+        assert!(!GasPaid::<Runtime>::contains_key(7));
+        assert!(GasPaid::<Runtime>::contains_key(8));
+        assert_eq!(Balances::free_balance(BOB), 100);
+    });
+}
+
+#[test]
+fn collect_fees() {
+    ExtBuilder::default().build().execute_with(|| {
+        let _ = Balances::deposit_creating(&AxelarGasService::account_id(), 100);
+
+        assert_noop!(
+            AxelarGasService::collect_fees(RuntimeOrigin::signed(ALICE), BOB, 100),
+            BadOrigin,
+        );
+        assert_noop!(
+            AxelarGasService::collect_fees(axelar_cgp::RawOrigin::Bridge.into(), BOB, 0),
+            Error::<Runtime>::ZeroAmount,
+        );
+
+        assert_ok!(AxelarGasService::collect_fees(
+            axelar_cgp::RawOrigin::Bridge.into(),
+            BOB,
+            100
+        ));
+        event_exists(Event::<Runtime>::FeesCollected {
+            receiver: BOB,
+            amount: 100,
+        });
+        assert_eq!(Balances::free_balance(BOB), 100);
+        assert_eq!(Balances::free_balance(AxelarGasService::account_id()), 0);
+    });
+}
@@ -0,0 +1,70 @@
+//! Weights for axelar_gas_service
+//!
+//! These are round placeholders, not measurements: no runtime including the pallet has been
+//! benchmarked yet. They only charge the storage accesses of each extrinsic and an order of
+//! magnitude of its computation. Runtimes should replace them with the output of
+//! `benchmark pallet` over the benchmarks in [`crate::benchmarking`].
+
+use frame_support::traits::Get;
+use frame_support::weights::{constants::RocksDbWeight, Weight};
+use sp_std::marker::PhantomData;
+
+/// Weight functions needed for axelar_gas_service.
+///
+/// The implementations in this module are round placeholders, see the module docs.
+pub trait WeightInfo {
+    fn pay_native_gas_for_contract_call() -> Weight;
+    fn add_native_gas() -> Weight;
+    fn refund() -> Weight;
+    fn collect_fees() -> Weight;
+}
+
+/// Placeholder weights for axelar_gas_service, with the storage accesses charged at the runtime
+/// `DbWeight`.
+pub struct SubstrateWeight<T>(PhantomData<T>);
+impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
+    fn pay_native_gas_for_contract_call() -> Weight {
+        Weight::from_ref_time(50_000_000)
+            .saturating_add(T::DbWeight::get().reads(3))
+            .saturating_add(T::DbWeight::get().writes(3))
+    }
+    fn add_native_gas() -> Weight {
+        Weight::from_ref_time(50_000_000)
+            .saturating_add(T::DbWeight::get().reads(3))
+            .saturating_add(T::DbWeight::get().writes(3))
+    }
+    fn refund() -> Weight {
+        Weight::from_ref_time(50_000_000)
+            .saturating_add(T::DbWeight::get().reads(3))
+            .saturating_add(T::DbWeight::get().writes(3))
+    }
+    fn collect_fees() -> Weight {
+        Weight::from_ref_time(40_000_000)
+            .saturating_add(T::DbWeight::get().reads(2))
+            .saturating_add(T::DbWeight::get().writes(2))
+    }
+}
+
+// For backwards compatibility and tests
+impl WeightInfo for () {
+    fn pay_native_gas_for_contract_call() -> Weight {
+        Weight::from_ref_time(50_000_000)
+            .saturating_add(RocksDbWeight::get().reads(3))
+            .saturating_add(RocksDbWeight::get().writes(3))
+    }
+    fn add_native_gas() -> Weight {
+        Weight::from_ref_time(50_000_000)
+            .saturating_add(RocksDbWeight::get().reads(3))
+            .saturating_add(RocksDbWeight::get().writes(3))
+    }
+    fn refund() -> Weight {
+        Weight::from_ref_time(50_000_000)
+            .saturating_add(RocksDbWeight::get().reads(3))
+            .saturating_add(RocksDbWeight::get().writes(3))
+    }
+    fn collect_fees() -> Weight {
+        Weight::from_ref_time(40_000_000)
+            .saturating_add(RocksDbWeight::get().reads(2))
+            .saturating_add(RocksDbWeight::get().writes(2))
+    }
+}
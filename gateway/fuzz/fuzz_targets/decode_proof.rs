@@ -0,0 +1,35 @@
+//! Feeds arbitrary bytes to the proof decoders: `cargo fuzz run decode_proof`
+
+#![no_main]
+
+use axelar_cgp::proof::{self, VersionedProof};
+use axelar_cgp::traits::{Ed25519Verifier, Secp256k1Verifier, SignatureVerifier};
+use axelar_cgp::ProofVersion;
+use libfuzzer_sys::fuzz_target;
+
+/// Decoding never panics, and a decoded proof is always well formed
+fn check_decoding<V: SignatureVerifier>(payload: &[u8]) {
+    if let Ok(proof) = proof::decode::<V>(payload) {
+        assert_eq!(proof.operators.len(), proof.weights.len());
+        assert!(proof
+            .signatures
+            .iter()
+            .all(|signature| signature.len() == V::signature_len()));
+    }
+    if let Ok(proof) = proof::decode_weighted_signers::<V>(payload) {
+        assert!(proof
+            .signatures
+            .iter()
+            .all(|signature| signature.len() == V::signature_len()));
+    }
+
+    for version in [ProofVersion::Legacy, ProofVersion::WeightedSigners] {
+        let _ = VersionedProof::decode::<V>(version, payload);
+        let _ = proof::estimate_counts(version, payload);
+    }
+}
+
+fuzz_target!(|payload: &[u8]| {
+    check_decoding::<Secp256k1Verifier>(payload);
+    check_decoding::<Ed25519Verifier>(payload);
+});
@@ -0,0 +1,198 @@
+//! # Axelar CGP RPC
+//!
+//! JSON-RPC methods over the Axelar gateway runtime API, so relayers can query a node
+//! the same way they query the view functions of Axelar's EVM gateway.
+
+// ----------------------------------------------------------------------------
+// Module imports and re-exports
+// ----------------------------------------------------------------------------
+
+use std::{marker::PhantomData, sync::Arc};
+
+pub use axelar_cgp_runtime_api::AxelarGatewayApi as AxelarGatewayRuntimeApi;
+use jsonrpsee::{
+    core::{async_trait, Error as JsonRpseeError, RpcResult},
+    proc_macros::rpc,
+    types::error::{CallError, ErrorObject},
+};
+use serde::{Deserialize, Serialize};
+use sp_api::ProvideRuntimeApi;
+use sp_blockchain::HeaderBackend;
+use sp_core::{Bytes, H160, H256};
+use sp_runtime::{generic::BlockId, traits::Block as BlockT};
+
+#[cfg(test)]
+mod tests;
+
+// ----------------------------------------------------------------------------
+// Types
+// ----------------------------------------------------------------------------
+
+/// Operators set allowed to sign batches at a given block
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CurrentOperators {
+    pub epoch: u64,
+    pub operators_hash: H256,
+}
+
+/// Error codes returned by the gateway RPC methods
+pub enum Error {
+    /// The call to the runtime API failed
+    RuntimeError,
+}
+
+impl From<Error> for i32 {
+    fn from(e: Error) -> i32 {
+        match e {
+            Error::RuntimeError => 1,
+        }
+    }
+}
+
+// ----------------------------------------------------------------------------
+// RPC methods
+// ----------------------------------------------------------------------------
+
+#[rpc(server)]
+pub trait AxelarGatewayApi<BlockHash> {
+    /// Whether the contract call has been approved and not forwarded yet
+    #[method(name = "axelar_isContractCallApproved")]
+    fn is_contract_call_approved(
+        &self,
+        command_id: H256,
+        source_chain: String,
+        source_address: String,
+        contract_address: H160,
+        payload_hash: H256,
+        at: Option<BlockHash>,
+    ) -> RpcResult<bool>;
+
+    /// Whether the command has already been executed by the gateway
+    #[method(name = "axelar_isCommandExecuted")]
+    fn is_command_executed(&self, command_id: H256, at: Option<BlockHash>) -> RpcResult<bool>;
+
+    /// The current operators epoch and hash
+    #[method(name = "axelar_currentOperators")]
+    fn current_operators(&self, at: Option<BlockHash>) -> RpcResult<CurrentOperators>;
+
+    /// The hash the current operators must sign for an `execute` batch with SCALE encoded `calls`
+    #[method(name = "axelar_batchMessageHash")]
+    fn batch_message_hash(
+        &self,
+        chain_id: u32,
+        command_ids: Vec<H256>,
+        commands: Vec<String>,
+        calls: Vec<Bytes>,
+        at: Option<BlockHash>,
+    ) -> RpcResult<H256>;
+}
+
+/// Implementation of the gateway RPC methods, querying the runtime of `client`
+pub struct AxelarGateway<C, Block> {
+    client: Arc<C>,
+    _marker: PhantomData<Block>,
+}
+
+impl<C, Block> AxelarGateway<C, Block> {
+    pub fn new(client: Arc<C>) -> Self {
+        Self {
+            client,
+            _marker: Default::default(),
+        }
+    }
+}
+
+impl<C, Block> AxelarGateway<C, Block>
+where
+    Block: BlockT,
+    C: HeaderBackend<Block>,
+{
+    /// Block to query, defaulting to the best block
+    fn block_id(&self, at: Option<Block::Hash>) -> BlockId<Block> {
+        BlockId::hash(at.unwrap_or_else(|| self.client.info().best_hash))
+    }
+}
+
+fn runtime_error(e: sp_api::ApiError) -> JsonRpseeError {
+    CallError::Custom(ErrorObject::owned(
+        Error::RuntimeError.into(),
+        "Unable to query the gateway state.",
+        Some(e.to_string()),
+    ))
+    .into()
+}
+
+#[async_trait]
+impl<C, Block> AxelarGatewayApiServer<<Block as BlockT>::Hash> for AxelarGateway<C, Block>
+where
+    Block: BlockT,
+    C: ProvideRuntimeApi<Block> + HeaderBackend<Block> + Send + Sync + 'static,
+    C::Api: AxelarGatewayRuntimeApi<Block>,
+{
+    fn is_contract_call_approved(
+        &self,
+        command_id: H256,
+        source_chain: String,
+        source_address: String,
+        contract_address: H160,
+        payload_hash: H256,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<bool> {
+        self.client
+            .runtime_api()
+            .is_contract_call_approved(
+                &self.block_id(at),
+                command_id,
+                source_chain.into_bytes(),
+                source_address.into_bytes(),
+                contract_address,
+                payload_hash,
+            )
+            .map_err(runtime_error)
+    }
+
+    fn is_command_executed(
+        &self,
+        command_id: H256,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<bool> {
+        self.client
+            .runtime_api()
+            .is_command_executed(&self.block_id(at), command_id)
+            .map_err(runtime_error)
+    }
+
+    fn current_operators(
+        &self,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<CurrentOperators> {
+        let api = self.client.runtime_api();
+        let at = self.block_id(at);
+
+        Ok(CurrentOperators {
+            epoch: api.current_epoch(&at).map_err(runtime_error)?,
+            operators_hash: api.current_operators_hash(&at).map_err(runtime_error)?,
+        })
+    }
+
+    fn batch_message_hash(
+        &self,
+        chain_id: u32,
+        command_ids: Vec<H256>,
+        commands: Vec<String>,
+        calls: Vec<Bytes>,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<H256> {
+        self.client
+            .runtime_api()
+            .batch_message_hash(
+                &self.block_id(at),
+                chain_id,
+                command_ids,
+                commands.into_iter().map(String::into_bytes).collect(),
+                calls.into_iter().map(|x| x.0).collect(),
+            )
+            .map_err(runtime_error)
+    }
+}
@@ -0,0 +1,251 @@
+use super::*;
+use axelar_cgp_runtime_api::BatchValidation;
+use codec::Encode;
+use sp_api::ApiRef;
+use sp_blockchain::{BlockStatus, Info};
+use sp_core::keccak_256;
+use sp_runtime::testing::{Block as TestBlock, ExtrinsicWrapper, Header};
+use sp_runtime::traits::NumberFor;
+
+type Block = TestBlock<ExtrinsicWrapper<u64>>;
+
+const BEST_HASH: H256 = H256::repeat_byte(7);
+
+/// Gateway state served by the mocked runtime
+#[derive(Clone, Default)]
+struct MockState {
+    approved_calls: Vec<(H256, Vec<u8>, Vec<u8>, H160, H256)>,
+    executed_commands: Vec<H256>,
+    epoch: u64,
+    operators_hash: H256,
+}
+
+#[derive(Clone, Default)]
+struct MockClient {
+    state: MockState,
+}
+
+struct MockRuntimeApi {
+    state: MockState,
+}
+
+impl ProvideRuntimeApi<Block> for MockClient {
+    type Api = MockRuntimeApi;
+
+    fn runtime_api(&self) -> ApiRef<Self::Api> {
+        MockRuntimeApi {
+            state: self.state.clone(),
+        }
+        .into()
+    }
+}
+
+impl HeaderBackend<Block> for MockClient {
+    fn header(&self, _id: BlockId<Block>) -> sp_blockchain::Result<Option<Header>> {
+        Ok(None)
+    }
+
+    fn info(&self) -> Info<Block> {
+        Info {
+            best_hash: BEST_HASH,
+            best_number: 1,
+            genesis_hash: H256::zero(),
+            finalized_hash: BEST_HASH,
+            finalized_number: 1,
+            finalized_state: None,
+            number_leaves: 1,
+            block_gap: None,
+        }
+    }
+
+    fn status(&self, _id: BlockId<Block>) -> sp_blockchain::Result<BlockStatus> {
+        Ok(BlockStatus::InChain)
+    }
+
+    fn number(&self, _hash: H256) -> sp_blockchain::Result<Option<NumberFor<Block>>> {
+        Ok(Some(1))
+    }
+
+    fn hash(&self, _number: NumberFor<Block>) -> sp_blockchain::Result<Option<H256>> {
+        Ok(Some(BEST_HASH))
+    }
+}
+
+sp_api::mock_impl_runtime_apis! {
+    impl AxelarGatewayRuntimeApi<Block> for MockRuntimeApi {
+        fn is_contract_call_approved(
+            &self,
+            command_id: H256,
+            source_chain: Vec<u8>,
+            source_address: Vec<u8>,
+            contract_address: H160,
+            payload_hash: H256,
+        ) -> bool {
+            self.state.approved_calls.contains(&(
+                command_id,
+                source_chain,
+                source_address,
+                contract_address,
+                payload_hash,
+            ))
+        }
+
+        fn is_command_executed(&self, command_id: H256) -> bool {
+            self.state.executed_commands.contains(&command_id)
+        }
+
+        fn current_epoch(&self) -> u64 {
+            self.state.epoch
+        }
+
+        fn current_operators_hash(&self) -> H256 {
+            self.state.operators_hash
+        }
+
+        fn epoch_for_hash(&self, operators_hash: H256) -> u64 {
+            if operators_hash == self.state.operators_hash {
+                self.state.epoch
+            } else {
+                0
+            }
+        }
+
+        fn is_operators_hash_valid(&self, operators_hash: H256) -> bool {
+            operators_hash == self.state.operators_hash
+        }
+
+        fn batch_message_hash(
+            &self,
+            chain_id: u32,
+            command_ids: Vec<H256>,
+            commands: Vec<Vec<u8>>,
+            calls: Vec<Vec<u8>>,
+        ) -> H256 {
+            H256::from(keccak_256(&(chain_id, command_ids, commands, calls).encode()))
+        }
+
+        fn validate_batch(
+            &self,
+            _proof: Vec<u8>,
+            _chain_id: u32,
+            _command_ids: Vec<H256>,
+            _commands: Vec<Vec<u8>>,
+            _calls: Vec<Vec<u8>>,
+        ) -> BatchValidation {
+            BatchValidation::default()
+        }
+    }
+}
+
+fn rpc(state: MockState) -> AxelarGateway<MockClient, Block> {
+    AxelarGateway::new(Arc::new(MockClient { state }))
+}
+
+#[test]
+fn is_contract_call_approved() {
+    let command_id = H256::repeat_byte(1);
+    let contract_address = H160::repeat_byte(2);
+    let payload_hash = H256::repeat_byte(3);
+    let rpc = rpc(MockState {
+        approved_calls: vec![(
+            command_id,
+            b"ethereum".to_vec(),
+            b"0x5f927395213ee6b95de97bddcb1b2b1c0f16844d".to_vec(),
+            contract_address,
+            payload_hash,
+        )],
+        ..Default::default()
+    });
+
+    assert!(rpc
+        .is_contract_call_approved(
+            command_id,
+            String::from("ethereum"),
+            String::from("0x5f927395213ee6b95de97bddcb1b2b1c0f16844d"),
+            contract_address,
+            payload_hash,
+            None,
+        )
+        .unwrap());
+    assert!(!rpc
+        .is_contract_call_approved(
+            command_id,
+            String::from("polygon"),
+            String::from("0x5f927395213ee6b95de97bddcb1b2b1c0f16844d"),
+            contract_address,
+            payload_hash,
+            Some(BEST_HASH),
+        )
+        .unwrap());
+}
+
+#[test]
+fn is_command_executed() {
+    let command_id = H256::repeat_byte(1);
+    let rpc = rpc(MockState {
+        executed_commands: vec![command_id],
+        ..Default::default()
+    });
+
+    assert!(rpc.is_command_executed(command_id, None).unwrap());
+    assert!(!rpc
+        .is_command_executed(H256::repeat_byte(2), None)
+        .unwrap());
+}
+
+#[test]
+fn current_operators() {
+    let operators_hash = H256::repeat_byte(9);
+    let rpc = rpc(MockState {
+        epoch: 4,
+        operators_hash,
+        ..Default::default()
+    });
+
+    assert_eq!(
+        rpc.current_operators(None).unwrap(),
+        CurrentOperators {
+            epoch: 4,
+            operators_hash,
+        }
+    );
+}
+
+#[test]
+fn batch_message_hash() {
+    let rpc = rpc(MockState::default());
+    let command_ids = vec![H256::repeat_byte(1)];
+
+    assert_eq!(
+        rpc.batch_message_hash(
+            36,
+            command_ids.clone(),
+            vec![String::from("approveContractCall")],
+            vec![Bytes(vec![1, 2, 3])],
+            None,
+        )
+        .unwrap(),
+        H256::from(keccak_256(
+            &(
+                36u32,
+                command_ids,
+                vec![b"approveContractCall".to_vec()],
+                vec![vec![1u8, 2, 3]],
+            )
+                .encode()
+        ))
+    );
+}
+
+#[test]
+fn current_operators_serialization() {
+    let operators = CurrentOperators {
+        epoch: 4,
+        operators_hash: H256::zero(),
+    };
+
+    assert_eq!(
+        serde_json::to_string(&operators).unwrap(),
+        "{\"epoch\":4,\"operatorsHash\":\"0x0000000000000000000000000000000000000000000000000000000000000000\"}"
+    );
+}
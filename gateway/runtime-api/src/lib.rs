@@ -0,0 +1,242 @@
+//! # Axelar CGP Runtime API
+//!
+//! Read-only queries over the Axelar gateway pallet state, meant for relayers and front-ends.
+//!
+//! A runtime exposes it by forwarding every method to the pallet:
+//!
+//! ```ignore
+//! impl axelar_cgp_runtime_api::AxelarGatewayApi<Block> for Runtime {
+//!     fn is_contract_call_approved(
+//!         command_id: H256,
+//!         source_chain: Vec<u8>,
+//!         source_address: Vec<u8>,
+//!         contract_address: H160,
+//!         payload_hash: H256,
+//!     ) -> bool {
+//!         AxelarGateway::is_contract_call_approved(
+//!             command_id,
+//!             &source_chain,
+//!             &source_address,
+//!             contract_address,
+//!             payload_hash,
+//!         )
+//!     }
+//!
+//!     fn is_command_executed(command_id: H256) -> bool {
+//!         AxelarGateway::is_command_executed(command_id)
+//!     }
+//!
+//!     fn current_epoch() -> u64 {
+//!         AxelarGateway::current_epoch()
+//!     }
+//!
+//!     fn current_operators_hash() -> H256 {
+//!         AxelarGateway::current_operators_hash()
+//!     }
+//!
+//!     fn epoch_for_hash(operators_hash: H256) -> u64 {
+//!         AxelarGateway::epoch_for_hash(operators_hash)
+//!     }
+//!
+//!     fn is_operators_hash_valid(operators_hash: H256) -> bool {
+//!         AxelarGateway::is_operators_hash_valid(operators_hash)
+//!     }
+//!
+//!     fn batch_message_hash(
+//!         chain_id: u32,
+//!         command_ids: Vec<H256>,
+//!         commands: Vec<Vec<u8>>,
+//!         calls: Vec<Vec<u8>>,
+//!     ) -> H256 {
+//!         AxelarGateway::batch_message_hash(
+//!             chain_id,
+//!             command_ids,
+//!             commands
+//!                 .into_iter()
+//!                 .map(|x| String::from_utf8_lossy(&x).into_owned())
+//!                 .collect(),
+//!             calls,
+//!         )
+//!     }
+//!
+//!     fn validate_batch(
+//!         proof: Vec<u8>,
+//!         chain_id: u32,
+//!         command_ids: Vec<H256>,
+//!         commands: Vec<Vec<u8>>,
+//!         calls: Vec<Vec<u8>>,
+//!     ) -> BatchValidation {
+//!         let calls = match calls
+//!             .into_iter()
+//!             .map(|x| RuntimeCall::decode(&mut &x[..]))
+//!             .collect::<Result<Vec<_>, _>>()
+//!         {
+//!             Ok(calls) => calls,
+//!             Err(_) => {
+//!                 return BatchValidation {
+//!                     error: Some(DispatchError::Other("Failed to decode calls")),
+//!                     ..Default::default()
+//!                 }
+//!             }
+//!         };
+//!
+//!         AxelarGateway::validate_batch(
+//!             &proof,
+//!             chain_id,
+//!             command_ids,
+//!             commands
+//!                 .into_iter()
+//!                 .map(|x| String::from_utf8_lossy(&x).into_owned())
+//!                 .collect(),
+//!             calls,
+//!         )
+//!     }
+//! }
+//!
+//! impl axelar_cgp_runtime_api::AxelarGatewayFeeApi<Block, Balance> for Runtime {
+//!     fn call_contract_fee(payload_len: u32) -> Balance {
+//!         AxelarGateway::call_contract_fee(payload_len)
+//!     }
+//! }
+//!
+//! impl axelar_cgp_runtime_api::AxelarGatewayOutboundApi<Block, OutboundMessageOf<Runtime>>
+//!     for Runtime
+//! {
+//!     fn outbound_messages(start_nonce: u64, limit: u32) -> Vec<(u64, OutboundMessageOf<Runtime>)> {
+//!         AxelarGateway::outbound_messages(start_nonce, limit)
+//!     }
+//!
+//!     fn outbound_nonces() -> (u64, u64) {
+//!         (
+//!             AxelarGateway::oldest_outbound_nonce(),
+//!             AxelarGateway::next_outbound_nonce(),
+//!         )
+//!     }
+//!
+//!     fn outbound_message_proof(nonce: u64) -> Option<OutboundMessageProof> {
+//!         AxelarGateway::outbound_message_proof(nonce)
+//!     }
+//! }
+//! ```
+
+// Ensure we're `no_std` when compiling for WebAssembly.
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use codec::{Codec, Decode, Encode};
+use sp_core::{RuntimeDebug, H160, H256};
+use sp_runtime::DispatchError;
+use sp_std::vec::Vec;
+
+/// What `execute` would do with a single command of a batch
+#[derive(Clone, Copy, PartialEq, Eq, Encode, Decode, RuntimeDebug)]
+pub enum CommandStatus {
+    /// The command would be dispatched
+    Run,
+    /// Skipped, the command has already been executed
+    AlreadyExecuted,
+    /// Skipped, operatorship transfers only run once per batch and when signed by the
+    /// current operators
+    NotActiveOperators,
+    /// Failed, the gateway doesn't execute the command
+    UnknownCommand,
+    /// Failed, the call is not the gateway call of the command it is signed as
+    CommandMismatch,
+}
+
+/// Merkle proof of an outbound message against the `OutboundCommitment` in the digest of
+/// the block it was sent in
+#[derive(Clone, Default, PartialEq, Eq, Encode, Decode, RuntimeDebug)]
+pub struct OutboundMessageProof {
+    /// Hash of the nonce and message, the leaf of the tree
+    pub leaf: H256,
+    /// Position of the message among the messages of its block
+    pub leaf_index: u32,
+    /// Number of messages sent in the block
+    pub leaf_count: u32,
+    /// Sibling nodes from the leaf up to the root
+    pub proof: Vec<H256>,
+}
+
+/// Outcome of validating an `execute` batch without dispatching it
+#[derive(Clone, Default, PartialEq, Eq, Encode, Decode, RuntimeDebug)]
+pub struct BatchValidation {
+    /// The error the whole batch would fail with, if any
+    pub error: Option<DispatchError>,
+    /// Whether the proof decodes, is signed by valid operators and meets their threshold
+    pub proof_valid: bool,
+    /// Whether the proof is signed by the current operators
+    pub active_operators: bool,
+    /// The status of each command, empty if the whole batch would fail
+    pub commands: Vec<CommandStatus>,
+}
+
+sp_api::decl_runtime_apis! {
+    /// Queries over the Axelar gateway state
+    pub trait AxelarGatewayApi {
+        /// Whether the contract call has been approved by Axelar and not forwarded yet.
+        /// `source_chain` and `source_address` are the UTF-8 bytes of the strings sent by Axelar.
+        fn is_contract_call_approved(
+            command_id: H256,
+            source_chain: Vec<u8>,
+            source_address: Vec<u8>,
+            contract_address: H160,
+            payload_hash: H256,
+        ) -> bool;
+
+        /// Whether the command has already been executed by the gateway
+        fn is_command_executed(command_id: H256) -> bool;
+
+        /// The current operators epoch
+        fn current_epoch() -> u64;
+
+        /// The hash of the current operators set
+        fn current_operators_hash() -> H256;
+
+        /// The epoch in which the operators set was installed, 0 if it never was
+        fn epoch_for_hash(operators_hash: H256) -> u64;
+
+        /// Whether proofs signed by the operators set are still accepted, i.e. it's within the
+        /// key retention period
+        fn is_operators_hash_valid(operators_hash: H256) -> bool;
+
+        /// The hash the current operators must sign for an `execute` batch, with `commands` as
+        /// UTF-8 bytes and `calls` already SCALE encoded
+        fn batch_message_hash(
+            chain_id: u32,
+            command_ids: Vec<H256>,
+            commands: Vec<Vec<u8>>,
+            calls: Vec<Vec<u8>>,
+        ) -> H256;
+
+        /// Runs every check of `execute` over the batch without dispatching it nor changing
+        /// any state, with `commands` as UTF-8 bytes and `calls` already SCALE encoded
+        fn validate_batch(
+            proof: Vec<u8>,
+            chain_id: u32,
+            command_ids: Vec<H256>,
+            commands: Vec<Vec<u8>>,
+            calls: Vec<Vec<u8>>,
+        ) -> BatchValidation;
+    }
+
+    /// Fees charged by the Axelar gateway
+    pub trait AxelarGatewayFeeApi<Balance> where Balance: Codec {
+        /// The fee `call_contract` charges for a payload of `payload_len` bytes
+        fn call_contract_fee(payload_len: u32) -> Balance;
+    }
+
+    /// Messages sent to other chains through the Axelar gateway
+    pub trait AxelarGatewayOutboundApi<Message> where Message: Codec {
+        /// Up to `limit` of the outbound messages still kept, from `start_nonce` onwards,
+        /// along with their nonce. The next page starts after the last nonce returned.
+        fn outbound_messages(start_nonce: u64, limit: u32) -> Vec<(u64, Message)>;
+
+        /// The nonce of the oldest outbound message still kept and the nonce the next one
+        /// will get
+        fn outbound_nonces() -> (u64, u64);
+
+        /// Merkle proof that the outbound message with `nonce` is committed to in the header
+        /// digest of the block it was sent in, `None` if it isn't kept or committed to yet
+        fn outbound_message_proof(nonce: u64) -> Option<OutboundMessageProof>;
+    }
+}
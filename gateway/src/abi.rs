@@ -0,0 +1,430 @@
+//! Decoding of the ABI encoded input of the EVM `AxelarGateway.execute`
+//!
+//! The input is `(bytes data, bytes proof)`, where `data` is the batch the operators sign:
+//! `(uint256 chainId, bytes32[] commandIds, string[] commands, bytes[] params)`, and each
+//! command has its own ABI encoded `params`.
+
+// ----------------------------------------------------------------------------
+// Module imports and re-exports
+// ----------------------------------------------------------------------------
+
+use crate::proof::{self, WORD};
+use crate::ProofVersion;
+use ethabi::{ParamType, Token};
+use sp_core::{H160, H256, U256};
+use sp_std::{boxed::Box, vec::Vec};
+
+// ----------------------------------------------------------------------------
+// Types
+// ----------------------------------------------------------------------------
+
+/// Batch of commands signed by the operators
+#[derive(PartialEq, Debug)]
+pub struct ExecuteData {
+    pub chain_id: U256,
+    pub command_ids: Vec<H256>,
+    pub commands: Vec<String>,
+    pub params: Vec<Vec<u8>>,
+}
+
+/// Params of an `approveContractCall` command
+#[derive(PartialEq, Debug)]
+pub struct ApproveContractCallParams {
+    pub source_chain: String,
+    pub source_address: String,
+    pub contract_address: H160,
+    pub payload_hash: H256,
+    pub source_tx_hash: H256,
+    pub source_event_index: U256,
+}
+
+/// Params of a `transferOperatorship` command
+#[derive(PartialEq, Debug)]
+pub struct TransferOperatorshipParams {
+    pub new_operators: Vec<[u8; 20]>,
+    pub new_weights: Vec<u128>,
+    pub new_threshold: u128,
+}
+
+/// Params of a `rotateSigners` command
+#[derive(PartialEq, Debug)]
+pub struct RotateSignersParams {
+    pub new_signers: Vec<[u8; 20]>,
+    pub new_weights: Vec<u128>,
+    pub new_threshold: u128,
+    pub nonce: H256,
+}
+
+// ----------------------------------------------------------------------------
+// Decoding
+// ----------------------------------------------------------------------------
+
+/// Splits the `execute` input into its `data` and `proof`.
+pub fn decode_input(input: &[u8]) -> Result<(Vec<u8>, Vec<u8>), ethabi::Error> {
+    match ethabi::decode(&[ParamType::Bytes, ParamType::Bytes], input)?.as_slice() {
+        [Token::Bytes(data), Token::Bytes(proof)] => Ok((data.clone(), proof.clone())),
+        _ => Err(ethabi::Error::InvalidData),
+    }
+}
+
+/// Decodes the batch signed by the operators.
+pub fn decode_data(data: &[u8]) -> Result<ExecuteData, ethabi::Error> {
+    let tokens = ethabi::decode(
+        &[
+            ParamType::Uint(256),
+            ParamType::Array(Box::new(ParamType::FixedBytes(32))),
+            ParamType::Array(Box::new(ParamType::String)),
+            ParamType::Array(Box::new(ParamType::Bytes)),
+        ],
+        data,
+    )?;
+
+    match tokens.as_slice() {
+        [Token::Uint(chain_id), Token::Array(command_ids), Token::Array(commands), Token::Array(params)] => {
+            Ok(ExecuteData {
+                chain_id: U256(chain_id.0),
+                command_ids: command_ids
+                    .iter()
+                    .map(|x| match x {
+                        Token::FixedBytes(x) => Ok(H256::from_slice(x)),
+                        _ => Err(ethabi::Error::InvalidData),
+                    })
+                    .collect::<Result<_, _>>()?,
+                commands: commands
+                    .iter()
+                    .map(|x| match x {
+                        Token::String(x) => Ok(x.clone()),
+                        _ => Err(ethabi::Error::InvalidData),
+                    })
+                    .collect::<Result<_, _>>()?,
+                params: params
+                    .iter()
+                    .map(|x| match x {
+                        Token::Bytes(x) => Ok(x.clone()),
+                        _ => Err(ethabi::Error::InvalidData),
+                    })
+                    .collect::<Result<_, _>>()?,
+            })
+        }
+        _ => Err(ethabi::Error::InvalidData),
+    }
+}
+
+/// Decodes the params of an `approveContractCall` command.
+pub fn decode_approve_contract_call(
+    params: &[u8],
+) -> Result<ApproveContractCallParams, ethabi::Error> {
+    let tokens = ethabi::decode(
+        &[
+            ParamType::String,
+            ParamType::String,
+            ParamType::Address,
+            ParamType::FixedBytes(32),
+            ParamType::FixedBytes(32),
+            ParamType::Uint(256),
+        ],
+        params,
+    )?;
+
+    match tokens.as_slice() {
+        [Token::String(source_chain), Token::String(source_address), Token::Address(contract_address), Token::FixedBytes(payload_hash), Token::FixedBytes(source_tx_hash), Token::Uint(source_event_index)] => {
+            Ok(ApproveContractCallParams {
+                source_chain: source_chain.clone(),
+                source_address: source_address.clone(),
+                contract_address: H160::from_slice(contract_address.as_bytes()),
+                payload_hash: H256::from_slice(payload_hash),
+                source_tx_hash: H256::from_slice(source_tx_hash),
+                source_event_index: U256(source_event_index.0),
+            })
+        }
+        _ => Err(ethabi::Error::InvalidData),
+    }
+}
+
+/// Decodes the params of a `transferOperatorship` command, the new operators data
+/// `(address[] newOperators, uint256[] newWeights, uint256 newThreshold)`.
+pub fn decode_transfer_operatorship(
+    params: &[u8],
+) -> Result<TransferOperatorshipParams, ethabi::Error> {
+    let tokens = ethabi::decode(
+        &[
+            ParamType::Array(Box::new(ParamType::Address)),
+            ParamType::Array(Box::new(ParamType::Uint(256))),
+            ParamType::Uint(256),
+        ],
+        params,
+    )?;
+
+    match tokens.as_slice() {
+        [Token::Array(operators), Token::Array(weights), Token::Uint(threshold)] => {
+            Ok(TransferOperatorshipParams {
+                new_operators: operators
+                    .iter()
+                    .map(|x| match x {
+                        Token::Address(x) => Ok(x.to_fixed_bytes()),
+                        _ => Err(ethabi::Error::InvalidData),
+                    })
+                    .collect::<Result<_, _>>()?,
+                new_weights: weights
+                    .iter()
+                    .map(|x| match x {
+                        Token::Uint(x) => to_u128(*x),
+                        _ => Err(ethabi::Error::InvalidData),
+                    })
+                    .collect::<Result<_, _>>()?,
+                new_threshold: to_u128(*threshold)?,
+            })
+        }
+        _ => Err(ethabi::Error::InvalidData),
+    }
+}
+
+/// Decodes the params of a `rotateSigners` command, the new signer set
+/// `(WeightedSigners newSigners)` in the format of `proof::WeightedSigners`.
+pub fn decode_rotate_signers(params: &[u8]) -> Result<RotateSignersParams, ethabi::Error> {
+    let tokens = ethabi::decode(&[proof::WeightedSigners::param_type()], params)?;
+
+    match tokens.as_slice() {
+        [signers] => {
+            let signers = proof::WeightedSigners::try_from(signers)
+                .map_err(|_| ethabi::Error::InvalidData)?;
+            let (new_signers, new_weights) = signers
+                .signers
+                .into_iter()
+                .map(|(signer, weight)| (signer.to_fixed_bytes(), weight))
+                .unzip();
+
+            Ok(RotateSignersParams {
+                new_signers,
+                new_weights,
+                new_threshold: signers.threshold,
+                nonce: signers.nonce,
+            })
+        }
+        _ => Err(ethabi::Error::InvalidData),
+    }
+}
+
+/// Estimates the number of `(commands, signatures, operators)` held by an `execute` input with a
+/// proof in the `version` format by reading the array lengths in place, the same way
+/// `proof::estimate_counts` does.
+pub fn estimate_counts(version: ProofVersion, input: &[u8]) -> (u32, u32, u32) {
+    let (data, proof) = match (bytes_at(input, 0), bytes_at(input, 1)) {
+        (Some(data), Some(proof)) => (data, proof),
+        _ => return (0, 0, 0),
+    };
+
+    // Every command is at least its id, an offset to its name and an offset to its params
+    let max_commands = data.len() / (3 * WORD);
+    let commands = proof::array_len(data, 1)
+        .unwrap_or(max_commands)
+        .min(max_commands);
+    let (signatures, operators) = proof::estimate_counts(version, proof);
+
+    (commands as u32, signatures, operators)
+}
+
+/// Reads the dynamic `bytes` whose offset is the `index`-th word of `payload`.
+fn bytes_at(payload: &[u8], index: usize) -> Option<&[u8]> {
+    let offset = proof::read_word(payload, index * WORD)?;
+    let len = proof::read_word(payload, offset)?;
+    let start = offset.checked_add(WORD)?;
+
+    payload.get(start..start.checked_add(len)?)
+}
+
+fn to_u128(value: ethabi::Uint) -> Result<u128, ethabi::Error> {
+    proof::to_u128(value).map_err(|_| ethabi::Error::InvalidData)
+}
+
+/// ABI encodes `execute` inputs the same way Axelar does, for tests and benchmarks.
+#[cfg(any(test, feature = "runtime-benchmarks"))]
+pub mod encode {
+    use super::*;
+
+    /// Encodes the `execute` input from the signed `data` and its `proof`.
+    pub fn input(data: Vec<u8>, proof: Vec<u8>) -> Vec<u8> {
+        ethabi::encode(&[Token::Bytes(data), Token::Bytes(proof)])
+    }
+
+    /// Encodes the batch signed by the operators.
+    pub fn data(
+        chain_id: u32,
+        command_ids: Vec<H256>,
+        commands: Vec<&str>,
+        params: Vec<Vec<u8>>,
+    ) -> Vec<u8> {
+        ethabi::encode(&[
+            Token::Uint(chain_id.into()),
+            Token::Array(
+                command_ids
+                    .into_iter()
+                    .map(|x| Token::FixedBytes(x.as_bytes().to_vec()))
+                    .collect(),
+            ),
+            Token::Array(
+                commands
+                    .into_iter()
+                    .map(|x| Token::String(x.into()))
+                    .collect(),
+            ),
+            Token::Array(params.into_iter().map(Token::Bytes).collect()),
+        ])
+    }
+
+    /// Encodes the params of an `approveContractCall` command.
+    pub fn approve_contract_call(
+        source_chain: &str,
+        source_address: &str,
+        contract_address: H160,
+        payload_hash: H256,
+        source_tx_hash: H256,
+        source_event_index: U256,
+    ) -> Vec<u8> {
+        ethabi::encode(&[
+            Token::String(source_chain.into()),
+            Token::String(source_address.into()),
+            Token::Address(contract_address.0.into()),
+            Token::FixedBytes(payload_hash.as_bytes().to_vec()),
+            Token::FixedBytes(source_tx_hash.as_bytes().to_vec()),
+            Token::Uint(ethabi::Uint(source_event_index.0)),
+        ])
+    }
+
+    /// Encodes the params of a `transferOperatorship` command.
+    pub fn transfer_operatorship(
+        new_operators: Vec<[u8; 20]>,
+        new_weights: Vec<u128>,
+        new_threshold: u128,
+    ) -> Vec<u8> {
+        ethabi::encode(&[
+            Token::Array(
+                new_operators
+                    .into_iter()
+                    .map(|x| Token::Address(x.into()))
+                    .collect(),
+            ),
+            Token::Array(
+                new_weights
+                    .into_iter()
+                    .map(|x| Token::Uint(x.into()))
+                    .collect(),
+            ),
+            Token::Uint(new_threshold.into()),
+        ])
+    }
+
+    /// Encodes the params of a `rotateSigners` command.
+    pub fn rotate_signers(
+        new_signers: Vec<[u8; 20]>,
+        new_weights: Vec<u128>,
+        new_threshold: u128,
+        nonce: H256,
+    ) -> Vec<u8> {
+        ethabi::encode(&[proof::WeightedSigners {
+            signers: new_signers
+                .into_iter()
+                .map(Into::into)
+                .zip(new_weights)
+                .collect(),
+            threshold: new_threshold,
+            nonce,
+        }
+        .to_token()])
+    }
+}
+
+#[cfg(test)]
+mod abi_tests {
+    use super::*;
+
+    #[test]
+    fn decode_execute_input() {
+        let approve = encode::approve_contract_call(
+            "ethereum",
+            "0x5f927395213ee6b95de97bddcb1b2b1c0f16844d",
+            H160::repeat_byte(1),
+            H256::repeat_byte(2),
+            H256::repeat_byte(3),
+            U256::from(4),
+        );
+        let transfer = encode::transfer_operatorship(vec![[1; 20], [2; 20]], vec![1, 2], 3);
+        let data = encode::data(
+            36,
+            vec![H256::repeat_byte(5), H256::repeat_byte(6)],
+            vec!["approveContractCall", "transferOperatorship"],
+            vec![approve.clone(), transfer.clone()],
+        );
+        let proof = vec![7u8; 5 * WORD];
+        let input = encode::input(data.clone(), proof.clone());
+
+        assert_eq!(decode_input(&input).unwrap(), (data.clone(), proof));
+        assert_eq!(
+            decode_data(&data).unwrap(),
+            ExecuteData {
+                chain_id: U256::from(36),
+                command_ids: vec![H256::repeat_byte(5), H256::repeat_byte(6)],
+                commands: vec![
+                    String::from("approveContractCall"),
+                    String::from("transferOperatorship")
+                ],
+                params: vec![approve.clone(), transfer.clone()],
+            }
+        );
+        assert_eq!(
+            decode_approve_contract_call(&approve).unwrap(),
+            ApproveContractCallParams {
+                source_chain: String::from("ethereum"),
+                source_address: String::from("0x5f927395213ee6b95de97bddcb1b2b1c0f16844d"),
+                contract_address: H160::repeat_byte(1),
+                payload_hash: H256::repeat_byte(2),
+                source_tx_hash: H256::repeat_byte(3),
+                source_event_index: U256::from(4),
+            }
+        );
+        assert_eq!(
+            decode_transfer_operatorship(&transfer).unwrap(),
+            TransferOperatorshipParams {
+                new_operators: vec![[1; 20], [2; 20]],
+                new_weights: vec![1, 2],
+                new_threshold: 3,
+            }
+        );
+
+        assert_eq!(estimate_counts(ProofVersion::Legacy, &input).0, 2);
+        assert_eq!(estimate_counts(ProofVersion::Legacy, &[]), (0, 0, 0));
+        assert!(decode_input(&data).is_err());
+        assert!(decode_approve_contract_call(&transfer).is_err());
+    }
+
+    #[test]
+    fn decode_rotate_signers_params() {
+        let params =
+            encode::rotate_signers(vec![[1; 20], [2; 20]], vec![1, 2], 3, H256::repeat_byte(4));
+
+        assert_eq!(
+            decode_rotate_signers(&params).unwrap(),
+            RotateSignersParams {
+                new_signers: vec![[1; 20], [2; 20]],
+                new_weights: vec![1, 2],
+                new_threshold: 3,
+                nonce: H256::repeat_byte(4),
+            }
+        );
+        assert!(decode_rotate_signers(&params[..params.len() - 1]).is_err());
+    }
+
+    #[test]
+    fn weights_beyond_u128() {
+        let params = ethabi::encode(&[
+            Token::Array(vec![Token::Address([1; 20].into())]),
+            Token::Array(vec![Token::Uint(ethabi::Uint::from(u128::MAX) + 1)]),
+            Token::Uint(1.into()),
+        ]);
+
+        assert!(matches!(
+            decode_transfer_operatorship(&params),
+            Err(ethabi::Error::InvalidData)
+        ));
+    }
+}
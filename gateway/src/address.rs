@@ -0,0 +1,164 @@
+//! Syntax checks for contract addresses of the chains Axelar connects to
+
+// ----------------------------------------------------------------------------
+// Module imports and re-exports
+// ----------------------------------------------------------------------------
+
+use codec::{Decode, Encode, MaxEncodedLen};
+use scale_info::TypeInfo;
+use sp_core::{keccak_256, RuntimeDebug};
+use sp_std::vec::Vec;
+
+// ----------------------------------------------------------------------------
+// Constants
+// ----------------------------------------------------------------------------
+
+const BECH32_CHARSET: &[u8] = b"qpzry9x8gf2tvdw0s3jn54khce6mua7l";
+const BECH32_GENERATOR: [u32; 5] = [0x3b6a57b2, 0x26508e6d, 0x1ea119fa, 0x3d4233dd, 0x2a1462b3];
+const BECH32_CONST: u32 = 1;
+const BECH32M_CONST: u32 = 0x2bc830a3;
+const BECH32_MAX_LEN: usize = 90;
+
+const BASE58_ALPHABET: &[u8] = b"123456789ABCDEFGHJKLMNPQRSTUVWXYZabcdefghijkmnopqrstuvwxyz";
+
+// ----------------------------------------------------------------------------
+// Types
+// ----------------------------------------------------------------------------
+
+/// Format of the contract addresses of a destination chain
+#[derive(Copy, Clone, PartialEq, Eq, Encode, Decode, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+#[cfg_attr(feature = "std", derive(serde::Serialize, serde::Deserialize))]
+pub enum AddressFormat {
+    /// `0x` prefixed 20 bytes hex, with an EIP-55 checksum when mixed case
+    EvmHex,
+    /// Bech32 or Bech32m, as used by Cosmos chains
+    Bech32,
+    /// Base58, as used by Solana
+    Base58,
+}
+
+impl AddressFormat {
+    /// Whether `address` is syntactically valid in this format
+    pub fn is_valid(&self, address: &str) -> bool {
+        match self {
+            AddressFormat::EvmHex => is_evm_hex(address.as_bytes()),
+            AddressFormat::Bech32 => is_bech32(address.as_bytes()),
+            AddressFormat::Base58 => is_base58(address.as_bytes()),
+        }
+    }
+}
+
+// ----------------------------------------------------------------------------
+// Format checks
+// ----------------------------------------------------------------------------
+
+fn is_evm_hex(address: &[u8]) -> bool {
+    let hex = match address.strip_prefix(b"0x") {
+        Some(hex) if hex.len() == 40 && hex.iter().all(u8::is_ascii_hexdigit) => hex,
+        _ => return false,
+    };
+
+    let has_lower = hex.iter().any(u8::is_ascii_lowercase);
+    let has_upper = hex.iter().any(u8::is_ascii_uppercase);
+    if !(has_lower && has_upper) {
+        return true;
+    }
+
+    // EIP-55: a letter is uppercase when the matching nibble of the hash of the
+    // lowercase address is 8 or more
+    let hash = keccak_256(&hex.to_ascii_lowercase());
+    hex.iter().enumerate().all(|(i, c)| {
+        let nibble = (hash[i / 2] >> (4 * (1 - i % 2))) & 0x0f;
+        !c.is_ascii_alphabetic() || c.is_ascii_uppercase() == (nibble >= 8)
+    })
+}
+
+fn is_bech32(address: &[u8]) -> bool {
+    if address.len() > BECH32_MAX_LEN
+        || (address.iter().any(u8::is_ascii_lowercase)
+            && address.iter().any(u8::is_ascii_uppercase))
+    {
+        return false;
+    }
+
+    let address = address.to_ascii_lowercase();
+    let separator = match address.iter().rposition(|c| *c == b'1') {
+        Some(separator) => separator,
+        None => return false,
+    };
+    let (hrp, data) = (&address[..separator], &address[separator + 1..]);
+    if hrp.is_empty() || data.len() < 6 || hrp.iter().any(|c| !(33..=126).contains(c)) {
+        return false;
+    }
+
+    let mut values: Vec<u8> = hrp.iter().map(|c| c >> 5).collect();
+    values.push(0);
+    values.extend(hrp.iter().map(|c| c & 31));
+    for c in data {
+        match BECH32_CHARSET.iter().position(|x| x == c) {
+            Some(value) => values.push(value as u8),
+            None => return false,
+        }
+    }
+
+    matches!(bech32_polymod(&values), BECH32_CONST | BECH32M_CONST)
+}
+
+fn bech32_polymod(values: &[u8]) -> u32 {
+    values.iter().fold(1u32, |checksum, value| {
+        let top = checksum >> 25;
+        let checksum = ((checksum & 0x1ffffff) << 5) ^ *value as u32;
+        BECH32_GENERATOR
+            .iter()
+            .enumerate()
+            .filter(|(i, _)| (top >> i) & 1 == 1)
+            .fold(checksum, |checksum, (_, generator)| checksum ^ generator)
+    })
+}
+
+fn is_base58(address: &[u8]) -> bool {
+    !address.is_empty() && address.iter().all(|c| BASE58_ALPHABET.contains(c))
+}
+
+#[cfg(test)]
+mod address_tests {
+    use super::*;
+
+    #[test]
+    fn evm_hex() {
+        let format = AddressFormat::EvmHex;
+        assert!(format.is_valid("0x5f927395213ee6b95de97bddcb1b2b1c0f16844d"));
+        assert!(format.is_valid("0x5F927395213EE6B95DE97BDDCB1B2B1C0F16844D"));
+        assert!(format.is_valid("0x5aAeb6053F3E94C9b9A09f33669435E7Ef1BeAed"));
+        // Wrong checksum
+        assert!(!format.is_valid("0x5aAeb6053F3E94C9b9A09f33669435E7Ef1BeAeD"));
+        assert!(!format.is_valid("5f927395213ee6b95de97bddcb1b2b1c0f16844d"));
+        assert!(!format.is_valid("0x5f927395213ee6b95de97bddcb1b2b1c0f16844"));
+        assert!(!format.is_valid("0x5f927395213ee6b95de97bddcb1b2b1c0f16844g"));
+    }
+
+    #[test]
+    fn bech32() {
+        let format = AddressFormat::Bech32;
+        assert!(format.is_valid("osmo1qs4g4q9x4nx5e7t6qxqqdwwl4w6ngmnkh54nqc"));
+        assert!(format.is_valid("A12UEL5L"));
+        assert!(format.is_valid("bc1p0xlxvlhemja6c4dqv22uapctqupfhlxm9h8z3k2e72q4k9hcz7vqzk5jj0"));
+        // Wrong checksum
+        assert!(!format.is_valid("osmo1qs4g4q9x4nx5e7t6qxqqdwwl4w6ngmnkh54nqp"));
+        // Mixed case
+        assert!(!format.is_valid("A12uEL5L"));
+        // No separator
+        assert!(!format.is_valid("pzry9x0s0muk"));
+        // Invalid character
+        assert!(!format.is_valid("osmo1bs4g4q9x4nx5e7t6qxqqdwwl4w6ngmnkh54nqc"));
+    }
+
+    #[test]
+    fn base58() {
+        let format = AddressFormat::Base58;
+        assert!(format.is_valid("9xQeWvG816bUx9EPjHmaT23yvVM2ZWbrrpZb9PusVFin"));
+        assert!(!format.is_valid(""));
+        assert!(!format.is_valid("9xQeWvG816bUx9EPjHmaT23yvVM2ZWbrrpZb9PusVFi0"));
+        assert!(!format.is_valid("0x5f927395213ee6b95de97bddcb1b2b1c0f16844d"));
+    }
+}
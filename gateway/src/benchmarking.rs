@@ -0,0 +1,470 @@
+//! Benchmarking setup for the Axelar gateway pallet
+
+// ----------------------------------------------------------------------------
+// Module imports and re-exports
+// ----------------------------------------------------------------------------
+
+use super::*;
+use crate::pallet::Call as AxelarGatewayCall;
+use ethabi::Token;
+use frame_benchmarking::{benchmarks, impl_benchmark_test_suite, whitelisted_caller};
+use frame_support::traits::{Currency, Get};
+use frame_support::weights::Weight;
+use frame_system::RawOrigin as SystemOrigin;
+use sp_core::{keccak_256, H160, H256, U256};
+use sp_runtime::traits::{Bounded, Zero};
+
+// ----------------------------------------------------------------------------
+// Helpers
+// ----------------------------------------------------------------------------
+
+/// Generates `n` operator key pairs sorted by ascending address, as required by
+/// `validate_operatorship`.
+fn generate_operators(n: u32) -> Vec<([u8; 20], [u8; 32])> {
+    let mut operators: Vec<([u8; 20], [u8; 32])> = (0..n)
+        .map(|_| {
+            let (public, secret) = ecdsa::generate_keypair();
+            let address = H160::from(H256::from_slice(keccak_256(&public).as_slice()));
+            (address.to_fixed_bytes(), secret)
+        })
+        .collect();
+    operators.sort_by(|a, b| a.0.cmp(&b.0));
+
+    operators
+}
+
+/// Signatures of `msg_hash` by the last `s` of the `operators`, so finding their signers walks
+/// the whole operator set.
+fn sign_by_last(operators: &[([u8; 20], [u8; 32])], s: u32, msg_hash: H256) -> Vec<Vec<u8>> {
+    operators
+        .iter()
+        .skip(operators.len() - s as usize)
+        .map(|x| ecdsa::sign_message(msg_hash, &x.1))
+        .collect()
+}
+
+/// Distinct sorted operator addresses, without keys.
+fn operator_addresses(n: u32) -> Vec<[u8; 20]> {
+    (1..=n)
+        .map(|i| H160::from_low_u64_be(i as u64).to_fixed_bytes())
+        .collect()
+}
+
+/// ABI encodes the proof the same way Axelar does.
+fn encode_proof(
+    operators: Vec<[u8; 20]>,
+    weights: Vec<u128>,
+    threshold: u128,
+    signatures: Vec<Vec<u8>>,
+) -> Vec<u8> {
+    ethabi::encode(&[
+        Token::Array(
+            operators
+                .into_iter()
+                .map(|x| Token::Address(x.into()))
+                .collect(),
+        ),
+        Token::Array(weights.into_iter().map(|x| Token::Uint(x.into())).collect()),
+        Token::Uint(threshold.into()),
+        Token::Array(signatures.into_iter().map(Token::Bytes).collect()),
+    ])
+}
+
+/// Installs dummy operator sets for every epoch of the key retention period.
+fn fill_retention_period<T: Config>() -> u64 {
+    let retention = T::OperatorRetention::get();
+    for epoch in 1..=retention {
+        let operators_hash = H256::from_low_u64_be(epoch);
+        HashForEpoch::<T>::set(epoch, operators_hash);
+        EpochForHash::<T>::set(operators_hash, epoch);
+    }
+    CurrentEpoch::<T>::set(retention);
+
+    retention
+}
+
+/// Trusts `address` on the "ethereum" chain, which only trusts its registered addresses.
+fn trust_source<T: Config>(address: &str) {
+    let chain_name = Pallet::<T>::chain_name("ethereum").unwrap();
+    TrustedSourceChains::<T>::insert(&chain_name, AddressPolicy::TrustedOnly);
+    TrustedSourceAddresses::<T>::insert(chain_name, Pallet::<T>::address(address).unwrap(), ());
+}
+
+/// Builds a local call with a `len` bytes source address, which the gateway origin is allowed
+/// to dispatch.
+fn approve_call<T: Config>(command_id: H256, len: u32) -> AxelarGatewayCall<T> {
+    AxelarGatewayCall::<T>::approve_contract_call {
+        source_chain: Pallet::<T>::chain_name("ethereum").unwrap(),
+        source_address: Pallet::<T>::address(&"a".repeat(len as usize)).unwrap(),
+        contract_address: H160::repeat_byte(1),
+        payload_hash: H256::repeat_byte(2),
+        source_tx_hash: H256::repeat_byte(3),
+        source_event_index: U256::from(100),
+        command_id,
+    }
+}
+
+/// Outbound message to ethereum sent in `block_number`
+fn outbound_message<T: Config>(block_number: T::BlockNumber) -> OutboundMessageOf<T> {
+    OutboundMessage {
+        sender: whitelisted_caller(),
+        destination_chain: Pallet::<T>::chain_name("ethereum").unwrap(),
+        destination_contract_address: Pallet::<T>::address(
+            "0x5f927395213ee6b95de97bddcb1b2b1c0f16844d",
+        )
+        .unwrap(),
+        payload_hash: H256::repeat_byte(1),
+        block_number,
+    }
+}
+
+// ----------------------------------------------------------------------------
+// Benchmarks
+// ----------------------------------------------------------------------------
+
+benchmarks! {
+    where_clause { where <T as Config>::RuntimeCall: From<AxelarGatewayCall<T>> }
+
+    execute {
+        let c in 1 .. T::MaxBatchSize::get();
+        let s in 1 .. T::MaxOperators::get();
+        let o in 1 .. T::MaxOperators::get();
+        let b in 1 .. T::MaxBatchSize::get() * T::MaxAddressLen::get();
+
+        let caller: T::AccountId = whitelisted_caller();
+        let chain_id = T::ChainId::get();
+        // The `b` bytes are spread over the source addresses of the approvals
+        let address_len = (b / c).clamp(1, T::MaxAddressLen::get());
+        trust_source::<T>(&"a".repeat(address_len as usize));
+
+        let command_ids: Vec<H256> = (0..c).map(|i| H256::from_low_u64_be(i as u64 + 1)).collect();
+        let commands: Vec<String> = (0..c).map(|_| String::from("approveContractCall")).collect();
+        let calls: Vec<<T as Config>::RuntimeCall> = command_ids
+            .iter()
+            .map(|id| approve_call::<T>(*id, address_len).into())
+            .collect();
+
+        // Components can't bound each other, so at most all `o` operators sign. Every operator
+        // has weight 1, so all `s` signatures are needed to reach the threshold.
+        let s = s.min(o);
+        let operators = generate_operators(o);
+        let addresses: Vec<[u8; 20]> = operators.iter().map(|x| x.0).collect();
+        let weights = vec![1u128; addresses.len()];
+        let threshold = s as u128;
+
+        let batch = Pallet::<T>::abi_encode_batch_params(
+            chain_id,
+            command_ids.clone(),
+            commands.clone(),
+            calls.clone(),
+        );
+        let msg_hash = H256::from_slice(&ecdsa::to_eth_signed_message_hash(keccak_256(
+            batch.as_slice(),
+        )));
+        let signatures = sign_by_last(&operators, s, msg_hash);
+
+        let operators_hash = Pallet::<T>::validate_operatorship(addresses.clone(), weights.clone(), threshold)?;
+        CurrentEpoch::<T>::set(1);
+        HashForEpoch::<T>::set(1, operators_hash);
+        EpochForHash::<T>::set(operators_hash, 1);
+
+        let proof: BoundedVec<u8, T::MaxProofLen> =
+            encode_proof(addresses, weights, threshold, signatures).try_into().unwrap();
+        let last_command_id = command_ids[command_ids.len() - 1];
+        let command_ids: BoundedVec<H256, T::MaxBatchSize> = command_ids.try_into().unwrap();
+        let commands: BoundedVec<CommandName, T::MaxBatchSize> = commands
+            .into_iter()
+            .map(|command| command.into_bytes().try_into().unwrap())
+            .collect::<Vec<CommandName>>()
+            .try_into()
+            .unwrap();
+        let calls: BoundedVec<<T as Config>::RuntimeCall, T::MaxBatchSize> =
+            calls.try_into().unwrap();
+    }: _(SystemOrigin::Signed(caller), proof, chain_id, command_ids, commands, calls)
+    verify {
+        assert!(CommandExecuted::<T>::contains_key(last_command_id));
+    }
+
+    execute_abi {
+        let c in 1 .. T::MaxBatchSize::get();
+        let s in 1 .. T::MaxOperators::get();
+        let o in 1 .. T::MaxOperators::get();
+        let n in 1 .. T::MaxInputLen::get();
+
+        let caller: T::AccountId = whitelisted_caller();
+        let source_address = "a".repeat(42);
+        trust_source::<T>(&source_address);
+
+        let command_ids: Vec<H256> = (0..c).map(|i| H256::from_low_u64_be(i as u64 + 1)).collect();
+        let params = crate::abi::encode::approve_contract_call(
+            "ethereum",
+            &source_address,
+            H160::repeat_byte(1),
+            H256::repeat_byte(2),
+            H256::repeat_byte(3),
+            U256::from(100),
+        );
+        let mut data = crate::abi::encode::data(
+            T::ChainId::get(),
+            command_ids.clone(),
+            vec!["approveContractCall"; c as usize],
+            vec![params; c as usize],
+        );
+
+        // Components can't bound each other, so at most all `o` operators sign. Every operator
+        // has weight 1, so all `s` signatures are needed to reach the threshold.
+        let s = s.min(o);
+        let operators = generate_operators(o);
+        let addresses: Vec<[u8; 20]> = operators.iter().map(|x| x.0).collect();
+        let weights = vec![1u128; addresses.len()];
+        let threshold = s as u128;
+
+        // Trailing bytes, which decoding ignores but hashing does not, pad the input up to `n`
+        // bytes. Signatures have a fixed length, so unsigned ones give the unpadded length.
+        let unsigned = vec![vec![0u8; crate::proof::SIGNATURE_LEN]; s as usize];
+        let unpadded_len = crate::abi::encode::input(
+            data.clone(),
+            encode_proof(addresses.clone(), weights.clone(), threshold, unsigned),
+        )
+        .len();
+        data.resize(data.len() + (n as usize).saturating_sub(unpadded_len), 0);
+
+        let msg_hash = H256::from_slice(&ecdsa::to_eth_signed_message_hash(keccak_256(
+            data.as_slice(),
+        )));
+        let signatures = sign_by_last(&operators, s, msg_hash);
+
+        let operators_hash = Pallet::<T>::validate_operatorship(addresses.clone(), weights.clone(), threshold)?;
+        CurrentEpoch::<T>::set(1);
+        HashForEpoch::<T>::set(1, operators_hash);
+        EpochForHash::<T>::set(operators_hash, 1);
+
+        let input: BoundedVec<u8, T::MaxInputLen> =
+            crate::abi::encode::input(data, encode_proof(addresses, weights, threshold, signatures))
+                .try_into()
+                .unwrap();
+        let last_command_id = command_ids[command_ids.len() - 1];
+    }: _(SystemOrigin::Signed(caller), input)
+    verify {
+        assert!(CommandExecuted::<T>::contains_key(last_command_id));
+    }
+
+    validate_proof {
+        let s in 1 .. T::MaxOperators::get();
+        let o in 1 .. T::MaxOperators::get();
+
+        // Components can't bound each other, so at most all `o` operators sign. Every operator
+        // has weight 1, so all `s` signatures are needed to reach the threshold.
+        let s = s.min(o);
+        let operators = generate_operators(o);
+        let addresses: Vec<[u8; 20]> = operators.iter().map(|x| x.0).collect();
+        let weights = vec![1u128; addresses.len()];
+        let threshold = s as u128;
+
+        let data_hash = H256::repeat_byte(1);
+        let msg_hash = H256(ecdsa::to_eth_signed_message_hash(data_hash.0));
+        let signatures = sign_by_last(&operators, s, msg_hash);
+
+        let operators_hash = Pallet::<T>::validate_operatorship(addresses.clone(), weights.clone(), threshold)?;
+        CurrentEpoch::<T>::set(1);
+        HashForEpoch::<T>::set(1, operators_hash);
+        EpochForHash::<T>::set(operators_hash, 1);
+
+        let proof = encode_proof(addresses, weights, threshold, signatures);
+    }: {
+        assert!(Pallet::<T>::validate_proof(data_hash, &proof)?);
+    }
+
+    transfer_operatorship {
+        let o in 1 .. T::MaxOperators::get();
+
+        // Fill the whole retention period so the oldest epoch gets pruned
+        let retention = fill_retention_period::<T>();
+
+        let operators = operator_addresses(o);
+        let weights = vec![1u128; o as usize];
+    }: _(RawOrigin::Bridge, operators, weights, o as u128)
+    verify {
+        assert_eq!(CurrentEpoch::<T>::get(), retention + 1);
+        assert!(!HashForEpoch::<T>::contains_key(1));
+    }
+
+    force_set_operators {
+        let o in 1 .. T::MaxOperators::get();
+
+        // Fill the whole retention period so the oldest epoch gets pruned. Revoking the previous
+        // operators is charged on top, per retained epoch.
+        let retention = fill_retention_period::<T>();
+
+        let operators = operator_addresses(o);
+        let weights = vec![1u128; o as usize];
+    }: _(SystemOrigin::Root, operators, weights, o as u128, H256::zero(), false)
+    verify {
+        assert_eq!(CurrentEpoch::<T>::get(), retention + 1);
+        assert!(!HashForEpoch::<T>::contains_key(1));
+    }
+
+    approve_contract_call {
+        let p in 1 .. T::MaxAddressLen::get();
+
+        let source_address = "a".repeat(p as usize);
+        trust_source::<T>(&source_address);
+    }: _(
+        RawOrigin::Bridge,
+        Pallet::<T>::chain_name("ethereum").unwrap(),
+        Pallet::<T>::address(&source_address).unwrap(),
+        H160::repeat_byte(1),
+        H256::repeat_byte(2),
+        H256::repeat_byte(3),
+        U256::from(100),
+        H256::repeat_byte(4)
+    )
+    verify {
+        assert_eq!(ContractCallApproved::<T>::iter().count(), 1);
+    }
+
+    // The forwarded approval is padded up to `p` bytes, which decoding ignores but hashing does
+    // not. Its own dispatch weight is charged on top of this benchmark.
+    forward_approved_call {
+        let p in 1 .. T::MaxPayloadLen::get();
+
+        let caller: T::AccountId = whitelisted_caller();
+        let command_id = H256::repeat_byte(4);
+        let source_chain = String::from("ethereum");
+        let source_address = String::from("0x5f927395213ee6b95de97bddcb1b2b1c0f16844d");
+        let contract_address = H160::repeat_byte(1);
+        let mut call = <T as Config>::RuntimeCall::from(approve_call::<T>(command_id, 1)).encode();
+        call.resize(call.len().max(p as usize), 0);
+        // The forwarded approval comes from a trusted source, the call carrying it is approved
+        // below
+        trust_source::<T>("a");
+
+        let call_hash = H256(keccak_256(call.as_slice()));
+        let mut approved_call = command_id.encode();
+        approved_call.append(&mut source_chain.encode());
+        approved_call.append(&mut source_address.encode());
+        approved_call.append(&mut contract_address.encode());
+        approved_call.append(&mut call_hash.encode());
+        let approved_call_hash = H256::from(keccak_256(approved_call.as_slice()));
+        ContractCallApproved::<T>::set(approved_call_hash, ());
+
+        let source_chain = Pallet::<T>::chain_name(&source_chain).unwrap();
+        let source_address = Pallet::<T>::address(&source_address).unwrap();
+        let call: PayloadOf<T> = call.try_into().unwrap();
+    }: _(SystemOrigin::Signed(caller), command_id, source_chain, source_address, contract_address, call)
+    verify {
+        assert!(!ContractCallApproved::<T>::contains_key(approved_call_hash));
+    }
+
+    call_contract {
+        let p in 1 .. T::MaxPayloadLen::get();
+
+        let caller: T::AccountId = whitelisted_caller();
+        let destination_chain = Pallet::<T>::chain_name("ethereum").unwrap();
+        let destination_contract_address =
+            Pallet::<T>::address("0x5f927395213ee6b95de97bddcb1b2b1c0f16844d").unwrap();
+        let payload: PayloadOf<T> = vec![1u8; p as usize].try_into().unwrap();
+        T::Currency::make_free_balance_be(&caller, BalanceOf::<T>::max_value() / 2u32.into());
+        DestinationChains::<T>::insert(&destination_chain, AddressFormat::EvmHex);
+    }: _(SystemOrigin::Signed(caller), destination_chain, destination_contract_address, payload)
+
+    pause {
+    }: _(SystemOrigin::Root, Traffic::Inbound)
+    verify {
+        assert!(Paused::<T>::get(Traffic::Inbound));
+    }
+
+    unpause {
+        Paused::<T>::insert(Traffic::Inbound, true);
+    }: _(SystemOrigin::Root, Traffic::Inbound)
+    verify {
+        assert!(!Paused::<T>::get(Traffic::Inbound));
+    }
+
+    add_source_chain {
+    }: _(SystemOrigin::Root, String::from("ethereum"), AddressPolicy::TrustedOnly)
+    verify {
+        assert!(TrustedSourceChains::<T>::contains_key(Pallet::<T>::chain_name("ethereum").unwrap()));
+    }
+
+    remove_source_chain {
+        let chain_name = Pallet::<T>::chain_name("ethereum").unwrap();
+        TrustedSourceChains::<T>::insert(&chain_name, AddressPolicy::Any);
+    }: _(SystemOrigin::Root, String::from("ethereum"))
+    verify {
+        assert!(!TrustedSourceChains::<T>::contains_key(chain_name));
+    }
+
+    add_source_address {
+        let address = String::from("0x5f927395213ee6b95de97bddcb1b2b1c0f16844d");
+    }: _(SystemOrigin::Root, String::from("ethereum"), address)
+    verify {
+        assert_eq!(TrustedSourceAddresses::<T>::iter().count(), 1);
+    }
+
+    add_destination_chain {
+    }: _(SystemOrigin::Root, String::from("ethereum"), AddressFormat::EvmHex)
+    verify {
+        assert!(DestinationChains::<T>::contains_key(Pallet::<T>::chain_name("ethereum").unwrap()));
+    }
+
+    remove_destination_chain {
+        let chain_name = Pallet::<T>::chain_name("ethereum").unwrap();
+        DestinationChains::<T>::insert(&chain_name, AddressFormat::EvmHex);
+    }: _(SystemOrigin::Root, String::from("ethereum"))
+    verify {
+        assert!(!DestinationChains::<T>::contains_key(chain_name));
+    }
+
+    remove_source_address {
+        let address = String::from("0x5f927395213ee6b95de97bddcb1b2b1c0f16844d");
+        trust_source::<T>(&address);
+    }: _(SystemOrigin::Root, String::from("ethereum"), address)
+    verify {
+        assert_eq!(TrustedSourceAddresses::<T>::iter().count(), 0);
+    }
+
+    commit_outbound_messages {
+        let n in 0 .. 100;
+
+        let now = T::OutboundRetention::get();
+        for nonce in 0..n as u64 {
+            OutboundMessages::<T>::insert(nonce, outbound_message::<T>(now));
+        }
+        NextOutboundNonce::<T>::put(n as u64);
+    }: {
+        Pallet::<T>::commit_outbound_messages(now);
+    }
+    verify {
+        assert_eq!(CommittedOutboundNonce::<T>::get(), n as u64);
+    }
+
+    prune_outbound_messages {
+        let n in 0 .. 100;
+
+        // `n` expired messages followed by one still kept
+        let now = T::OutboundRetention::get();
+        for nonce in 0..n as u64 {
+            OutboundMessages::<T>::insert(nonce, outbound_message::<T>(Zero::zero()));
+        }
+        OutboundMessages::<T>::insert(n as u64, outbound_message::<T>(now));
+        OutboundCommitments::<T>::insert(
+            T::BlockNumber::zero(),
+            OutboundCommitment { first_nonce: 0, message_count: n, root: H256::zero() },
+        );
+        NextOutboundNonce::<T>::put(n as u64 + 1);
+        CommittedOutboundNonce::<T>::put(n as u64 + 1);
+    }: {
+        Pallet::<T>::prune_outbound_messages(now, Weight::MAX);
+    }
+    verify {
+        assert_eq!(OldestOutboundNonce::<T>::get(), n as u64);
+        assert_eq!(OutboundCommitments::<T>::contains_key(T::BlockNumber::zero()), n == 0);
+    }
+}
+
+impl_benchmark_test_suite!(
+    Pallet,
+    crate::mock::ExtBuilder::default().build(),
+    crate::mock::Runtime
+);
@@ -12,32 +12,164 @@
 // Pallet traits declaration
 pub mod traits;
 
+// Pallet extrinsics weight information (placeholders until benchmarked)
+pub mod weights;
+
 // Re-export pallet components in crate namespace (for runtime construction)
-use crate::traits::WeightInfo;
+pub use crate::address::AddressFormat;
+pub use crate::weights::WeightInfo;
+pub use axelar_cgp_runtime_api::{BatchValidation, CommandStatus, OutboundMessageProof};
 use codec::{Decode, Encode, MaxEncodedLen};
 use frame_support::dispatch::{extract_actual_weight, GetDispatchInfo, PostDispatchInfo};
-use frame_support::traits::EnsureOrigin;
+use frame_support::traits::{Currency, EnsureOrigin};
 pub use pallet::*;
 use scale_info::TypeInfo;
-use sp_core::RuntimeDebug;
+use sp_core::{ConstU32, RuntimeDebug, H256};
+use sp_runtime::{BoundedVec, ConsensusEngineId};
 
 #[cfg(test)]
 mod mock;
 #[cfg(test)]
 mod tests;
 
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
+
+pub mod abi;
+pub mod address;
+pub mod merkle;
+pub mod migrations;
 pub mod proof;
 
 // ----------------------------------------------------------------------------
 // Constants
 // ----------------------------------------------------------------------------
+/// Number of epochs Axelar's EVM gateway keeps accepting an operator set for,
+/// the usual value of `Config::OperatorRetention`.
 pub const OLD_KEY_RETENTION: u64 = 16;
 
+/// Id of the header digest items holding the `OutboundCommitment` of a block
+pub const OUTBOUND_COMMITMENT_ENGINE_ID: ConsensusEngineId = *b"AXLO";
+
 #[derive(Copy, Clone, PartialEq, Eq, Encode, Decode, RuntimeDebug, TypeInfo, MaxEncodedLen)]
 pub enum RawOrigin {
     Bridge,
 }
 
+/// Direction of the traffic going through the gateway, which can be paused separately
+#[derive(Copy, Clone, PartialEq, Eq, Encode, Decode, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub enum Traffic {
+    /// Batches signed by Axelar and the calls they approve: `execute` and `forward_approved_call`
+    Inbound,
+    /// Messages to other chains: `call_contract`
+    Outbound,
+}
+
+/// Source addresses a trusted source chain is allowed to send calls from
+#[derive(Copy, Clone, PartialEq, Eq, Encode, Decode, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub enum AddressPolicy {
+    /// Any address of the chain
+    Any,
+    /// Only the addresses registered for the chain
+    TrustedOnly,
+}
+
+/// Format of the proofs the gateway accepts, see `proof::VersionedProof`
+#[derive(Copy, Clone, PartialEq, Eq, Encode, Decode, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub enum ProofVersion {
+    /// `(operators, weights, threshold, signatures)`, signed over the Ethereum prefixed
+    /// hash of the data
+    Legacy,
+    /// `WeightedSigners` of the Axelar Amplifier and v6 gateways, signed over the data hash
+    /// along with a domain separator and the hash of the signers
+    WeightedSigners,
+}
+
+/// Command of a batch signed by Axelar that the gateway executes
+#[derive(Copy, Clone, PartialEq, Eq, Encode, Decode, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub enum Command {
+    /// `approveContractCall`, run as `approve_contract_call`
+    ApproveContractCall,
+    /// `transferOperatorship`, run as `transfer_operatorship`
+    TransferOperatorship,
+    /// `rotateSigners`, run as `rotate_signers`
+    RotateSigners,
+}
+
+impl Command {
+    /// Name of the command in the batches Axelar signs
+    pub fn as_abi(&self) -> &'static str {
+        match self {
+            Command::ApproveContractCall => "approveContractCall",
+            Command::TransferOperatorship => "transferOperatorship",
+            Command::RotateSigners => "rotateSigners",
+        }
+    }
+
+    /// Command named `name` in a batch, `None` when the gateway doesn't execute it
+    pub fn from_abi(name: &str) -> Option<Self> {
+        match name {
+            "approveContractCall" => Some(Command::ApproveContractCall),
+            "transferOperatorship" => Some(Command::TransferOperatorship),
+            "rotateSigners" => Some(Command::RotateSigners),
+            _ => None,
+        }
+    }
+}
+
+/// Name of a chain, as Axelar refers to it
+pub type ChainNameOf<T> = BoundedVec<u8, <T as Config>::MaxChainNameLen>;
+
+/// Address of a contract on another chain, in the format of that chain
+pub type AddressOf<T> = BoundedVec<u8, <T as Config>::MaxAddressLen>;
+
+/// Payload sent with `call_contract`, or call forwarded with `forward_approved_call`
+pub type PayloadOf<T> = BoundedVec<u8, <T as Config>::MaxPayloadLen>;
+
+/// Maximum length of the name of a command in an `execute` batch
+pub const MAX_COMMAND_NAME_LEN: u32 = 32;
+
+/// Name of a command in an `execute` batch, see `Command::as_abi`
+pub type CommandName = BoundedVec<u8, ConstU32<MAX_COMMAND_NAME_LEN>>;
+
+/// Balance of the currency `call_contract` fees are paid with
+pub type BalanceOf<T> =
+    <<T as Config>::Currency as Currency<<T as frame_system::Config>::AccountId>>::Balance;
+
+/// A message sent to another chain with `call_contract`
+#[derive(Clone, PartialEq, Eq, Encode, Decode, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct OutboundMessage<AccountId, BlockNumber, ChainName, Address> {
+    pub sender: AccountId,
+    pub destination_chain: ChainName,
+    pub destination_contract_address: Address,
+    pub payload_hash: H256,
+    /// Block the message was sent in
+    pub block_number: BlockNumber,
+}
+
+/// Outbound message as stored by the pallet
+pub type OutboundMessageOf<T> = OutboundMessage<
+    <T as frame_system::Config>::AccountId,
+    <T as frame_system::Config>::BlockNumber,
+    ChainNameOf<T>,
+    AddressOf<T>,
+>;
+
+/// Commitment to the messages sent in a block, deposited in its header digest as a
+/// consensus item with the `OUTBOUND_COMMITMENT_ENGINE_ID`.
+///
+/// A message is checked against the header alone with `merkle::verify_proof`, its leaf
+/// being `merkle::leaf_hash(nonce, message)` and its index `nonce - first_nonce`.
+#[derive(Clone, PartialEq, Eq, Encode, Decode, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct OutboundCommitment {
+    /// Nonce of the first message of the block
+    pub first_nonce: u64,
+    /// Number of messages sent in the block, with consecutive nonces
+    pub message_count: u32,
+    /// Merkle root of the messages
+    pub root: H256,
+}
+
 // ----------------------------------------------------------------------------
 // Pallet module
 // ----------------------------------------------------------------------------
@@ -49,25 +181,28 @@ pub enum RawOrigin {
 // pallet itself.
 #[frame_support::pallet]
 pub mod pallet {
-    use crate::proof::operators_hash;
     use ethabi::Token;
     use frame_support::pallet_prelude::*;
-    use frame_support::traits::IsSubType;
+    use frame_support::storage;
+    use frame_support::traits::{ExistenceRequirement, IsSubType, WithdrawReasons};
     use frame_system::pallet_prelude::*;
     use sp_core::{keccak_256, H160, H256, U256};
-    use sp_runtime::traits::Dispatchable;
-    use sp_runtime::ArithmeticError;
+    use sp_runtime::traits::{Dispatchable, Saturating, Zero};
+    use sp_runtime::{ArithmeticError, DigestItem};
     use traits::CallForwarder;
 
     use super::*;
 
+    /// The current storage version, see the `migrations` module for the upgrades to it.
+    const STORAGE_VERSION: StorageVersion = StorageVersion::new(1);
+
     // Axelar pallet type declaration.
     //
     // This structure is a placeholder for traits and functions implementation
     // for the pallet.
     #[pallet::pallet]
     #[pallet::generate_store(pub(super) trait Store)]
-    #[pallet::without_storage_info]
+    #[pallet::storage_version(STORAGE_VERSION)]
     pub struct Pallet<T>(_);
 
     // ------------------------------------------------------------------------
@@ -115,9 +250,82 @@ pub mod pallet {
         #[pallet::constant]
         type ChainId: Get<u32>;
 
+        /// Number of epochs an operator set is accepted for, the current one included.
+        /// Must be at least 1, which only accepts the current operators.
+        ///
+        /// Lowering it expires several epochs at once, run `migrations::PruneExpiredOperators`
+        /// along the upgrade to remove them.
+        #[pallet::constant]
+        type OperatorRetention: Get<u64>;
+
+        /// Format of the proofs the gateway accepts, operator sets are hashed in the same
+        /// format. Changing it requires installing the operators again.
+        #[pallet::constant]
+        type ProofVersion: Get<ProofVersion>;
+
+        /// Domain separator `ProofVersion::WeightedSigners` proofs are signed with, identifying
+        /// this gateway
+        #[pallet::constant]
+        type DomainSeparator: Get<H256>;
+
+        /// Checks the signatures of the operators, `traits::Secp256k1Verifier` for the Ethereum
+        /// keys of Axelar. The `execute` weights are benchmarked with that one.
+        type SignatureVerifier: traits::SignatureVerifier;
+
+        /// Maximum length of a source or destination chain name
+        #[pallet::constant]
+        type MaxChainNameLen: Get<u32>;
+
+        /// Maximum length of a source or destination contract address
+        #[pallet::constant]
+        type MaxAddressLen: Get<u32>;
+
+        /// Maximum length of an outgoing payload or of a forwarded call
+        #[pallet::constant]
+        type MaxPayloadLen: Get<u32>;
+
+        /// Maximum length of an `execute` proof
+        #[pallet::constant]
+        type MaxProofLen: Get<u32>;
+
+        /// Maximum length of an `execute_abi` input, which holds both the signed batch data
+        /// and its proof
+        #[pallet::constant]
+        type MaxInputLen: Get<u32>;
+
+        /// Maximum number of operators in an operator set
+        #[pallet::constant]
+        type MaxOperators: Get<u32>;
+
+        /// Maximum number of calls in an `execute` batch
+        #[pallet::constant]
+        type MaxBatchSize: Get<u32>;
+
+        /// Currency `call_contract` fees are paid with
+        type Currency: Currency<Self::AccountId>;
+
+        /// Fee charged for every `call_contract`
+        #[pallet::constant]
+        type BaseFee: Get<BalanceOf<Self>>;
+
+        /// Fee charged for every byte of a `call_contract` payload
+        #[pallet::constant]
+        type ByteFee: Get<BalanceOf<Self>>;
+
+        /// Account `call_contract` fees are paid to, they are burned when `None`
+        type FeeDestination: Get<Option<Self::AccountId>>;
+
+        /// Number of blocks outbound messages are kept for, older ones are pruned
+        /// when blocks have weight left
+        #[pallet::constant]
+        type OutboundRetention: Get<Self::BlockNumber>;
+
         /// The forwarder for approved calls
         type ApprovedCallForwarder: CallForwarder<Self>;
 
+        /// Origin allowed to install operator sets outside of an `execute` batch
+        type AdminOrigin: EnsureOrigin<<Self as frame_system::Config>::RuntimeOrigin>;
+
         /// Weight information for extrinsics in this pallet
         type WeightInfo: WeightInfo;
     }
@@ -135,6 +343,15 @@ pub mod pallet {
             new_operator_hash: H256,
             new_epoch: u64,
         },
+        OperatorsForceSet {
+            new_operator_hash: H256,
+            new_epoch: u64,
+            revoked_previous: bool,
+        },
+        OperatorsExpired {
+            epoch: u64,
+            operators_hash: H256,
+        },
         ContractCallApproved {
             command_id: H256,
             source_chain: String,
@@ -152,11 +369,41 @@ pub mod pallet {
             error: DispatchError,
         },
         ContractCall {
+            nonce: u64,
             sender: T::AccountId,
             destination_chain: String,
             destination_contract_address: String,
             payload_hash: H256,
             payload: Vec<u8>,
+            fee: BalanceOf<T>,
+        },
+        Paused {
+            traffic: Traffic,
+        },
+        Unpaused {
+            traffic: Traffic,
+        },
+        SourceChainAdded {
+            chain: String,
+            address_policy: AddressPolicy,
+        },
+        SourceChainRemoved {
+            chain: String,
+        },
+        SourceAddressAdded {
+            chain: String,
+            address: String,
+        },
+        SourceAddressRemoved {
+            chain: String,
+            address: String,
+        },
+        DestinationChainAdded {
+            chain: String,
+            address_format: AddressFormat,
+        },
+        DestinationChainRemoved {
+            chain: String,
         },
     }
 
@@ -226,6 +473,205 @@ pub mod pallet {
         ValueQuery,
     >;
 
+    #[pallet::storage]
+    #[pallet::getter(fn paused)]
+    pub(super) type Paused<T: Config> = StorageMap<
+        _,
+        Blake2_128Concat,
+        // Paused traffic
+        Traffic,
+        // Whether it is paused
+        bool,
+        ValueQuery,
+    >;
+
+    #[pallet::storage]
+    #[pallet::getter(fn trusted_source_chain)]
+    pub(super) type TrustedSourceChains<T: Config> = StorageMap<
+        _,
+        Blake2_128Concat,
+        // Source chain name
+        ChainNameOf<T>,
+        // Source addresses allowed to send calls
+        AddressPolicy,
+        OptionQuery,
+    >;
+
+    #[pallet::storage]
+    #[pallet::getter(fn trusted_source_address)]
+    pub(super) type TrustedSourceAddresses<T: Config> = StorageDoubleMap<
+        _,
+        Blake2_128Concat,
+        // Source chain name
+        ChainNameOf<T>,
+        Blake2_128Concat,
+        // Source address
+        AddressOf<T>,
+        // Empty
+        (),
+        OptionQuery,
+    >;
+
+    #[pallet::storage]
+    #[pallet::getter(fn destination_chain)]
+    pub(super) type DestinationChains<T: Config> = StorageMap<
+        _,
+        Blake2_128Concat,
+        // Destination chain name
+        ChainNameOf<T>,
+        // Format of the chain contract addresses
+        AddressFormat,
+        OptionQuery,
+    >;
+
+    #[pallet::storage]
+    #[pallet::getter(fn next_outbound_nonce)]
+    pub(super) type NextOutboundNonce<T: Config> = StorageValue<_, u64, ValueQuery>;
+
+    #[pallet::storage]
+    #[pallet::getter(fn oldest_outbound_nonce)]
+    pub(super) type OldestOutboundNonce<T: Config> = StorageValue<_, u64, ValueQuery>;
+
+    #[pallet::storage]
+    #[pallet::getter(fn committed_outbound_nonce)]
+    pub(super) type CommittedOutboundNonce<T: Config> = StorageValue<_, u64, ValueQuery>;
+
+    #[pallet::storage]
+    #[pallet::getter(fn outbound_commitment)]
+    pub(super) type OutboundCommitments<T: Config> = StorageMap<
+        _,
+        Twox64Concat,
+        // Block the messages were sent in
+        T::BlockNumber,
+        // Merkle commitment to the messages
+        OutboundCommitment,
+        OptionQuery,
+    >;
+
+    #[pallet::storage]
+    #[pallet::getter(fn outbound_message)]
+    pub(super) type OutboundMessages<T: Config> = StorageMap<
+        _,
+        Twox64Concat,
+        // Nonce
+        u64,
+        // Message sent with `call_contract`
+        OutboundMessageOf<T>,
+        OptionQuery,
+    >;
+
+    // ------------------------------------------------------------------------
+    // Pallet lifecycle hooks
+    // ------------------------------------------------------------------------
+
+    #[pallet::hooks]
+    impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+        fn on_initialize(_now: BlockNumberFor<T>) -> Weight {
+            // Messages are charged their share of the commitment by `call_contract`
+            T::WeightInfo::commit_outbound_messages(0)
+        }
+
+        fn on_finalize(now: BlockNumberFor<T>) {
+            Self::commit_outbound_messages(now);
+        }
+
+        fn on_idle(now: BlockNumberFor<T>, remaining_weight: Weight) -> Weight {
+            Self::prune_outbound_messages(now, remaining_weight)
+        }
+
+        fn integrity_test() {
+            assert!(
+                T::OperatorRetention::get() > 0,
+                "OperatorRetention must accept at least the current operators"
+            );
+        }
+    }
+
+    // ------------------------------------------------------------------------
+    // Pallet genesis configuration
+    // ------------------------------------------------------------------------
+
+    /// Initial Axelar operator set, installed as epoch 1, and trusted source chains.
+    ///
+    /// Leaving `operators` empty skips the bootstrap, the gateway then stays
+    /// without operators until storage is seeded some other way. The `nonce` is only
+    /// part of the operator set with `ProofVersion::WeightedSigners`.
+    ///
+    /// Source chains listed in `source_addresses` only trust the addresses listed
+    /// for them, the other `source_chains` trust any address.
+    #[pallet::genesis_config]
+    pub struct GenesisConfig {
+        pub operators: Vec<[u8; 20]>,
+        pub weights: Vec<u128>,
+        pub threshold: u128,
+        pub nonce: H256,
+        pub source_chains: Vec<String>,
+        pub source_addresses: Vec<(String, String)>,
+        pub destination_chains: Vec<(String, AddressFormat)>,
+    }
+
+    #[cfg(feature = "std")]
+    impl Default for GenesisConfig {
+        fn default() -> Self {
+            Self {
+                operators: vec![],
+                weights: vec![],
+                threshold: 0,
+                nonce: H256::zero(),
+                source_chains: vec![],
+                source_addresses: vec![],
+                destination_chains: vec![],
+            }
+        }
+    }
+
+    #[pallet::genesis_build]
+    impl<T: Config> GenesisBuild<T> for GenesisConfig {
+        fn build(&self) {
+            for chain in self.source_chains.iter() {
+                let chain_name = Pallet::<T>::chain_name(chain)
+                    .expect("Genesis source chain names must fit MaxChainNameLen");
+                TrustedSourceChains::<T>::insert(chain_name, AddressPolicy::Any);
+            }
+
+            for (chain, address) in self.source_addresses.iter() {
+                let chain_name = Pallet::<T>::chain_name(chain)
+                    .expect("Genesis source chain names must fit MaxChainNameLen");
+                let address = Pallet::<T>::address(address)
+                    .expect("Genesis source addresses must fit MaxAddressLen");
+                assert!(
+                    TrustedSourceChains::<T>::contains_key(&chain_name),
+                    "Genesis source addresses must belong to one of the source chains"
+                );
+                TrustedSourceChains::<T>::insert(&chain_name, AddressPolicy::TrustedOnly);
+                TrustedSourceAddresses::<T>::insert(chain_name, address, ());
+            }
+
+            for (chain, address_format) in self.destination_chains.iter() {
+                let chain_name = Pallet::<T>::chain_name(chain)
+                    .expect("Genesis destination chain names must fit MaxChainNameLen");
+                DestinationChains::<T>::insert(chain_name, address_format);
+            }
+
+            if self.operators.is_empty() {
+                return;
+            }
+
+            let operators_hash = Pallet::<T>::validate_signers(
+                self.operators.clone(),
+                self.weights.clone(),
+                self.threshold,
+                self.nonce,
+            )
+            .expect("Genesis operators must be valid");
+
+            let epoch = 1;
+            CurrentEpoch::<T>::set(epoch);
+            HashForEpoch::<T>::set(epoch, operators_hash);
+            EpochForHash::<T>::set(operators_hash, epoch);
+        }
+    }
+
     // ------------------------------------------------------------------------
     // Pallet errors
     // ------------------------------------------------------------------------
@@ -244,6 +690,39 @@ pub mod pallet {
         WrongChainId,
         ContractCallNotApproved,
         ErrorForwarding,
+        ChainNameTooLong,
+        AddressTooLong,
+        ProofTooLong,
+        TooManyOperators,
+        GatewayPaused,
+        UntrustedSourceChain,
+        UntrustedSourceAddress,
+        UnknownSourceChain,
+        UnknownSourceAddress,
+        UnsupportedDestinationChain,
+        InvalidDestinationAddress,
+        UnknownDestinationChain,
+        FailedToDecodeInput,
+        FailedToDecodeCommandParams,
+        UnknownCommand,
+        CommandMismatch,
+        ProofWeightsLengthMismatch,
+        ProofValueOverflow,
+        InvalidSignatureLength,
+        UnsupportedProofVersion,
+    }
+
+    impl<T> From<proof::ProofError> for Error<T> {
+        fn from(e: proof::ProofError) -> Self {
+            match e {
+                proof::ProofError::InvalidEncoding | proof::ProofError::InvalidToken => {
+                    Error::<T>::FailedToDecodeProof
+                }
+                proof::ProofError::WeightsLengthMismatch => Error::<T>::ProofWeightsLengthMismatch,
+                proof::ProofError::ValueOverflow => Error::<T>::ProofValueOverflow,
+                proof::ProofError::InvalidSignatureLength => Error::<T>::InvalidSignatureLength,
+            }
+        }
     }
 
     // ------------------------------------------------------------------------
@@ -254,12 +733,16 @@ pub mod pallet {
     impl<T: Config> Pallet<T> {
         /// Main entrypoint for outgoing messaging to destination chains
         #[pallet::call_index(0)]
-        #[pallet::weight(<T as pallet::Config>::WeightInfo::call_contract())]
+        #[pallet::weight({
+            let commitment = <T as pallet::Config>::WeightInfo::commit_outbound_messages(1)
+                .saturating_sub(<T as pallet::Config>::WeightInfo::commit_outbound_messages(0));
+            <T as pallet::Config>::WeightInfo::call_contract(payload.len() as u32).saturating_add(commitment)
+        })]
         pub fn call_contract(
             origin: OriginFor<T>,
-            destination_chain: String,
-            destination_contract_address: String,
-            payload: Vec<u8>,
+            destination_chain: ChainNameOf<T>,
+            destination_contract_address: AddressOf<T>,
+            payload: PayloadOf<T>,
         ) -> DispatchResult {
             //TODO: It is important that the sender is identified and propagated so
             // destination chains contracts can apply any authorization needed
@@ -267,12 +750,46 @@ pub mod pallet {
             // ensure_signed might not make sense
             let who = ensure_signed(origin)?;
 
+            Self::ensure_not_paused(Traffic::Outbound)?;
+
+            // Axelar silently drops messages to chains or addresses it can't route to
+            let address_format = DestinationChains::<T>::get(&destination_chain)
+                .ok_or(Error::<T>::UnsupportedDestinationChain)?;
+            let chain = Self::utf8(&destination_chain, Error::<T>::UnsupportedDestinationChain)?;
+            let contract_address = Self::utf8(
+                &destination_contract_address,
+                Error::<T>::InvalidDestinationAddress,
+            )?;
+            ensure!(
+                address_format.is_valid(&contract_address),
+                Error::<T>::InvalidDestinationAddress
+            );
+
+            let fee = Self::call_contract_fee(payload.len() as u32);
+            Self::charge_fee(&who, fee)?;
+
+            let payload_hash = H256::from_slice(keccak_256(&payload).as_slice());
+            let nonce = NextOutboundNonce::<T>::get();
+            OutboundMessages::<T>::insert(
+                nonce,
+                OutboundMessage {
+                    sender: who.clone(),
+                    destination_chain,
+                    destination_contract_address,
+                    payload_hash,
+                    block_number: frame_system::Pallet::<T>::block_number(),
+                },
+            );
+            NextOutboundNonce::<T>::put(nonce.saturating_add(1));
+
             Self::deposit_event(Event::ContractCall {
+                nonce,
                 sender: who,
-                destination_chain,
-                destination_contract_address,
-                payload_hash: H256::from_slice(keccak_256(&payload).as_slice()),
-                payload,
+                destination_chain: chain,
+                destination_contract_address: contract_address,
+                payload_hash,
+                payload: payload.into_inner(),
+                fee,
             });
 
             Ok(())
@@ -281,18 +798,29 @@ pub mod pallet {
         /// Executes a batch of calls previously approved by the Axelar Consensus
         ///
         /// command_ids: ordered list of uuid that identify each command within the batch
-        /// commands: ordered list of which command triggered the call. It is a fixed set of options burnToken|contractCall|...
-        /// calls: the actual call to be executed in the gateway contract (it contains as well any other final calls)
+        /// commands: ordered list of which command triggered the call, by its ABI name (see `Command`)
+        /// calls: the gateway call of each command, the only call that command runs
+        ///
+        /// Each call must be the gateway call of its command, and an approval must carry the
+        /// command id it is signed under. Unknown commands and commands carrying another call
+        /// are reported with `ItemFailed` without failing the batch.
         ///
         /// The weight definition taken from Substrate Utility.force_batch, not sure if there is a more succinct and maintainable
-        /// way to ensure the call is properly weighted
+        /// way to ensure the call is properly weighted. The proof verification is charged upfront for every signature and
+        /// operator the proof claims to hold, and refunded down to the signatures actually checked.
         #[pallet::call_index(1)]
         #[pallet::weight({
             let dispatch_infos = calls.iter().map(|call| call.get_dispatch_info()).collect::<Vec<_>>();
+            let (signatures, operators) = crate::proof::estimate_counts(T::ProofVersion::get(), proof);
             let dispatch_weight = dispatch_infos.iter()
                 .map(|di| di.weight)
                 .fold(Weight::zero(), |total: Weight, weight: Weight| total.saturating_add(weight))
-                .saturating_add(<T as pallet::Config>::WeightInfo::execute(calls.len() as u32));
+                .saturating_add(<T as pallet::Config>::WeightInfo::execute(
+                    calls.len() as u32,
+                    signatures,
+                    operators,
+                    Pallet::<T>::calls_size(calls),
+                ));
             let dispatch_class = {
                 let all_operational = dispatch_infos.iter()
                     .map(|di| di.class)
@@ -307,61 +835,71 @@ pub mod pallet {
         })]
         pub fn execute(
             origin: OriginFor<T>,
-            proof: Vec<u8>,
+            proof: BoundedVec<u8, T::MaxProofLen>,
             chain_id: u32,
-            command_ids: Vec<H256>,
-            commands: Vec<String>,
-            calls: Vec<<T as Config>::RuntimeCall>,
+            command_ids: BoundedVec<H256, T::MaxBatchSize>,
+            commands: BoundedVec<CommandName, T::MaxBatchSize>,
+            calls: BoundedVec<<T as Config>::RuntimeCall, T::MaxBatchSize>,
         ) -> DispatchResultWithPostInfo {
             let _ = ensure_signed(origin)?;
 
+            Self::ensure_not_paused(Traffic::Inbound)?;
+            // Axelar only signs commands named by strings
+            let commands = commands
+                .iter()
+                .map(|command| Self::utf8(command, Error::<T>::UnknownCommand))
+                .collect::<Result<Vec<_>, _>>()?;
+
             // TODO: Once XCM is enabled this check might not make sense
             ensure!(chain_id == T::ChainId::get(), Error::<T>::WrongChainId);
 
             ensure!(
-                calls.len() == command_ids.len(),
+                calls.len() == command_ids.len() && commands.len() == command_ids.len(),
                 Error::<T>::CommandIdsLengthMismatch
             );
 
-            let payload = Self::abi_encode_batch_params(
-                chain_id.clone(),
-                command_ids.clone(),
-                commands.clone(),
-                calls.clone(),
-            );
-            // TODO: Double check on Axelar if they always prepend the eth prefix
-            let payload_hash = H256::from_slice(&ecdsa::to_eth_signed_message_hash(keccak_256(
-                payload.as_slice(),
-            )));
-            let mut is_active_operators = Self::validate_proof(payload_hash, &proof)?;
-
             // Code simplified taken from https://github.com/paritytech/substrate/blob/ee316317b85b2f65fc022b27bbfefcd42b6560ae/frame/utility/src/lib.rs#L440
             let calls_len = calls.len();
+            let calls_size = Self::calls_size(&calls);
             ensure!(
-                calls_len <= Self::batched_calls_limit() as usize,
+                calls_len <= Self::max_batch_size() as usize,
                 Error::<T>::TooManyCalls
             );
 
+            let payload = Self::abi_encode_batch_params(
+                chain_id.clone(),
+                command_ids.to_vec(),
+                commands.clone(),
+                calls.to_vec(),
+            );
+            let (mut is_active_operators, signatures, operators) =
+                Self::do_validate_proof(Self::data_hash(&payload), &proof)?;
+
             // Track the actual weight of each of the batch calls.
             let mut weight = Weight::zero();
             // Track failed dispatch occur.
             let mut has_error = false;
             for (idx, call) in calls.into_iter().enumerate() {
-                if CommandExecuted::<T>::contains_key(command_ids[idx]) {
+                let error = match Self::command_status(
+                    command_ids[idx],
+                    &commands[idx],
+                    &call,
+                    &mut is_active_operators,
+                ) {
+                    CommandStatus::Run => None,
+                    CommandStatus::AlreadyExecuted | CommandStatus::NotActiveOperators => continue,
+                    CommandStatus::UnknownCommand => Some(Error::<T>::UnknownCommand),
+                    CommandStatus::CommandMismatch => Some(Error::<T>::CommandMismatch),
+                };
+                if let Some(error) = error {
+                    has_error = true;
+                    Self::deposit_event(Event::ItemFailed {
+                        index: idx as u32,
+                        error: error.into(),
+                    });
                     continue;
                 }
 
-                match call.is_sub_type() {
-                    Some(Call::transfer_operatorship { .. }) => {
-                        if !is_active_operators {
-                            continue;
-                        }
-                        is_active_operators = false;
-                    }
-                    Some(Call::approve_contract_call { .. }) => {}
-                    _ => continue,
-                }
-
                 let info = call.get_dispatch_info();
                 CommandExecuted::<T>::set(command_ids[idx], chain_id);
 
@@ -386,7 +924,12 @@ pub mod pallet {
                 Self::deposit_event(Event::BatchCompleted);
             }
 
-            let base_weight = <T as pallet::Config>::WeightInfo::execute(calls_len as u32);
+            let base_weight = <T as pallet::Config>::WeightInfo::execute(
+                calls_len as u32,
+                signatures,
+                operators,
+                calls_size,
+            );
             Ok(Some(base_weight.saturating_add(weight)).into())
         }
 
@@ -401,35 +944,21 @@ pub mod pallet {
             // Ensure only gateway origin can call this
             let _ = EnsureGateway::ensure_origin(origin)?;
 
-            let new_operator_hash =
-                Self::validate_operatorship(new_operators, new_weights, new_threshold)?;
-
-            ensure!(
-                !EpochForHash::<T>::contains_key(new_operator_hash),
-                Error::<T>::DuplicateOperators
-            );
-
-            let epoch = CurrentEpoch::<T>::get()
-                .checked_add(1)
-                .ok_or(ArithmeticError::Overflow)?;
-            CurrentEpoch::<T>::set(epoch);
-            HashForEpoch::<T>::set(epoch, new_operator_hash);
-            EpochForHash::<T>::set(new_operator_hash, epoch);
-
-            Self::deposit_event(Event::OperatorshipTransferred {
-                new_operator_hash,
-                new_epoch: epoch,
-            });
-
-            Ok(())
+            Self::do_transfer_operatorship(new_operators, new_weights, new_threshold)
         }
 
+        /// Approves a contract call from `source_chain`
+        ///
+        /// `payload_hash` is the plain `keccak256` of the payload, as emitted by `callContract`
+        /// on EVM gateways; [`Pallet::forward_approved_call`] looks the approval up under it.
         #[pallet::call_index(3)]
-        #[pallet::weight(<T as pallet::Config>::WeightInfo::approve_contract_call())]
+        #[pallet::weight(<T as pallet::Config>::WeightInfo::approve_contract_call(
+            (source_chain.len() + source_address.len()) as u32
+        ))]
         pub fn approve_contract_call(
             origin: OriginFor<T>,
-            source_chain: String,
-            source_address: String,
+            source_chain: ChainNameOf<T>,
+            source_address: AddressOf<T>,
             contract_address: H160,
             payload_hash: H256,
             source_tx_hash: H256,
@@ -439,58 +968,57 @@ pub mod pallet {
             // Ensure only gateway origin can call this
             let _ = EnsureGateway::ensure_origin(origin)?;
 
-            let mut payload = command_id.encode();
-            payload.append(&mut source_chain.encode());
-            payload.append(&mut source_address.encode());
-            payload.append(&mut contract_address.encode());
-            payload.append(&mut payload_hash.encode());
-
-            ContractCallApproved::<T>::set(H256::from(keccak_256(payload.as_slice())), ());
-
-            Self::deposit_event(Event::ContractCallApproved {
-                command_id,
-                source_chain,
-                source_address,
+            Self::do_approve_contract_call(
+                Self::utf8(&source_chain, Error::<T>::UntrustedSourceChain)?,
+                Self::utf8(&source_address, Error::<T>::UntrustedSourceAddress)?,
                 contract_address,
                 payload_hash,
                 source_tx_hash,
                 source_event_index,
-            });
-
-            Ok(())
+                command_id,
+            )
         }
 
+        /// Forwards `call` once it has been approved with `keccak256(call)` as its payload hash
         #[pallet::call_index(4)]
         #[pallet::weight({
-            let total_weight = <T as pallet::Config>::WeightInfo::forward_approved_call();
+            let total_weight = <T as pallet::Config>::WeightInfo::forward_approved_call(call.len() as u32);
             if <T as pallet::Config>::ApprovedCallForwarder::is_local() {
-                let cc = <T as Config>::RuntimeCall::decode(&mut &call[..]).unwrap();
-                total_weight.saturating_add(cc.get_dispatch_info().weight);
+                // Undecodable calls are rejected when forwarding, so they only pay the base weight
+                let call_weight = <T as Config>::RuntimeCall::decode(&mut &call[..])
+                    .map(|c| c.get_dispatch_info().weight)
+                    .unwrap_or_default();
+                total_weight.saturating_add(call_weight)
+            } else {
+                total_weight
             }
-            total_weight
         })]
         pub fn forward_approved_call(
             origin: OriginFor<T>,
             command_id: H256,
-            source_chain: String,
-            source_address: String,
+            source_chain: ChainNameOf<T>,
+            source_address: AddressOf<T>,
             contract_address: H160,
-            call: Vec<u8>,
+            call: PayloadOf<T>,
         ) -> DispatchResult {
             let _ = ensure_signed(origin)?;
 
-            // TODO: keccak_256 is the Axelar Gateway standard hashing at origin on EVM chains, check if it is consistent in every contractCall on every chain
-            let call_hash = H256::from_slice(&ecdsa::to_eth_signed_message_hash(keccak_256(
-                call.as_slice(),
-            )));
-            // TODO: Does it need to be ABI encoded?
-            let mut approved_call = command_id.encode();
-            approved_call.append(&mut source_chain.encode());
-            approved_call.append(&mut source_address.encode());
-            approved_call.append(&mut contract_address.encode());
-            approved_call.append(&mut call_hash.encode());
+            Self::ensure_not_paused(Traffic::Inbound)?;
+            // The source was checked when the call was approved, so approved calls aren't
+            // stranded when their source chain is removed from the registry later on
+            let source_chain = Self::utf8(&source_chain, Error::<T>::ContractCallNotApproved)?;
+            let source_address = Self::utf8(&source_address, Error::<T>::ContractCallNotApproved)?;
 
-            let approved_call_hash = H256::from(keccak_256(approved_call.as_slice()));
+            // Same convention as `validateContractCall` on EVM gateways and as the `payloadHash`
+            // carried by `approveContractCall` commands
+            let call_hash = H256(keccak_256(call.as_slice()));
+            let approved_call_hash = Self::approved_call_hash(
+                command_id,
+                source_chain.as_bytes(),
+                source_address.as_bytes(),
+                contract_address,
+                call_hash,
+            );
 
             // Ensure the call has been approved by the bridge beforehand
             ensure!(
@@ -509,26 +1037,728 @@ pub mod pallet {
                 source_address,
                 contract_address,
                 dest,
-                call,
+                call.into_inner(),
             )?;
 
             Ok(())
         }
-    }
-
-    impl<T: Config> Pallet<T> {
-        // Code taken from https://github.com/paritytech/substrate/blob/ee316317b85b2f65fc022b27bbfefcd42b6560ae/frame/utility/src/lib.rs#L133
-        fn batched_calls_limit() -> u32 {
-            let allocator_limit = sp_core::MAX_POSSIBLE_ALLOCATION;
-            let call_size =
-                ((sp_std::mem::size_of::<<T as Config>::RuntimeCall>() as u32 + CALL_ALIGN - 1)
-                    / CALL_ALIGN)
-                    * CALL_ALIGN;
-            // The margin to take into account vec doubling capacity.
-            let margin_factor = 3;
-
-            allocator_limit / margin_factor / call_size
-        }
+
+        /// Installs a new operator set without an Axelar signed batch
+        ///
+        /// Meant for bootstrapping and for recovering from compromised or lost operator keys.
+        /// When `revoke_previous` is set, every earlier operator set stops being accepted
+        /// straight away instead of fading out through the key retention period.
+        ///
+        /// The `nonce` is only part of the operator set with `ProofVersion::WeightedSigners`.
+        #[pallet::call_index(5)]
+        #[pallet::weight({
+            let weight = <T as pallet::Config>::WeightInfo::force_set_operators(new_operators.len() as u32);
+            if *revoke_previous {
+                // Revoking reads and forgets every operator set of the key retention period
+                let retention = T::OperatorRetention::get();
+                weight.saturating_add(T::DbWeight::get().reads_writes(retention, retention))
+            } else {
+                weight
+            }
+        })]
+        pub fn force_set_operators(
+            origin: OriginFor<T>,
+            new_operators: Vec<[u8; 20]>,
+            new_weights: Vec<u128>,
+            new_threshold: u128,
+            nonce: H256,
+            revoke_previous: bool,
+        ) -> DispatchResult {
+            T::AdminOrigin::ensure_origin(origin)?;
+
+            let new_operator_hash =
+                Self::validate_signers(new_operators, new_weights, new_threshold, nonce)?;
+
+            if revoke_previous {
+                Self::revoke_operators();
+            }
+
+            let epoch = Self::set_operators(new_operator_hash)?;
+
+            Self::deposit_event(Event::OperatorsForceSet {
+                new_operator_hash,
+                new_epoch: epoch,
+                revoked_previous: revoke_previous,
+            });
+
+            Ok(())
+        }
+
+        /// Stops the `traffic` going through the gateway until it is unpaused
+        #[pallet::call_index(6)]
+        #[pallet::weight(<T as pallet::Config>::WeightInfo::pause())]
+        pub fn pause(origin: OriginFor<T>, traffic: Traffic) -> DispatchResult {
+            T::AdminOrigin::ensure_origin(origin)?;
+
+            Paused::<T>::insert(traffic, true);
+
+            Self::deposit_event(Event::Paused { traffic });
+
+            Ok(())
+        }
+
+        /// Lets the `traffic` go through the gateway again
+        #[pallet::call_index(7)]
+        #[pallet::weight(<T as pallet::Config>::WeightInfo::unpause())]
+        pub fn unpause(origin: OriginFor<T>, traffic: Traffic) -> DispatchResult {
+            T::AdminOrigin::ensure_origin(origin)?;
+
+            Paused::<T>::remove(traffic);
+
+            Self::deposit_event(Event::Unpaused { traffic });
+
+            Ok(())
+        }
+
+        /// Trusts calls approved from `chain`, or changes which of its addresses are trusted
+        #[pallet::call_index(8)]
+        #[pallet::weight(<T as pallet::Config>::WeightInfo::add_source_chain())]
+        pub fn add_source_chain(
+            origin: OriginFor<T>,
+            chain: String,
+            address_policy: AddressPolicy,
+        ) -> DispatchResult {
+            T::AdminOrigin::ensure_origin(origin)?;
+
+            TrustedSourceChains::<T>::insert(Self::chain_name(&chain)?, address_policy);
+
+            Self::deposit_event(Event::SourceChainAdded {
+                chain,
+                address_policy,
+            });
+
+            Ok(())
+        }
+
+        /// Stops trusting calls from `chain`, the calls it already approved can still be forwarded
+        ///
+        /// The addresses registered for the chain are kept, and trusted again if the chain is added back.
+        #[pallet::call_index(9)]
+        #[pallet::weight(<T as pallet::Config>::WeightInfo::remove_source_chain())]
+        pub fn remove_source_chain(origin: OriginFor<T>, chain: String) -> DispatchResult {
+            T::AdminOrigin::ensure_origin(origin)?;
+
+            let chain_name = Self::chain_name(&chain)?;
+            ensure!(
+                TrustedSourceChains::<T>::contains_key(&chain_name),
+                Error::<T>::UnknownSourceChain
+            );
+            TrustedSourceChains::<T>::remove(chain_name);
+
+            Self::deposit_event(Event::SourceChainRemoved { chain });
+
+            Ok(())
+        }
+
+        /// Trusts calls from `address` on `chain`, when the chain only trusts registered addresses
+        #[pallet::call_index(10)]
+        #[pallet::weight(<T as pallet::Config>::WeightInfo::add_source_address())]
+        pub fn add_source_address(
+            origin: OriginFor<T>,
+            chain: String,
+            address: String,
+        ) -> DispatchResult {
+            T::AdminOrigin::ensure_origin(origin)?;
+
+            TrustedSourceAddresses::<T>::insert(
+                Self::chain_name(&chain)?,
+                Self::address(&address)?,
+                (),
+            );
+
+            Self::deposit_event(Event::SourceAddressAdded { chain, address });
+
+            Ok(())
+        }
+
+        /// Stops trusting calls from `address` on `chain`
+        #[pallet::call_index(11)]
+        #[pallet::weight(<T as pallet::Config>::WeightInfo::remove_source_address())]
+        pub fn remove_source_address(
+            origin: OriginFor<T>,
+            chain: String,
+            address: String,
+        ) -> DispatchResult {
+            T::AdminOrigin::ensure_origin(origin)?;
+
+            let chain_name = Self::chain_name(&chain)?;
+            let source_address = Self::address(&address)?;
+            ensure!(
+                TrustedSourceAddresses::<T>::contains_key(&chain_name, &source_address),
+                Error::<T>::UnknownSourceAddress
+            );
+            TrustedSourceAddresses::<T>::remove(chain_name, source_address);
+
+            Self::deposit_event(Event::SourceAddressRemoved { chain, address });
+
+            Ok(())
+        }
+
+        /// Allows `call_contract` to `chain`, or changes the format of its addresses
+        #[pallet::call_index(12)]
+        #[pallet::weight(<T as pallet::Config>::WeightInfo::add_destination_chain())]
+        pub fn add_destination_chain(
+            origin: OriginFor<T>,
+            chain: String,
+            address_format: AddressFormat,
+        ) -> DispatchResult {
+            T::AdminOrigin::ensure_origin(origin)?;
+
+            DestinationChains::<T>::insert(Self::chain_name(&chain)?, address_format);
+
+            Self::deposit_event(Event::DestinationChainAdded {
+                chain,
+                address_format,
+            });
+
+            Ok(())
+        }
+
+        /// Stops `call_contract` to `chain`
+        #[pallet::call_index(13)]
+        #[pallet::weight(<T as pallet::Config>::WeightInfo::remove_destination_chain())]
+        pub fn remove_destination_chain(origin: OriginFor<T>, chain: String) -> DispatchResult {
+            T::AdminOrigin::ensure_origin(origin)?;
+
+            let chain_name = Self::chain_name(&chain)?;
+            ensure!(
+                DestinationChains::<T>::contains_key(&chain_name),
+                Error::<T>::UnknownDestinationChain
+            );
+            DestinationChains::<T>::remove(chain_name);
+
+            Self::deposit_event(Event::DestinationChainRemoved { chain });
+
+            Ok(())
+        }
+
+        /// Executes a batch signed by the Axelar operators, given the exact input of the EVM
+        /// gateway `execute`: the ABI encoded `(bytes data, bytes proof)`.
+        ///
+        /// Each command is run from its ABI params, as the EVM gateway does. Unknown and
+        /// failing commands are reported with `ItemFailed` without failing the batch.
+        #[pallet::call_index(14)]
+        #[pallet::weight({
+            let (commands, signatures, operators) = crate::abi::estimate_counts(T::ProofVersion::get(), input);
+            // Only one operatorship transfer can run per batch
+            <T as pallet::Config>::WeightInfo::execute_abi(commands, signatures, operators, input.len() as u32)
+                .saturating_add(<T as pallet::Config>::WeightInfo::transfer_operatorship(T::MaxOperators::get()))
+        })]
+        pub fn execute_abi(
+            origin: OriginFor<T>,
+            input: BoundedVec<u8, T::MaxInputLen>,
+        ) -> DispatchResultWithPostInfo {
+            let _ = ensure_signed(origin)?;
+
+            Self::ensure_not_paused(Traffic::Inbound)?;
+
+            let (data, proof) =
+                abi::decode_input(&input).map_err(|_| Error::<T>::FailedToDecodeInput)?;
+            let batch = abi::decode_data(&data).map_err(|_| Error::<T>::FailedToDecodeInput)?;
+
+            let chain_id = T::ChainId::get();
+            ensure!(batch.chain_id == chain_id.into(), Error::<T>::WrongChainId);
+
+            let commands_len = batch.command_ids.len();
+            ensure!(
+                batch.commands.len() == commands_len && batch.params.len() == commands_len,
+                Error::<T>::CommandIdsLengthMismatch
+            );
+            ensure!(
+                commands_len <= Self::max_batch_size() as usize,
+                Error::<T>::TooManyCalls
+            );
+            Self::ensure_within::<T::MaxProofLen>(proof.len(), Error::<T>::ProofTooLong)?;
+
+            let (mut is_active_operators, signatures, operators) =
+                Self::do_validate_proof(Self::data_hash(&data), &proof)?;
+
+            // Weight of the operatorship transfer, the other commands are part of the base weight
+            let mut weight = Weight::zero();
+            let mut has_error = false;
+            let commands = batch
+                .command_ids
+                .into_iter()
+                .zip(batch.commands.into_iter().zip(batch.params.into_iter()));
+            for (idx, (command_id, (command, params))) in commands.enumerate() {
+                if CommandExecuted::<T>::contains_key(command_id) {
+                    continue;
+                }
+
+                let result = match Command::from_abi(&command) {
+                    Some(Command::ApproveContractCall) => storage::with_storage_layer(|| {
+                        Self::execute_approve_contract_call(command_id, &params)
+                    }),
+                    Some(command @ (Command::TransferOperatorship | Command::RotateSigners)) => {
+                        if !is_active_operators {
+                            continue;
+                        }
+                        is_active_operators = false;
+                        storage::with_storage_layer(|| {
+                            let operators = if command == Command::RotateSigners {
+                                Self::execute_rotate_signers(&params)?
+                            } else {
+                                Self::execute_transfer_operatorship(&params)?
+                            };
+                            weight =
+                                <T as pallet::Config>::WeightInfo::transfer_operatorship(operators);
+                            Ok(())
+                        })
+                    }
+                    None => Err(Error::<T>::UnknownCommand.into()),
+                };
+
+                if let Err(error) = result {
+                    has_error = true;
+                    Self::deposit_event(Event::ItemFailed {
+                        index: idx as u32,
+                        error,
+                    });
+                } else {
+                    CommandExecuted::<T>::set(command_id, chain_id);
+                    Self::deposit_event(Event::ItemCompleted);
+                }
+            }
+
+            if has_error {
+                Self::deposit_event(Event::BatchCompletedWithErrors);
+            } else {
+                Self::deposit_event(Event::BatchCompleted);
+            }
+
+            let base_weight = <T as pallet::Config>::WeightInfo::execute_abi(
+                commands_len as u32,
+                signatures,
+                operators,
+                input.len() as u32,
+            );
+            Ok(Some(base_weight.saturating_add(weight)).into())
+        }
+
+        /// Installs the operator set of a `rotateSigners` command, the counterpart of
+        /// `transfer_operatorship` for `ProofVersion::WeightedSigners` gateways.
+        #[pallet::call_index(15)]
+        #[pallet::weight(<T as pallet::Config>::WeightInfo::transfer_operatorship(new_signers.len() as u32))]
+        pub fn rotate_signers(
+            origin: OriginFor<T>,
+            new_signers: Vec<[u8; 20]>,
+            new_weights: Vec<u128>,
+            new_threshold: u128,
+            nonce: H256,
+        ) -> DispatchResult {
+            // Ensure only gateway origin can call this
+            let _ = EnsureGateway::ensure_origin(origin)?;
+
+            Self::do_rotate_signers(new_signers, new_weights, new_threshold, nonce)
+        }
+    }
+
+    impl<T: Config> Pallet<T> {
+        // Code taken from https://github.com/paritytech/substrate/blob/ee316317b85b2f65fc022b27bbfefcd42b6560ae/frame/utility/src/lib.rs#L133
+        fn batched_calls_limit() -> u32 {
+            let allocator_limit = sp_core::MAX_POSSIBLE_ALLOCATION;
+            let call_size =
+                ((sp_std::mem::size_of::<<T as Config>::RuntimeCall>() as u32 + CALL_ALIGN - 1)
+                    / CALL_ALIGN)
+                    * CALL_ALIGN;
+            // The margin to take into account vec doubling capacity.
+            let margin_factor = 3;
+
+            allocator_limit / margin_factor / call_size
+        }
+
+        /// Number of calls an `execute` batch may hold, the configured `MaxBatchSize`
+        /// as long as the allocator can cope with it.
+        fn max_batch_size() -> u32 {
+            T::MaxBatchSize::get().min(Self::batched_calls_limit())
+        }
+
+        /// Encoded length of the calls of an `execute` batch, which are encoded again into the
+        /// signed batch.
+        pub(crate) fn calls_size(calls: &[<T as Config>::RuntimeCall]) -> u32 {
+            calls.iter().fold(0u32, |size, call| {
+                size.saturating_add(call.encoded_size() as u32)
+            })
+        }
+
+        /// Installs a new operator set signed off by the current one.
+        fn do_transfer_operatorship(
+            new_operators: Vec<[u8; 20]>,
+            new_weights: Vec<u128>,
+            new_threshold: u128,
+        ) -> DispatchResult {
+            ensure!(
+                T::ProofVersion::get() == ProofVersion::Legacy,
+                Error::<T>::UnsupportedProofVersion
+            );
+
+            let new_operator_hash =
+                Self::validate_operatorship(new_operators, new_weights, new_threshold)?;
+            let epoch = Self::set_operators(new_operator_hash)?;
+
+            Self::deposit_event(Event::OperatorshipTransferred {
+                new_operator_hash,
+                new_epoch: epoch,
+            });
+
+            Ok(())
+        }
+
+        /// Installs a new signer set signed off by the current one, told apart from earlier
+        /// sets of the same signers by its `nonce`.
+        fn do_rotate_signers(
+            new_signers: Vec<[u8; 20]>,
+            new_weights: Vec<u128>,
+            new_threshold: u128,
+            nonce: H256,
+        ) -> DispatchResult {
+            ensure!(
+                T::ProofVersion::get() == ProofVersion::WeightedSigners,
+                Error::<T>::UnsupportedProofVersion
+            );
+
+            let new_operator_hash =
+                Self::validate_signers(new_signers, new_weights, new_threshold, nonce)?;
+            let epoch = Self::set_operators(new_operator_hash)?;
+
+            Self::deposit_event(Event::OperatorshipTransferred {
+                new_operator_hash,
+                new_epoch: epoch,
+            });
+
+            Ok(())
+        }
+
+        /// Approves a contract call from a trusted source, to be forwarded with
+        /// `forward_approved_call`.
+        fn do_approve_contract_call(
+            source_chain: String,
+            source_address: String,
+            contract_address: H160,
+            payload_hash: H256,
+            source_tx_hash: H256,
+            source_event_index: U256,
+            command_id: H256,
+        ) -> DispatchResult {
+            Self::ensure_trusted_source(&source_chain, &source_address)?;
+
+            let approved_call_hash = Self::approved_call_hash(
+                command_id,
+                source_chain.as_bytes(),
+                source_address.as_bytes(),
+                contract_address,
+                payload_hash,
+            );
+
+            ContractCallApproved::<T>::set(approved_call_hash, ());
+
+            Self::deposit_event(Event::ContractCallApproved {
+                command_id,
+                source_chain,
+                source_address,
+                contract_address,
+                payload_hash,
+                source_tx_hash,
+                source_event_index,
+            });
+
+            Ok(())
+        }
+
+        /// Runs an `approveContractCall` command from its ABI params.
+        fn execute_approve_contract_call(command_id: H256, params: &[u8]) -> DispatchResult {
+            let params = abi::decode_approve_contract_call(params)
+                .map_err(|_| Error::<T>::FailedToDecodeCommandParams)?;
+
+            Self::do_approve_contract_call(
+                params.source_chain,
+                params.source_address,
+                params.contract_address,
+                params.payload_hash,
+                params.source_tx_hash,
+                params.source_event_index,
+                command_id,
+            )
+        }
+
+        /// Runs a `transferOperatorship` command from its ABI params, returning the number of
+        /// new operators.
+        fn execute_transfer_operatorship(params: &[u8]) -> Result<u32, DispatchError> {
+            let params = abi::decode_transfer_operatorship(params)
+                .map_err(|_| Error::<T>::FailedToDecodeCommandParams)?;
+            let operators = params.new_operators.len() as u32;
+
+            Self::do_transfer_operatorship(
+                params.new_operators,
+                params.new_weights,
+                params.new_threshold,
+            )?;
+
+            Ok(operators)
+        }
+
+        /// Runs a `rotateSigners` command from its ABI `params`, returning the number of signers.
+        fn execute_rotate_signers(params: &[u8]) -> Result<u32, DispatchError> {
+            let params = abi::decode_rotate_signers(params)
+                .map_err(|_| Error::<T>::FailedToDecodeCommandParams)?;
+            let signers = params.new_signers.len() as u32;
+
+            Self::do_rotate_signers(
+                params.new_signers,
+                params.new_weights,
+                params.new_threshold,
+                params.nonce,
+            )?;
+
+            Ok(signers)
+        }
+
+        /// Fails with `GatewayPaused` while the `traffic` is paused.
+        fn ensure_not_paused(traffic: Traffic) -> DispatchResult {
+            ensure!(!Self::paused(traffic), Error::<T>::GatewayPaused);
+            Ok(())
+        }
+
+        /// Fee `call_contract` charges for a payload of `payload_len` bytes.
+        pub fn call_contract_fee(payload_len: u32) -> BalanceOf<T> {
+            T::BaseFee::get().saturating_add(T::ByteFee::get().saturating_mul(payload_len.into()))
+        }
+
+        /// Up to `limit` outbound messages still kept, from `start_nonce` onwards, along with
+        /// their nonce. The next page starts after the last nonce returned.
+        pub fn outbound_messages(start_nonce: u64, limit: u32) -> Vec<(u64, OutboundMessageOf<T>)> {
+            let start = start_nonce.max(OldestOutboundNonce::<T>::get());
+            let end = NextOutboundNonce::<T>::get().min(start.saturating_add(limit.into()));
+
+            (start..end)
+                .filter_map(|nonce| OutboundMessages::<T>::get(nonce).map(|m| (nonce, m)))
+                .collect()
+        }
+
+        /// Proof that the outbound message with `nonce` is committed to in the digest of the
+        /// block it was sent in, `None` until the block is finalized or once the messages of
+        /// the block start being pruned.
+        pub fn outbound_message_proof(nonce: u64) -> Option<OutboundMessageProof> {
+            let message = OutboundMessages::<T>::get(nonce)?;
+            let commitment = OutboundCommitments::<T>::get(message.block_number)?;
+            if commitment.first_nonce < OldestOutboundNonce::<T>::get() {
+                return None;
+            }
+
+            let end = commitment.first_nonce + commitment.message_count as u64;
+            let leaves = (commitment.first_nonce..end)
+                .map(|nonce| {
+                    OutboundMessages::<T>::get(nonce).map(|m| merkle::leaf_hash(nonce, &m))
+                })
+                .collect::<Option<Vec<_>>>()?;
+            let leaf_index = (nonce - commitment.first_nonce) as u32;
+
+            Some(OutboundMessageProof {
+                leaf: leaves[leaf_index as usize],
+                leaf_index,
+                leaf_count: commitment.message_count,
+                proof: merkle::merkle_proof(&leaves, leaf_index as usize),
+            })
+        }
+
+        /// Commits to the messages sent since the last commitment, i.e. in block `now`, by
+        /// depositing their Merkle root in the block digest.
+        pub(crate) fn commit_outbound_messages(now: T::BlockNumber) {
+            let first_nonce = CommittedOutboundNonce::<T>::get();
+            let next = NextOutboundNonce::<T>::get();
+            if first_nonce == next {
+                return;
+            }
+
+            let leaves: Vec<H256> = (first_nonce..next)
+                .filter_map(|nonce| {
+                    OutboundMessages::<T>::get(nonce).map(|m| merkle::leaf_hash(nonce, &m))
+                })
+                .collect();
+            let commitment = OutboundCommitment {
+                first_nonce,
+                message_count: (next - first_nonce) as u32,
+                root: merkle::merkle_root(&leaves),
+            };
+
+            frame_system::Pallet::<T>::deposit_log(DigestItem::Consensus(
+                OUTBOUND_COMMITMENT_ENGINE_ID,
+                commitment.encode(),
+            ));
+            OutboundCommitments::<T>::insert(now, commitment);
+            CommittedOutboundNonce::<T>::put(next);
+        }
+
+        /// Removes the oldest committed outbound messages past `OutboundRetention`, in nonce
+        /// order and within `limit`, returning the weight used. The commitment of a block goes
+        /// along with its last message.
+        pub(crate) fn prune_outbound_messages(now: T::BlockNumber, limit: Weight) -> Weight {
+            let mut weight = T::WeightInfo::prune_outbound_messages(0);
+            if weight.any_gt(limit) {
+                return Weight::zero();
+            }
+
+            let retention = T::OutboundRetention::get();
+            let committed = CommittedOutboundNonce::<T>::get();
+            let mut oldest = OldestOutboundNonce::<T>::get();
+            let mut pruned = 0;
+            while oldest < committed {
+                let pruning_weight = T::WeightInfo::prune_outbound_messages(pruned + 1);
+                if pruning_weight.any_gt(limit) {
+                    break;
+                }
+                match OutboundMessages::<T>::get(oldest) {
+                    Some(message) if message.block_number.saturating_add(retention) > now => break,
+                    Some(message) => {
+                        OutboundMessages::<T>::remove(oldest);
+                        let commitment = OutboundCommitments::<T>::get(message.block_number);
+                        if commitment.map_or(false, |c| {
+                            c.first_nonce + c.message_count as u64 == oldest + 1
+                        }) {
+                            OutboundCommitments::<T>::remove(message.block_number);
+                        }
+                    }
+                    None => {}
+                }
+                oldest += 1;
+                pruned += 1;
+                weight = pruning_weight;
+            }
+
+            if pruned > 0 {
+                OldestOutboundNonce::<T>::put(oldest);
+            }
+
+            weight
+        }
+
+        /// Withdraws `fee` from `who` and pays it to `FeeDestination`, or burns it.
+        fn charge_fee(who: &T::AccountId, fee: BalanceOf<T>) -> DispatchResult {
+            if fee.is_zero() {
+                return Ok(());
+            }
+
+            let imbalance = T::Currency::withdraw(
+                who,
+                fee,
+                WithdrawReasons::FEE,
+                ExistenceRequirement::KeepAlive,
+            )?;
+            match T::FeeDestination::get() {
+                Some(destination) => T::Currency::resolve_creating(&destination, imbalance),
+                // Dropping the imbalance reduces the total issuance
+                None => drop(imbalance),
+            }
+
+            Ok(())
+        }
+
+        /// Fails unless calls from `source_address` on `source_chain` are trusted.
+        fn ensure_trusted_source(source_chain: &str, source_address: &str) -> DispatchResult {
+            let chain_name = Self::chain_name(source_chain)?;
+            let address = Self::address(source_address)?;
+
+            match TrustedSourceChains::<T>::get(&chain_name) {
+                None => Err(Error::<T>::UntrustedSourceChain.into()),
+                Some(AddressPolicy::Any) => Ok(()),
+                Some(AddressPolicy::TrustedOnly) => {
+                    ensure!(
+                        TrustedSourceAddresses::<T>::contains_key(chain_name, address),
+                        Error::<T>::UntrustedSourceAddress
+                    );
+                    Ok(())
+                }
+            }
+        }
+
+        /// Bounds `chain` to `MaxChainNameLen`.
+        pub(crate) fn chain_name(chain: &str) -> Result<ChainNameOf<T>, DispatchError> {
+            chain
+                .as_bytes()
+                .to_vec()
+                .try_into()
+                .map_err(|_| Error::<T>::ChainNameTooLong.into())
+        }
+
+        /// Bounds `address` to `MaxAddressLen`.
+        pub(crate) fn address(address: &str) -> Result<AddressOf<T>, DispatchError> {
+            address
+                .as_bytes()
+                .to_vec()
+                .try_into()
+                .map_err(|_| Error::<T>::AddressTooLong.into())
+        }
+
+        /// Reads back a string argument, whose length alone is checked when the call is decoded.
+        fn utf8(bytes: &[u8], error: Error<T>) -> Result<String, DispatchError> {
+            String::from_utf8(bytes.to_vec()).map_err(|_| error.into())
+        }
+
+        /// Fails with `error` when `len` exceeds the `S` limit.
+        fn ensure_within<S: Get<u32>>(len: usize, error: Error<T>) -> DispatchResult {
+            ensure!(len <= S::get() as usize, error);
+            Ok(())
+        }
+
+        /// Registers `operators_hash` as the operator set of a new epoch and returns that epoch.
+        fn set_operators(operators_hash: H256) -> Result<u64, DispatchError> {
+            ensure!(
+                !EpochForHash::<T>::contains_key(operators_hash),
+                Error::<T>::DuplicateOperators
+            );
+
+            let epoch = CurrentEpoch::<T>::get()
+                .checked_add(1)
+                .ok_or(ArithmeticError::Overflow)?;
+            CurrentEpoch::<T>::set(epoch);
+            HashForEpoch::<T>::set(epoch, operators_hash);
+            EpochForHash::<T>::set(operators_hash, epoch);
+
+            // The new epoch pushes the oldest retained one out of the key retention period
+            if let Some(expired_epoch) = epoch.checked_sub(T::OperatorRetention::get()) {
+                Self::prune_epoch(expired_epoch);
+            }
+
+            Ok(epoch)
+        }
+
+        /// Forgets the operators set of `epoch`, once it's out of the key retention period.
+        pub(crate) fn prune_epoch(epoch: u64) {
+            if let Ok(operators_hash) = HashForEpoch::<T>::try_get(epoch) {
+                HashForEpoch::<T>::remove(epoch);
+                // The same set may have been installed again after being revoked
+                if EpochForHash::<T>::get(operators_hash) == epoch {
+                    EpochForHash::<T>::remove(operators_hash);
+                }
+
+                Self::deposit_event(Event::OperatorsExpired {
+                    epoch,
+                    operators_hash,
+                });
+            }
+        }
+
+        /// Stops accepting proofs from any operator set registered so far.
+        /// Only the epochs still within the key retention period need to be revoked,
+        /// older ones are already rejected by `valid_operators`.
+        fn revoke_operators() {
+            let current_epoch = CurrentEpoch::<T>::get();
+            let oldest_epoch = current_epoch
+                .saturating_sub(T::OperatorRetention::get())
+                .saturating_add(1)
+                .max(1);
+
+            for epoch in oldest_epoch..=current_epoch {
+                if let Ok(operators_hash) = HashForEpoch::<T>::try_get(epoch) {
+                    EpochForHash::<T>::remove(operators_hash);
+                }
+            }
+        }
 
         pub fn validate_operatorship(
             new_operators: Vec<[u8; 20]>,
@@ -538,6 +1768,7 @@ pub mod pallet {
             let operators_length = new_operators.len();
             let weights_length = new_weights.len();
 
+            Self::ensure_within::<T::MaxOperators>(operators_length, Error::<T>::TooManyOperators)?;
             ensure!(
                 operators_length != 0
                     && Self::is_sorted_asc_and_contains_no_duplicates(new_operators.clone()),
@@ -548,9 +1779,11 @@ pub mod pallet {
                 Error::<T>::InvalidWeights
             );
 
-            let mut total_weight = 0;
+            let mut total_weight: u128 = 0;
             for i in 0..weights_length {
-                total_weight += new_weights[i];
+                total_weight = total_weight
+                    .checked_add(new_weights[i])
+                    .ok_or(Error::<T>::InvalidWeights)?;
             }
 
             ensure!(
@@ -577,6 +1810,36 @@ pub mod pallet {
             Ok(sp_io::hashing::keccak_256(&params).into())
         }
 
+        /// Validates an operator set the same way as `validate_operatorship`, returning its hash
+        /// in the `ProofVersion` of the gateway. The `nonce` is only part of
+        /// `ProofVersion::WeightedSigners` hashes.
+        pub fn validate_signers(
+            new_operators: Vec<[u8; 20]>,
+            new_weights: Vec<u128>,
+            new_threshold: u128,
+            nonce: H256,
+        ) -> Result<H256, DispatchError> {
+            let legacy_hash = Self::validate_operatorship(
+                new_operators.clone(),
+                new_weights.clone(),
+                new_threshold,
+            )?;
+
+            Ok(match T::ProofVersion::get() {
+                ProofVersion::Legacy => legacy_hash,
+                ProofVersion::WeightedSigners => proof::WeightedSigners {
+                    signers: new_operators
+                        .into_iter()
+                        .map(Into::into)
+                        .zip(new_weights)
+                        .collect(),
+                    threshold: new_threshold,
+                    nonce,
+                }
+                .hash(),
+            })
+        }
+
         pub fn is_sorted_asc_and_contains_no_duplicates(accounts: Vec<[u8; 20]>) -> bool {
             for i in 0..accounts.len() - 1 {
                 if accounts[i] >= accounts[i + 1] {
@@ -587,13 +1850,26 @@ pub mod pallet {
             accounts[0] != [0; 20]
         }
 
-        pub fn validate_proof(msg_hash: H256, raw_proof: &[u8]) -> Result<bool, DispatchError> {
-            let proof = proof::decode(raw_proof).map_err(|_| Error::<T>::FailedToDecodeProof)?;
-            let operators_hash = operators_hash(
-                proof.operators.clone(),
-                proof.weights.clone(),
-                proof.threshold,
-            );
+        /// Checks `raw_proof`, in the `ProofVersion` of the gateway, holds enough signatures of
+        /// a valid operator set over the data hashed to `data_hash`. Returns whether the
+        /// operators are the current ones.
+        pub fn validate_proof(data_hash: H256, raw_proof: &[u8]) -> Result<bool, DispatchError> {
+            Self::do_validate_proof(data_hash, raw_proof)
+                .map(|(is_active_operators, _, _)| is_active_operators)
+        }
+
+        /// Same as `validate_proof`, also returning the number of signatures checked and the number of
+        /// operators in the proof, so the verification cost can be weighed.
+        fn do_validate_proof(
+            data_hash: H256,
+            raw_proof: &[u8],
+        ) -> Result<(bool, u32, u32), DispatchError> {
+            let proof = proof::VersionedProof::decode::<T::SignatureVerifier>(
+                T::ProofVersion::get(),
+                raw_proof,
+            )
+            .map_err(Error::<T>::from)?;
+            let operators_hash = proof.operators_hash();
             let operators_epoch = <EpochForHash<T>>::get(operators_hash);
             let current_epoch = <CurrentEpoch<T>>::get();
 
@@ -602,17 +1878,198 @@ pub mod pallet {
                 Error::<T>::InvalidOperators
             );
 
-            proof::validate_signatures(msg_hash, proof).map_err(|_| Error::<T>::InvalidProof)?;
+            let operators = proof.operators_len() as u32;
+            let signatures = proof
+                .validate_signatures::<T::SignatureVerifier>(T::DomainSeparator::get(), data_hash)
+                .map_err(|_| Error::<T>::InvalidProof)?;
+
+            Ok((operators_epoch == current_epoch, signatures, operators))
+        }
+
+        /// Runs the same checks as `execute` over a batch without dispatching any of its calls,
+        /// reporting whether the proof is valid and what would happen to each command.
+        pub fn validate_batch(
+            proof: &[u8],
+            chain_id: u32,
+            command_ids: Vec<H256>,
+            commands: Vec<String>,
+            calls: Vec<<T as Config>::RuntimeCall>,
+        ) -> BatchValidation {
+            let mut report = BatchValidation::default();
+
+            if let Err(e) = Self::ensure_not_paused(Traffic::Inbound) {
+                report.error = Some(e);
+                return report;
+            }
+
+            if chain_id != T::ChainId::get() {
+                report.error = Some(Error::<T>::WrongChainId.into());
+                return report;
+            }
+
+            if calls.len() != command_ids.len() || commands.len() != command_ids.len() {
+                report.error = Some(Error::<T>::CommandIdsLengthMismatch.into());
+                return report;
+            }
+
+            if calls.len() > Self::max_batch_size() as usize {
+                report.error = Some(Error::<T>::TooManyCalls.into());
+                return report;
+            }
+
+            if let Err(e) =
+                Self::ensure_within::<T::MaxProofLen>(proof.len(), Error::<T>::ProofTooLong)
+            {
+                report.error = Some(e);
+                return report;
+            }
+
+            let payload = Self::abi_encode_batch_params(
+                chain_id,
+                command_ids.clone(),
+                commands.clone(),
+                calls.clone(),
+            );
+            let mut is_active_operators =
+                match Self::validate_proof(Self::data_hash(&payload), proof) {
+                    Ok(is_active_operators) => is_active_operators,
+                    Err(e) => {
+                        report.error = Some(e);
+                        return report;
+                    }
+                };
+            report.proof_valid = true;
+            report.active_operators = is_active_operators;
+
+            // Commands run earlier in the batch are marked as executed by then
+            let mut to_run: Vec<H256> = vec![];
+            let batch = command_ids
+                .into_iter()
+                .zip(commands.iter().zip(calls.iter()));
+            for (command_id, (command, call)) in batch {
+                let status = if to_run.contains(&command_id) {
+                    CommandStatus::AlreadyExecuted
+                } else {
+                    Self::command_status(command_id, command, call, &mut is_active_operators)
+                };
+                if status == CommandStatus::Run {
+                    to_run.push(command_id);
+                }
+                report.commands.push(status);
+            }
 
-            Ok(operators_epoch == current_epoch)
+            report
+        }
+
+        /// Decides whether `execute` runs a command. The call must be the gateway call of the
+        /// `command` it is signed as, carrying its `command_id` when it records one. Operatorship
+        /// transfers and signer rotations consume `is_active_operators`, so only the first one
+        /// of a batch can run.
+        fn command_status(
+            command_id: H256,
+            command: &str,
+            call: &<T as Config>::RuntimeCall,
+            is_active_operators: &mut bool,
+        ) -> CommandStatus {
+            if CommandExecuted::<T>::contains_key(command_id) {
+                return CommandStatus::AlreadyExecuted;
+            }
+
+            let command = match Command::from_abi(command) {
+                Some(command) => command,
+                None => return CommandStatus::UnknownCommand,
+            };
+            if Self::call_command(call) != Some(command) {
+                return CommandStatus::CommandMismatch;
+            }
+            // Approvals are stored under their own command id, which must be the executed one
+            if let Some(Call::approve_contract_call {
+                command_id: approved_id,
+                ..
+            }) = call.is_sub_type()
+            {
+                if *approved_id != command_id {
+                    return CommandStatus::CommandMismatch;
+                }
+            }
+
+            if command != Command::ApproveContractCall {
+                if !*is_active_operators {
+                    return CommandStatus::NotActiveOperators;
+                }
+                *is_active_operators = false;
+            }
+            CommandStatus::Run
+        }
+
+        /// Command `call` runs, if it is a gateway call a batch can carry
+        fn call_command(call: &<T as Config>::RuntimeCall) -> Option<Command> {
+            match call.is_sub_type() {
+                Some(Call::transfer_operatorship { .. }) => Some(Command::TransferOperatorship),
+                Some(Call::rotate_signers { .. }) => Some(Command::RotateSigners),
+                Some(Call::approve_contract_call { .. }) => Some(Command::ApproveContractCall),
+                _ => None,
+            }
+        }
+
+        /// Key under which an approved contract call is stored in `ContractCallApproved`.
+        /// `source_chain` and `source_address` are the UTF-8 bytes of the strings received from Axelar.
+        // TODO: Does it need to be ABI encoded?
+        pub fn approved_call_hash(
+            command_id: H256,
+            source_chain: &[u8],
+            source_address: &[u8],
+            contract_address: H160,
+            payload_hash: H256,
+        ) -> H256 {
+            let mut payload = command_id.encode();
+            payload.append(&mut source_chain.encode());
+            payload.append(&mut source_address.encode());
+            payload.append(&mut contract_address.encode());
+            payload.append(&mut payload_hash.encode());
+
+            H256::from(keccak_256(payload.as_slice()))
+        }
+
+        /// Whether the contract call has been approved and not forwarded yet.
+        pub fn is_contract_call_approved(
+            command_id: H256,
+            source_chain: &[u8],
+            source_address: &[u8],
+            contract_address: H160,
+            payload_hash: H256,
+        ) -> bool {
+            ContractCallApproved::<T>::contains_key(Self::approved_call_hash(
+                command_id,
+                source_chain,
+                source_address,
+                contract_address,
+                payload_hash,
+            ))
+        }
+
+        /// Whether the command has already been executed by the gateway.
+        pub fn is_command_executed(command_id: H256) -> bool {
+            CommandExecuted::<T>::contains_key(command_id)
+        }
+
+        /// Hash of the operator set of the current epoch.
+        pub fn current_operators_hash() -> H256 {
+            Self::hash_for_epoch(Self::current_epoch())
+        }
+
+        /// Whether proofs signed by the `operators_hash` set are still accepted.
+        pub fn is_operators_hash_valid(operators_hash: H256) -> bool {
+            Self::valid_operators(Self::epoch_for_hash(operators_hash), Self::current_epoch())
         }
 
         /// Check if the operators are allowed to execute.
         /// Execution is allowed if
         ///   - The `operators_epoch` is not 0
-        ///   - The `operators_epoch` is not expired, i.e., it's within the OLD_KEY_RETENTION period.
+        ///   - The `operators_epoch` is not expired, i.e., it's within the `OperatorRetention` period.
         fn valid_operators(operators_epoch: u64, current_epoch: u64) -> bool {
-            operators_epoch != 0 && current_epoch - operators_epoch < OLD_KEY_RETENTION
+            operators_epoch != 0
+                && current_epoch.saturating_sub(operators_epoch) < T::OperatorRetention::get()
         }
 
         /// Encodes batch params to ABI, so proof can be verified
@@ -621,6 +2078,47 @@ pub mod pallet {
             command_ids: Vec<H256>,
             commands: Vec<String>,
             calls: Vec<<T as Config>::RuntimeCall>,
+        ) -> ethabi::Bytes {
+            Self::abi_encode_batch(
+                chain_id,
+                command_ids,
+                commands,
+                calls.into_iter().map(|x| x.encode()).collect(),
+            )
+        }
+
+        /// Hash the current operators must sign for a batch whose `calls` are already SCALE encoded
+        pub fn batch_message_hash(
+            chain_id: u32,
+            command_ids: Vec<H256>,
+            commands: Vec<String>,
+            calls: Vec<Vec<u8>>,
+        ) -> H256 {
+            proof::message_hash(
+                T::ProofVersion::get(),
+                T::DomainSeparator::get(),
+                Self::current_operators_hash(),
+                Self::data_hash(&Self::abi_encode_batch(
+                    chain_id,
+                    command_ids,
+                    commands,
+                    calls,
+                )),
+            )
+        }
+
+        /// Hash of an ABI encoded batch, which the operators sign as described by
+        /// `proof::message_hash`
+        fn data_hash(payload: &[u8]) -> H256 {
+            H256(keccak_256(payload))
+        }
+
+        /// Encodes batch params with already SCALE encoded calls to ABI
+        fn abi_encode_batch(
+            chain_id: u32,
+            command_ids: Vec<H256>,
+            commands: Vec<String>,
+            calls: Vec<Vec<u8>>,
         ) -> ethabi::Bytes {
             // TODO: verify calling encode() on H256 doesnt double encode
             let command_ids_token: Vec<Token> = command_ids
@@ -631,10 +2129,7 @@ pub mod pallet {
                 .into_iter()
                 .map(|x| Token::String(x.into()))
                 .collect();
-            let calls_token: Vec<Token> = calls
-                .into_iter()
-                .map(|x| Token::Bytes(x.encode().into()))
-                .collect();
+            let calls_token: Vec<Token> = calls.into_iter().map(Token::Bytes).collect();
 
             ethabi::encode(&[
                 Token::Uint(ethabi::Uint::from(chain_id)),
@@ -0,0 +1,197 @@
+//! Binary keccak Merkle trees committing to the outbound messages of a block
+//!
+//! Leaves are the keccak hashes of [`LEAF_PREFIX`] followed by the SCALE encoded
+//! `(nonce, message)` pairs, in nonce order. Each node is the keccak hash of [`NODE_PREFIX`]
+//! followed by its two children concatenated, and the last node of a level with an odd
+//! number of nodes is promoted to the next level as is.
+//!
+//! The distinct prefixes keep an inner node from ever being passed off as a leaf, whatever the
+//! encoded length of a message. Off-chain verifiers must hash with the same prefixes.
+
+// ----------------------------------------------------------------------------
+// Module imports and re-exports
+// ----------------------------------------------------------------------------
+
+use codec::Encode;
+use sp_core::{keccak_256, H256};
+use sp_std::vec::Vec;
+
+// ----------------------------------------------------------------------------
+// Tree functions
+// ----------------------------------------------------------------------------
+
+/// First byte hashed into a leaf
+pub const LEAF_PREFIX: u8 = 0x00;
+
+/// First byte hashed into an inner node
+pub const NODE_PREFIX: u8 = 0x01;
+
+/// Leaf of the outbound message `message` sent with `nonce`
+pub fn leaf_hash<M: Encode>(nonce: u64, message: &M) -> H256 {
+    let mut prefixed = sp_std::vec![LEAF_PREFIX];
+    (nonce, message).encode_to(&mut prefixed);
+    H256(keccak_256(&prefixed))
+}
+
+/// Root of the tree over `leaves`, zero when there are none
+pub fn merkle_root(leaves: &[H256]) -> H256 {
+    let mut level = leaves.to_vec();
+    while level.len() > 1 {
+        level = next_level(&level);
+    }
+
+    level.first().copied().unwrap_or_default()
+}
+
+/// Siblings of the leaf at `index` from the bottom of the tree up, empty when the
+/// index is out of range
+pub fn merkle_proof(leaves: &[H256], mut index: usize) -> Vec<H256> {
+    if index >= leaves.len() {
+        return Vec::new();
+    }
+
+    let mut proof = Vec::new();
+    let mut level = leaves.to_vec();
+    while level.len() > 1 {
+        if let Some(sibling) = level.get(index ^ 1) {
+            proof.push(*sibling);
+        }
+        level = next_level(&level);
+        index /= 2;
+    }
+
+    proof
+}
+
+/// Whether `proof` shows `leaf` is the leaf at `index` of a tree of `leaf_count` leaves
+/// with the given `root`
+pub fn verify_proof(
+    root: H256,
+    leaf: H256,
+    mut index: u32,
+    mut leaf_count: u32,
+    proof: &[H256],
+) -> bool {
+    if index >= leaf_count {
+        return false;
+    }
+
+    let mut siblings = proof.iter();
+    let mut node = leaf;
+    while leaf_count > 1 {
+        if index ^ 1 < leaf_count {
+            node = match siblings.next() {
+                Some(sibling) if index % 2 == 0 => hash_pair(&node, sibling),
+                Some(sibling) => hash_pair(sibling, &node),
+                None => return false,
+            };
+        }
+        index /= 2;
+        leaf_count = (leaf_count + 1) / 2;
+    }
+
+    siblings.next().is_none() && node == root
+}
+
+fn next_level(level: &[H256]) -> Vec<H256> {
+    level
+        .chunks(2)
+        .map(|pair| match pair {
+            [left, right] => hash_pair(left, right),
+            [node] => *node,
+            _ => unreachable!("chunks of 2 hold one or two nodes"),
+        })
+        .collect()
+}
+
+fn hash_pair(left: &H256, right: &H256) -> H256 {
+    let mut concatenated = [0u8; 65];
+    concatenated[0] = NODE_PREFIX;
+    concatenated[1..33].copy_from_slice(left.as_bytes());
+    concatenated[33..].copy_from_slice(right.as_bytes());
+    H256(keccak_256(&concatenated))
+}
+
+#[cfg(test)]
+mod merkle_tests {
+    use super::*;
+    use codec::Decode;
+
+    fn leaves(n: u64) -> Vec<H256> {
+        (0..n)
+            .map(|nonce| leaf_hash(nonce, &[nonce as u8]))
+            .collect()
+    }
+
+    #[test]
+    fn root() {
+        assert_eq!(merkle_root(&[]), H256::zero());
+
+        let leaves = leaves(3);
+        assert_eq!(merkle_root(&leaves[..1]), leaves[0]);
+        assert_eq!(
+            merkle_root(&leaves),
+            hash_pair(&hash_pair(&leaves[0], &leaves[1]), &leaves[2])
+        );
+    }
+
+    #[test]
+    fn leaves_and_nodes_are_domain_separated() {
+        let (nonce, message) = (1u64, [7u8; 56]);
+        assert_eq!(
+            leaf_hash(nonce, &message),
+            H256(keccak_256(
+                &[&[LEAF_PREFIX][..], &(nonce, message).encode()].concat()
+            ))
+        );
+
+        // The 64 bytes of two children encode like a leaf, yet hash differently
+        let (left, right) = (H256::repeat_byte(1), H256::repeat_byte(2));
+        let children = [left.as_bytes(), right.as_bytes()].concat();
+        let (nonce, message) = <(u64, [u8; 56])>::decode(&mut &children[..]).unwrap();
+        assert_ne!(hash_pair(&left, &right), leaf_hash(nonce, &message));
+        assert_eq!(
+            hash_pair(&left, &right),
+            H256(keccak_256(&[&[NODE_PREFIX][..], &children].concat()))
+        );
+    }
+
+    #[test]
+    fn proofs() {
+        for n in 1..=9 {
+            let leaves = leaves(n);
+            let root = merkle_root(&leaves);
+
+            for (index, leaf) in leaves.iter().enumerate() {
+                let proof = merkle_proof(&leaves, index);
+                assert!(verify_proof(root, *leaf, index as u32, n as u32, &proof));
+
+                // Wrong leaf, index or proof
+                assert!(!verify_proof(
+                    root,
+                    H256::zero(),
+                    index as u32,
+                    n as u32,
+                    &proof
+                ));
+                if n > 1 {
+                    let other = (index as u32 + 1) % n as u32;
+                    assert!(!verify_proof(root, *leaf, other, n as u32, &proof));
+                    assert!(!verify_proof(
+                        root,
+                        *leaf,
+                        index as u32,
+                        n as u32,
+                        &proof[1..]
+                    ));
+                }
+                let mut longer = proof.clone();
+                longer.push(H256::zero());
+                assert!(!verify_proof(root, *leaf, index as u32, n as u32, &longer));
+            }
+
+            assert!(merkle_proof(&leaves, n as usize).is_empty());
+            assert!(!verify_proof(root, leaves[0], n as u32, n as u32, &[]));
+        }
+    }
+}
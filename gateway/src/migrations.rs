@@ -0,0 +1,160 @@
+//! Storage migrations for the Axelar pallet
+//!
+//! Versioned migrations check the on-chain `StorageVersion` of the pallet and only run
+//! from the version they were written for, so they can be left in a runtime's migration
+//! list across upgrades. With the `try-runtime` feature, every migration also checks the
+//! state before and after running.
+
+// ----------------------------------------------------------------------------
+// Module imports and re-exports
+// ----------------------------------------------------------------------------
+
+use crate::{Config, CurrentEpoch, HashForEpoch, Pallet};
+use frame_support::traits::{Get, OnRuntimeUpgrade};
+use frame_support::weights::Weight;
+use sp_std::{marker::PhantomData, vec::Vec};
+
+#[cfg(feature = "try-runtime")]
+use codec::{Decode, Encode};
+#[cfg(feature = "try-runtime")]
+use frame_support::ensure;
+
+// ----------------------------------------------------------------------------
+// Migrations
+// ----------------------------------------------------------------------------
+
+/// Removes the operator sets which are already out of the key retention period, either
+/// registered before epoch pruning existed or expired by lowering `Config::OperatorRetention`.
+///
+/// Running it again is harmless, it only ever prunes epochs `valid_operators` rejects.
+pub struct PruneExpiredOperators<T>(PhantomData<T>);
+impl<T: Config> OnRuntimeUpgrade for PruneExpiredOperators<T> {
+    fn on_runtime_upgrade() -> Weight {
+        let current_epoch = CurrentEpoch::<T>::get();
+        let retention = T::OperatorRetention::get();
+        let mut reads: u64 = 1;
+        let mut writes: u64 = 0;
+
+        let expired_epochs: Vec<u64> = HashForEpoch::<T>::iter_keys()
+            .inspect(|_| reads += 1)
+            .filter(|epoch| current_epoch.saturating_sub(*epoch) >= retention)
+            .collect();
+
+        for epoch in expired_epochs {
+            Pallet::<T>::prune_epoch(epoch);
+            reads += 2;
+            writes += 3;
+        }
+
+        T::DbWeight::get().reads_writes(reads, writes)
+    }
+
+    #[cfg(feature = "try-runtime")]
+    fn pre_upgrade() -> Result<Vec<u8>, &'static str> {
+        Ok((CurrentEpoch::<T>::get(), retained_epochs::<T>()).encode())
+    }
+
+    #[cfg(feature = "try-runtime")]
+    fn post_upgrade(state: Vec<u8>) -> Result<(), &'static str> {
+        let (current_epoch, retained): (u64, u32) =
+            Decode::decode(&mut state.as_slice()).map_err(|_| "Invalid pre upgrade state")?;
+
+        ensure!(
+            CurrentEpoch::<T>::get() == current_epoch,
+            "The current epoch changed"
+        );
+        ensure!(
+            HashForEpoch::<T>::iter_keys().count() as u32 == retained,
+            "Operator sets within the retention period were pruned or expired ones were kept"
+        );
+
+        Ok(())
+    }
+}
+
+/// Number of registered operator sets still within the key retention period.
+#[cfg(feature = "try-runtime")]
+fn retained_epochs<T: Config>() -> u32 {
+    let current_epoch = CurrentEpoch::<T>::get();
+    HashForEpoch::<T>::iter_keys()
+        .filter(|epoch| current_epoch.saturating_sub(*epoch) < T::OperatorRetention::get())
+        .count() as u32
+}
+
+pub mod v1 {
+    //! Storage version 1: the epoch maps only hold operator sets within the key retention period,
+    //! and calls are only approved from the chains of the trusted source registry.
+
+    use super::*;
+    use crate::{AddressPolicy, TrustedSourceChains};
+    use frame_support::traits::{GetStorageVersion, StorageVersion};
+
+    /// Brings an unversioned gateway to version 1 by pruning expired operator sets and
+    /// trusting `SourceChains`.
+    ///
+    /// Unversioned gateways approved calls from any chain, the registry starts empty and would
+    /// reject every approval until an admin adds the chains. `SourceChains` should list the
+    /// chains the gateway keeps approving calls from. The addresses of the `TrustedOnly` ones
+    /// still have to be added with `add_source_address`.
+    pub struct MigrateToV1<T, SourceChains>(PhantomData<(T, SourceChains)>);
+    impl<T: Config, SourceChains: Get<Vec<(String, AddressPolicy)>>> OnRuntimeUpgrade
+        for MigrateToV1<T, SourceChains>
+    {
+        fn on_runtime_upgrade() -> Weight {
+            if Pallet::<T>::on_chain_storage_version() != 0 {
+                return T::DbWeight::get().reads(1);
+            }
+
+            let weight = PruneExpiredOperators::<T>::on_runtime_upgrade();
+
+            let mut writes: u64 = 1;
+            for (chain, address_policy) in SourceChains::get() {
+                // Longer names can't be approved anyway, `post_upgrade` reports them
+                if let Ok(chain_name) = Pallet::<T>::chain_name(&chain) {
+                    TrustedSourceChains::<T>::insert(chain_name, address_policy);
+                    writes += 1;
+                }
+            }
+            StorageVersion::new(1).put::<Pallet<T>>();
+
+            weight.saturating_add(T::DbWeight::get().reads_writes(1, writes))
+        }
+
+        #[cfg(feature = "try-runtime")]
+        fn pre_upgrade() -> Result<Vec<u8>, &'static str> {
+            let state = if Pallet::<T>::on_chain_storage_version() == 0 {
+                Some(PruneExpiredOperators::<T>::pre_upgrade()?)
+            } else {
+                None
+            };
+
+            Ok(state.encode())
+        }
+
+        #[cfg(feature = "try-runtime")]
+        fn post_upgrade(state: Vec<u8>) -> Result<(), &'static str> {
+            let state: Option<Vec<u8>> =
+                Decode::decode(&mut state.as_slice()).map_err(|_| "Invalid pre upgrade state")?;
+
+            ensure!(
+                Pallet::<T>::on_chain_storage_version() >= 1,
+                "The storage version was not bumped"
+            );
+
+            match state {
+                Some(state) => {
+                    for (chain, address_policy) in SourceChains::get() {
+                        let chain_name = Pallet::<T>::chain_name(&chain)
+                            .map_err(|_| "A source chain name exceeds MaxChainNameLen")?;
+                        ensure!(
+                            TrustedSourceChains::<T>::get(chain_name) == Some(address_policy),
+                            "A source chain was not trusted"
+                        );
+                    }
+                    PruneExpiredOperators::<T>::post_upgrade(state)
+                }
+                None => Ok(()),
+            }
+        }
+    }
+}
@@ -1,15 +1,23 @@
 use frame_support::{
     construct_runtime, parameter_types,
-    traits::{ConstU32, ConstU64, Everything},
+    traits::{ConstU128, ConstU32, ConstU64, Everything, GenesisBuild, Get, OnRuntimeUpgrade},
+    weights::Weight,
 };
+use frame_system::EnsureRoot;
 use sp_core::H256;
-use sp_runtime::{testing::Header, traits::IdentityLookup};
+use sp_runtime::{testing::Header, traits::IdentityLookup, BoundedVec};
+use std::fmt::Debug;
 
-use crate::traits::LocalCallForwarder;
-use crate::{self as pallet_axelar_cgp, Config};
+use crate::traits::{
+    Ed25519Verifier, LocalCallForwarder, Secp256k1Verifier, SignatureVerifier, Sr25519Verifier,
+};
+use crate::{
+    self as pallet_axelar_cgp, AddressFormat, AddressPolicy, CommandName, Config, ProofVersion,
+};
 
 pub type AccountId = u64;
 pub type BlockNumber = u64;
+pub type Balance = u128;
 
 impl frame_system::Config for Runtime {
     type RuntimeOrigin = RuntimeOrigin;
@@ -27,7 +35,7 @@ impl frame_system::Config for Runtime {
     type BlockLength = ();
     type Version = ();
     type PalletInfo = PalletInfo;
-    type AccountData = ();
+    type AccountData = pallet_balances::AccountData<Balance>;
     type OnNewAccount = ();
     type OnKilledAccount = ();
     type DbWeight = ();
@@ -38,8 +46,33 @@ impl frame_system::Config for Runtime {
     type MaxConsumers = ConstU32<16>;
 }
 
+impl pallet_balances::Config for Runtime {
+    type MaxLocks = ();
+    type MaxReserves = ();
+    type ReserveIdentifier = [u8; 8];
+    type Balance = Balance;
+    type RuntimeEvent = RuntimeEvent;
+    type DustRemoval = ();
+    type ExistentialDeposit = ConstU128<1>;
+    type AccountStore = System;
+    type WeightInfo = ();
+}
+
 parameter_types! {
     pub const ChainId: u16 = 36;
+    pub static BaseFee: Balance = 0;
+    pub static ByteFee: Balance = 0;
+    pub static FeeDestination: Option<AccountId> = None;
+    pub static OperatorRetention: u64 = crate::OLD_KEY_RETENTION;
+    pub static GatewayProofVersion: ProofVersion = ProofVersion::Legacy;
+    pub const DomainSeparator: H256 = H256([0xd0; 32]);
+    pub static GatewaySignatureScheme: SignatureScheme = SignatureScheme::Secp256k1;
+    pub static OutboundRetention: BlockNumber = 100;
+    /// Source chains trusted by `migrations::v1::MigrateToV1`
+    pub MigratedSourceChains: Vec<(String, AddressPolicy)> = vec![
+        (String::from("moonbeam"), AddressPolicy::Any),
+        (String::from("osmosis"), AddressPolicy::TrustedOnly),
+    ];
 }
 
 impl Config for Runtime {
@@ -47,10 +80,55 @@ impl Config for Runtime {
     type RuntimeOrigin = RuntimeOrigin;
     type RuntimeCall = RuntimeCall;
     type ChainId = ChainId;
+    type OperatorRetention = OperatorRetention;
+    type ProofVersion = GatewayProofVersion;
+    type DomainSeparator = DomainSeparator;
+    type SignatureVerifier = MockSignatureVerifier;
+    type MaxChainNameLen = ConstU32<32>;
+    type MaxAddressLen = ConstU32<128>;
+    type MaxPayloadLen = ConstU32<{ 16 * 1024 }>;
+    type MaxProofLen = ConstU32<{ 16 * 1024 }>;
+    type MaxInputLen = ConstU32<{ 128 * 1024 }>;
+    type MaxOperators = ConstU32<100>;
+    type MaxBatchSize = ConstU32<100>;
+    type Currency = Balances;
+    type BaseFee = BaseFee;
+    type ByteFee = ByteFee;
+    type FeeDestination = FeeDestination;
+    type OutboundRetention = OutboundRetention;
     type ApprovedCallForwarder = LocalCallForwarder;
+    type AdminOrigin = EnsureRoot<AccountId>;
     type WeightInfo = ();
 }
 
+/// Key type of the mock operators
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum SignatureScheme {
+    Secp256k1,
+    Ed25519,
+    Sr25519,
+}
+
+/// Checks signatures with the verifier of the `GatewaySignatureScheme`
+pub struct MockSignatureVerifier;
+impl SignatureVerifier for MockSignatureVerifier {
+    fn signature_len() -> usize {
+        match GatewaySignatureScheme::get() {
+            SignatureScheme::Secp256k1 => Secp256k1Verifier::signature_len(),
+            SignatureScheme::Ed25519 => Ed25519Verifier::signature_len(),
+            SignatureScheme::Sr25519 => Sr25519Verifier::signature_len(),
+        }
+    }
+
+    fn signer(msg_hash: &H256, signature: &[u8]) -> Option<[u8; 20]> {
+        match GatewaySignatureScheme::get() {
+            SignatureScheme::Secp256k1 => Secp256k1Verifier::signer(msg_hash, signature),
+            SignatureScheme::Ed25519 => Ed25519Verifier::signer(msg_hash, signature),
+            SignatureScheme::Sr25519 => Sr25519Verifier::signer(msg_hash, signature),
+        }
+    }
+}
+
 type UncheckedExtrinsic = frame_system::mocking::MockUncheckedExtrinsic<Runtime>;
 type Block = frame_system::mocking::MockBlock<Runtime>;
 
@@ -61,26 +139,130 @@ construct_runtime!(
         UncheckedExtrinsic = UncheckedExtrinsic,
     {
         System: frame_system::{Pallet, Call, Config, Storage, Event<T>},
-        AxelarGateway: pallet_axelar_cgp::{Pallet, Call, Storage, Origin, Event<T>} = 2,
+        Balances: pallet_balances::{Pallet, Call, Storage, Config<T>, Event<T>},
+        AxelarGateway: pallet_axelar_cgp::{Pallet, Call, Storage, Config, Origin, Event<T>} = 2,
     }
 );
 
 pub const ALICE: AccountId = 1;
+pub const TREASURY: AccountId = 2;
 
-pub struct ExtBuilder;
+pub struct ExtBuilder {
+    operators: Vec<[u8; 20]>,
+    weights: Vec<u128>,
+    threshold: u128,
+    nonce: H256,
+    proof_version: ProofVersion,
+    signature_scheme: SignatureScheme,
+    operator_retention: u64,
+    fees: (Balance, Balance, Option<AccountId>),
+    source_chains: Vec<String>,
+    source_addresses: Vec<(String, String)>,
+    destination_chains: Vec<(String, AddressFormat)>,
+}
 
 impl Default for ExtBuilder {
     fn default() -> Self {
-        ExtBuilder
+        ExtBuilder {
+            operators: vec![],
+            weights: vec![],
+            threshold: 0,
+            nonce: H256::zero(),
+            proof_version: ProofVersion::Legacy,
+            signature_scheme: SignatureScheme::Secp256k1,
+            operator_retention: crate::OLD_KEY_RETENTION,
+            fees: (0, 0, None),
+            source_chains: vec![String::from("ethereum")],
+            source_addresses: vec![],
+            destination_chains: vec![(String::from("ethereum"), AddressFormat::EvmHex)],
+        }
     }
 }
 
 impl ExtBuilder {
+    pub fn with_operators(
+        mut self,
+        operators: Vec<[u8; 20]>,
+        weights: Vec<u128>,
+        threshold: u128,
+    ) -> Self {
+        self.operators = operators;
+        self.weights = weights;
+        self.threshold = threshold;
+        self
+    }
+
+    /// Uses the `ProofVersion::WeightedSigners` format, with the genesis operators under `nonce`.
+    pub fn with_weighted_signers(mut self, nonce: H256) -> Self {
+        self.proof_version = ProofVersion::WeightedSigners;
+        self.nonce = nonce;
+        self
+    }
+
+    pub fn with_signature_scheme(mut self, signature_scheme: SignatureScheme) -> Self {
+        self.signature_scheme = signature_scheme;
+        self
+    }
+
+    pub fn with_operator_retention(mut self, operator_retention: u64) -> Self {
+        self.operator_retention = operator_retention;
+        self
+    }
+
+    pub fn with_fees(
+        mut self,
+        base_fee: Balance,
+        byte_fee: Balance,
+        fee_destination: Option<AccountId>,
+    ) -> Self {
+        self.fees = (base_fee, byte_fee, fee_destination);
+        self
+    }
+
+    pub fn with_source_chains(
+        mut self,
+        source_chains: Vec<String>,
+        source_addresses: Vec<(String, String)>,
+    ) -> Self {
+        self.source_chains = source_chains;
+        self.source_addresses = source_addresses;
+        self
+    }
+
+    pub fn with_destination_chains(
+        mut self,
+        destination_chains: Vec<(String, AddressFormat)>,
+    ) -> Self {
+        self.destination_chains = destination_chains;
+        self
+    }
+
     pub fn build(self) -> sp_io::TestExternalities {
-        let t = frame_system::GenesisConfig::default()
+        OperatorRetention::set(&self.operator_retention);
+        GatewayProofVersion::set(&self.proof_version);
+        GatewaySignatureScheme::set(&self.signature_scheme);
+        BaseFee::set(&self.fees.0);
+        ByteFee::set(&self.fees.1);
+        FeeDestination::set(&self.fees.2);
+
+        let mut t = frame_system::GenesisConfig::default()
             .build_storage::<Runtime>()
             .unwrap();
 
+        GenesisBuild::<Runtime>::assimilate_storage(
+            &pallet_axelar_cgp::GenesisConfig {
+                operators: self.operators,
+                weights: self.weights,
+                threshold: self.threshold,
+                nonce: self.nonce,
+                source_chains: self.source_chains,
+                source_addresses: self.source_addresses,
+                destination_chains: self.destination_chains,
+            },
+            &mut t,
+        )
+        .unwrap();
+
         let mut ext = sp_io::TestExternalities::new(t);
         ext.execute_with(|| System::set_block_number(1));
         ext
@@ -103,3 +285,34 @@ pub fn event_exists<E: Into<RuntimeEvent>>(e: E) {
     }
     assert!(exists);
 }
+
+/// Runs the `M` upgrade, along with its try-runtime checks when the feature is enabled.
+pub fn run_upgrade<M: OnRuntimeUpgrade>() -> Weight {
+    #[cfg(feature = "try-runtime")]
+    let state = M::pre_upgrade().unwrap();
+    let weight = M::on_runtime_upgrade();
+    #[cfg(feature = "try-runtime")]
+    M::post_upgrade(state).unwrap();
+
+    weight
+}
+
+/// Bounds an extrinsic argument, as decoding the extrinsic does.
+pub fn bounded<T: Debug, S: Get<u32>>(value: Vec<T>) -> BoundedVec<T, S> {
+    value.try_into().unwrap()
+}
+
+/// Bounds a chain name or an address argument.
+pub fn bounded_str<S: Get<u32>>(value: &str) -> BoundedVec<u8, S> {
+    bounded(value.as_bytes().to_vec())
+}
+
+/// Bounds the command names of an `execute` batch.
+pub fn command_names<S: Get<u32>>(commands: Vec<String>) -> BoundedVec<CommandName, S> {
+    bounded(
+        commands
+            .iter()
+            .map(|command| bounded_str(command))
+            .collect(),
+    )
+}
@@ -4,8 +4,11 @@
 
 // Ensure we're `no_std` when compiling for WebAssembly.
 #![cfg_attr(not(feature = "std"), no_std)]
+use crate::traits::SignatureVerifier;
+use crate::ProofVersion;
 use ethabi::{Address, ParamType, Token};
-use sp_core::{keccak_256, H256};
+use sp_core::{keccak_256, H256, U256};
+use sp_std::{boxed::Box, vec, vec::Vec};
 
 #[derive(PartialEq, Debug)]
 pub struct Proof {
@@ -15,46 +18,280 @@ pub struct Proof {
     pub signatures: Vec<Vec<u8>>,
 }
 
+/// Length of a recoverable ECDSA signature, `r ++ s ++ v`
+pub const SIGNATURE_LEN: usize = 65;
+
+#[derive(PartialEq, Debug)]
+pub enum ProofError {
+    /// The payload is not an ABI encoded `(address[], uint256[], uint256, bytes[])`
+    InvalidEncoding,
+    /// A token isn't of the type expected at its position
+    InvalidToken,
+    /// The numbers of operators and weights differ
+    WeightsLengthMismatch,
+    /// A weight or the threshold doesn't fit in a `u128`
+    ValueOverflow,
+    /// A signature doesn't have the length of the signatures of the `SignatureVerifier`
+    InvalidSignatureLength,
+}
+
 impl TryFrom<Vec<Token>> for Proof {
-    type Error = ethabi::Error;
+    type Error = ProofError;
 
     fn try_from(tokens: Vec<Token>) -> Result<Self, Self::Error> {
-        if let [Token::Array(operators_token), Token::Array(weights_token), Token::Uint(t), Token::Array(signatures_token)] =
-            tokens.as_slice()
-        {
-            let operators = operators_token
-                .into_iter()
-                .flat_map(|x| match x {
-                    Token::Address(x) => Ok(x.clone()),
-                    _ => Err(ethabi::Error::InvalidData),
-                })
-                .collect();
-            let weights = weights_token
-                .into_iter()
-                .flat_map(|x| match x {
-                    Token::Uint(w) => Ok(w.as_u128()),
-                    _ => Err(ethabi::Error::InvalidData),
-                })
-                .collect();
-            let threshold = t.as_u128();
-            let signatures = signatures_token
-                .into_iter()
-                .flat_map(|x| match x {
-                    Token::Bytes(x) => Ok(x.clone()),
-                    _ => Err(ethabi::Error::InvalidData),
-                })
-                .collect();
-
-            return Ok(Proof {
-                operators,
-                weights,
-                threshold,
+        let (operators_token, weights_token, t, signatures_token) = match tokens.as_slice() {
+            [Token::Array(operators), Token::Array(weights), Token::Uint(t), Token::Array(signatures)] => {
+                (operators, weights, t, signatures)
+            }
+            _ => return Err(ProofError::InvalidToken),
+        };
+
+        let operators: Vec<Address> = operators_token
+            .iter()
+            .map(|x| match x {
+                Token::Address(x) => Ok(*x),
+                _ => Err(ProofError::InvalidToken),
+            })
+            .collect::<Result<_, _>>()?;
+        let weights: Vec<u128> = weights_token
+            .iter()
+            .map(|x| match x {
+                Token::Uint(w) => to_u128(*w),
+                _ => Err(ProofError::InvalidToken),
+            })
+            .collect::<Result<_, _>>()?;
+        if operators.len() != weights.len() {
+            return Err(ProofError::WeightsLengthMismatch);
+        }
+        let threshold = to_u128(*t)?;
+        let signatures = signatures_from(signatures_token)?;
+
+        Ok(Proof {
+            operators,
+            weights,
+            threshold,
+            signatures,
+        })
+    }
+}
+
+/// Signer set of the Axelar Amplifier and v6 gateways, `WeightedSigners` in Solidity
+#[derive(Clone, PartialEq, Debug)]
+pub struct WeightedSigners {
+    /// Signers, ascending, along with their weights
+    pub signers: Vec<(Address, u128)>,
+    pub threshold: u128,
+    /// Distinguishes signer sets with the same signers, weights and threshold
+    pub nonce: H256,
+}
+
+impl WeightedSigners {
+    /// Hash identifying the signer set, `keccak256(abi.encode(weightedSigners))`
+    pub fn hash(&self) -> H256 {
+        H256(keccak_256(&ethabi::encode(&[self.to_token()])))
+    }
+
+    pub(crate) fn to_token(&self) -> Token {
+        Token::Tuple(vec![
+            Token::Array(
+                self.signers
+                    .iter()
+                    .map(|(signer, weight)| {
+                        Token::Tuple(vec![Token::Address(*signer), Token::Uint((*weight).into())])
+                    })
+                    .collect(),
+            ),
+            Token::Uint(self.threshold.into()),
+            Token::FixedBytes(self.nonce.as_bytes().to_vec()),
+        ])
+    }
+
+    pub(crate) fn param_type() -> ParamType {
+        ParamType::Tuple(vec![
+            ParamType::Array(Box::new(ParamType::Tuple(vec![
+                ParamType::Address,
+                ParamType::Uint(128),
+            ]))),
+            ParamType::Uint(128),
+            ParamType::FixedBytes(32),
+        ])
+    }
+}
+
+impl TryFrom<&Token> for WeightedSigners {
+    type Error = ProofError;
+
+    fn try_from(token: &Token) -> Result<Self, Self::Error> {
+        let (signers_token, threshold, nonce) = match token {
+            Token::Tuple(fields) => match fields.as_slice() {
+                [Token::Array(signers), Token::Uint(threshold), Token::FixedBytes(nonce)]
+                    if nonce.len() == 32 =>
+                {
+                    (signers, threshold, nonce)
+                }
+                _ => return Err(ProofError::InvalidToken),
+            },
+            _ => return Err(ProofError::InvalidToken),
+        };
+
+        let signers = signers_token
+            .iter()
+            .map(|x| match x {
+                Token::Tuple(signer) => match signer.as_slice() {
+                    [Token::Address(signer), Token::Uint(weight)] => {
+                        Ok((*signer, to_u128(*weight)?))
+                    }
+                    _ => Err(ProofError::InvalidToken),
+                },
+                _ => Err(ProofError::InvalidToken),
+            })
+            .collect::<Result<_, _>>()?;
+
+        Ok(WeightedSigners {
+            signers,
+            threshold: to_u128(*threshold)?,
+            nonce: H256::from_slice(nonce),
+        })
+    }
+}
+
+/// Proof of the Axelar Amplifier and v6 gateways, `Proof` in Solidity
+#[derive(PartialEq, Debug)]
+pub struct WeightedSignersProof {
+    pub signers: WeightedSigners,
+    pub signatures: Vec<Vec<u8>>,
+}
+
+/// A proof in one of the formats of `ProofVersion`
+#[derive(PartialEq, Debug)]
+pub enum VersionedProof {
+    Legacy(Proof),
+    WeightedSigners(WeightedSignersProof),
+}
+
+impl VersionedProof {
+    /// Decodes a proof in the `version` format, with signatures checked by `V`.
+    pub fn decode<V: SignatureVerifier>(
+        version: ProofVersion,
+        payload: &[u8],
+    ) -> Result<Self, ProofError> {
+        match version {
+            ProofVersion::Legacy => decode::<V>(payload).map(VersionedProof::Legacy),
+            ProofVersion::WeightedSigners => {
+                decode_weighted_signers::<V>(payload).map(VersionedProof::WeightedSigners)
+            }
+        }
+    }
+
+    /// Hash of the operator set that signed the proof, as stored in `EpochForHash`
+    pub fn operators_hash(&self) -> H256 {
+        match self {
+            VersionedProof::Legacy(proof) => operators_hash(
+                proof.operators.clone(),
+                proof.weights.clone(),
+                proof.threshold,
+            ),
+            VersionedProof::WeightedSigners(proof) => proof.signers.hash(),
+        }
+    }
+
+    /// Number of operators in the signer set
+    pub fn operators_len(&self) -> usize {
+        match self {
+            VersionedProof::Legacy(proof) => proof.operators.len(),
+            VersionedProof::WeightedSigners(proof) => proof.signers.signers.len(),
+        }
+    }
+
+    /// Checks the signatures over the data hashed to `data_hash`, see `validate_signatures`.
+    pub fn validate_signatures<V: SignatureVerifier>(
+        self,
+        domain_separator: H256,
+        data_hash: H256,
+    ) -> Result<u32, SignatureError> {
+        let msg_hash = match &self {
+            VersionedProof::Legacy(_) => message_hash(
+                ProofVersion::Legacy,
+                domain_separator,
+                H256::zero(),
+                data_hash,
+            ),
+            VersionedProof::WeightedSigners(proof) => message_hash(
+                ProofVersion::WeightedSigners,
+                domain_separator,
+                proof.signers.hash(),
+                data_hash,
+            ),
+        };
+
+        let proof = match self {
+            VersionedProof::Legacy(proof) => proof,
+            VersionedProof::WeightedSigners(WeightedSignersProof {
+                signers,
                 signatures,
-            });
+            }) => {
+                let (operators, weights) = signers.signers.into_iter().unzip();
+                Proof {
+                    operators,
+                    weights,
+                    threshold: signers.threshold,
+                    signatures,
+                }
+            }
+        };
+
+        validate_signatures::<V>(msg_hash, proof)
+    }
+}
+
+/// Hash the operators sign for the data hashed to `data_hash`, in the `version` format.
+///
+/// Legacy proofs sign the Ethereum prefixed `data_hash`. `WeightedSigners` proofs sign
+/// `keccak256("\x19Ethereum Signed Message:\n96" ++ domain_separator ++ signers_hash ++ data_hash)`,
+/// binding the signatures to the gateway and the signer set.
+pub fn message_hash(
+    version: ProofVersion,
+    domain_separator: H256,
+    signers_hash: H256,
+    data_hash: H256,
+) -> H256 {
+    match version {
+        ProofVersion::Legacy => H256(ecdsa::to_eth_signed_message_hash(data_hash.0)),
+        ProofVersion::WeightedSigners => {
+            let mut message = b"\x19Ethereum Signed Message:\n96".to_vec();
+            message.extend_from_slice(domain_separator.as_bytes());
+            message.extend_from_slice(signers_hash.as_bytes());
+            message.extend_from_slice(data_hash.as_bytes());
+            H256(keccak_256(&message))
         }
+    }
+}
 
-        Err(ethabi::Error::InvalidData)
+fn signatures_from(tokens: &[Token]) -> Result<Vec<Vec<u8>>, ProofError> {
+    tokens
+        .iter()
+        .map(|x| match x {
+            Token::Bytes(x) => Ok(x.clone()),
+            _ => Err(ProofError::InvalidToken),
+        })
+        .collect()
+}
+
+fn ensure_signature_len<V: SignatureVerifier>(signatures: &[Vec<u8>]) -> Result<(), ProofError> {
+    if signatures.iter().any(|x| x.len() != V::signature_len()) {
+        return Err(ProofError::InvalidSignatureLength);
+    }
+
+    Ok(())
+}
+
+/// `value` as a `u128`, as long as it fits
+pub(crate) fn to_u128(value: ethabi::Uint) -> Result<u128, ProofError> {
+    if value > ethabi::Uint::from(u128::MAX) {
+        return Err(ProofError::ValueOverflow);
     }
+
+    Ok(value.as_u128())
 }
 
 /// Obtain the operators set hash by computing the H256 hash of the encoded operators, weights, and threshold.
@@ -72,23 +309,108 @@ pub fn operators_hash(operators: Vec<Address>, weights: Vec<u128>, threshold: u1
     ])))
 }
 
-/// Decode a payload expected to contain a `Proof`.
-#[allow(dead_code)]
-pub fn decode(payload: &[u8]) -> Result<Proof, ethabi::Error> {
-    ethabi::decode(
+/// Decode a payload expected to contain a `Proof` with signatures checked by `V`, failing on
+/// anything that doesn't strictly follow its encoding rather than dropping or truncating it.
+pub fn decode<V: SignatureVerifier>(payload: &[u8]) -> Result<Proof, ProofError> {
+    let tokens = ethabi::decode(
         &[
             // operator's addresses
             ParamType::Array(Box::new(ParamType::Address)),
             // weights
-            ParamType::Array(Box::new(ParamType::Uint(usize::MAX))),
+            ParamType::Array(Box::new(ParamType::Uint(256))),
             // threshold
-            ParamType::Uint(usize::MAX),
+            ParamType::Uint(256),
             // signatures
             ParamType::Array(Box::new(ParamType::Bytes)),
         ],
         payload,
     )
-    .map(|x| Proof::try_from(x))?
+    .map_err(|_| ProofError::InvalidEncoding)?;
+
+    let proof = Proof::try_from(tokens)?;
+    ensure_signature_len::<V>(&proof.signatures)?;
+
+    Ok(proof)
+}
+
+/// Decode a payload expected to contain a `WeightedSignersProof`, the ABI encoded
+/// `((WeightedSigner[] signers, uint128 threshold, bytes32 nonce), bytes[] signatures)`, with
+/// signatures checked by `V`.
+pub fn decode_weighted_signers<V: SignatureVerifier>(
+    payload: &[u8],
+) -> Result<WeightedSignersProof, ProofError> {
+    let tokens = ethabi::decode(
+        &[ParamType::Tuple(vec![
+            WeightedSigners::param_type(),
+            ParamType::Array(Box::new(ParamType::Bytes)),
+        ])],
+        payload,
+    )
+    .map_err(|_| ProofError::InvalidEncoding)?;
+
+    let proof = match tokens.as_slice() {
+        [Token::Tuple(proof)] => match proof.as_slice() {
+            [signers, Token::Array(signatures)] => WeightedSignersProof {
+                signers: WeightedSigners::try_from(signers)?,
+                signatures: signatures_from(signatures)?,
+            },
+            _ => return Err(ProofError::InvalidToken),
+        },
+        _ => return Err(ProofError::InvalidToken),
+    };
+    ensure_signature_len::<V>(&proof.signatures)?;
+
+    Ok(proof)
+}
+
+/// Size of an ABI word
+pub(crate) const WORD: usize = 32;
+
+/// Estimates the number of `(signatures, operators)` held by an ABI encoded proof in the
+/// `version` format by reading the array lengths in place, without decoding the proof or
+/// checking its signatures.
+///
+/// The counts are capped by what `payload` could actually hold, so a malformed proof can't
+/// claim more than its own size allows.
+pub fn estimate_counts(version: ProofVersion, payload: &[u8]) -> (u32, u32) {
+    // Every operator is an address plus a weight
+    let max_operators = payload.len() / (2 * WORD);
+    // Every signature is an offset, a length and 65 or 96 bytes padded to 3 words
+    let max_signatures = payload.len() / (5 * WORD);
+
+    let (operators, signatures) = match version {
+        ProofVersion::Legacy => (array_len(payload, 0), array_len(payload, 3)),
+        ProofVersion::WeightedSigners => {
+            // The proof tuple holds the offsets of the signers tuple and of the signatures
+            let proof = read_word(payload, 0).and_then(|offset| payload.get(offset..));
+            let signers = proof
+                .and_then(|proof| Some(proof.get(read_word(proof, 0)?..)?))
+                .and_then(|signers| array_len(signers, 0));
+            (signers, proof.and_then(|proof| array_len(proof, 1)))
+        }
+    };
+    let operators = operators.unwrap_or(max_operators).min(max_operators);
+    let signatures = signatures.unwrap_or(max_signatures).min(max_signatures);
+
+    (signatures as u32, operators as u32)
+}
+
+/// Reads the length of the dynamic array whose offset is the `index`-th word of `payload`.
+pub(crate) fn array_len(payload: &[u8], index: usize) -> Option<usize> {
+    let offset = read_word(payload, index * WORD)?;
+    read_word(payload, offset)
+}
+
+/// Reads the word at `position` as an `usize`, if it fits within the payload size.
+pub(crate) fn read_word(payload: &[u8], position: usize) -> Option<usize> {
+    let word = payload.get(position..position.checked_add(WORD)?)?;
+    let value = U256::from_big_endian(word);
+
+    if value > U256::from(payload.len()) {
+        return None;
+    }
+
+    Some(value.as_usize())
 }
 
 #[derive(PartialEq, Debug)]
@@ -97,46 +419,75 @@ pub enum SignatureError {
     InvalidSignature,
     /// Couldn't find the signer of a signature in the list of operators
     MalformedSigners,
+    /// A signer comes before the signer of a previous signature in the list of operators
+    UnorderedSigners,
+    /// An operator signed more than once
+    DuplicateSigner,
     /// Not enough signatures found to meet the threshold
     LowSignaturesWeight,
 }
 
-/// Verifies that the proof holds enough signatures to meet the required threshold.
+/// Verifies that the proof holds enough signatures, checked by `V`, to meet the required threshold.
 /// Fails if not enough operators signed the `msg_hash` to meet the threshold.
-pub fn validate_signatures(msg_hash: H256, proof: Proof) -> Result<(), SignatureError> {
+/// Returns the number of signatures that had to be checked to reach the threshold.
+///
+/// As in Axelar's `AxelarAuthWeighted`, signatures must follow the order of their signers in
+/// the list of operators, so each operator's weight is counted at most once. The signer of
+/// each signature is only looked for among the operators after the previous signer.
+pub fn validate_signatures<V: SignatureVerifier>(
+    msg_hash: H256,
+    proof: Proof,
+) -> Result<u32, SignatureError> {
     let Proof {
         operators,
         weights,
         threshold,
         signatures,
     } = proof;
-    let mut weight = 0;
+    let mut weight: u128 = 0;
+    // Operator indexes of the signers so far, ascending
+    let mut signers: Vec<usize> = Vec::new();
+    // Operators before this one already signed or were skipped
+    let mut next = 0;
 
-    for signature in signatures.into_iter() {
-        let signer =
-            ecdsa::recover(msg_hash, signature).map_err(|_| SignatureError::InvalidSignature)?;
+    for (checked, signature) in (1..).zip(signatures.into_iter()) {
+        let signer = V::signer(&msg_hash, &signature).ok_or(SignatureError::InvalidSignature)?;
 
-        let index = operators
-            .iter()
-            .position(|x| x.0 == signer.0)
-            .ok_or(SignatureError::MalformedSigners)?;
+        let index = match operators[next..].iter().position(|x| x.0 == signer) {
+            Some(offset) => next + offset,
+            None => return Err(misplaced_signer(&operators[..next], &signers, signer)),
+        };
+        signers.push(index);
+        next = index + 1;
 
-        weight += weights[index];
+        weight =
+            weight.saturating_add(*weights.get(index).ok_or(SignatureError::MalformedSigners)?);
 
         if weight >= threshold {
-            return Ok(());
+            return Ok(checked);
         }
     }
 
     Err(SignatureError::LowSignaturesWeight)
 }
 
+/// Why `signer` isn't among the operators after the previous signer, given the `operators`
+/// before it and the indexes of the `signers` so far.
+fn misplaced_signer(operators: &[Address], signers: &[usize], signer: [u8; 20]) -> SignatureError {
+    match operators.iter().position(|x| x.0 == signer) {
+        Some(index) if signers.binary_search(&index).is_ok() => SignatureError::DuplicateSigner,
+        Some(_) => SignatureError::UnorderedSigners,
+        None => SignatureError::MalformedSigners,
+    }
+}
+
 // ----------------------------------------------------------------------------
 // Tests
 // ----------------------------------------------------------------------------
 #[cfg(test)]
 pub mod proof_tests {
     use super::*;
+    use crate::traits::Secp256k1Verifier;
     use ecdsa::to_eth_signed_message_hash;
     use ethabi::Token;
     use sp_core::{keccak_256, H160};
@@ -148,7 +499,7 @@ pub mod proof_tests {
         let operators = vec![[1u8; 20], [2u8; 20]];
         let weights = vec![100, 200];
         let threshold = 99;
-        let signatures = vec![vec![1], vec![2]];
+        let signatures = vec![vec![1; SIGNATURE_LEN], vec![2; SIGNATURE_LEN]];
 
         // Encode
         let encoded = encode(
@@ -158,7 +509,7 @@ pub mod proof_tests {
             signatures.clone(),
         );
         // Now decode
-        let proof = decode(&encoded).expect("Should decode proof");
+        let proof = decode::<Secp256k1Verifier>(&encoded).expect("Should decode proof");
 
         let expected_proof = Proof {
             operators: operators.into_iter().map(|x| Address::from(x)).collect(),
@@ -170,15 +521,300 @@ pub mod proof_tests {
         assert_eq!(proof, expected_proof);
     }
 
+    #[test]
+    fn decode_rejects_malformed_proofs() {
+        let operators = vec![[1u8; 20], [2u8; 20]];
+        let signatures = vec![vec![1u8; SIGNATURE_LEN]];
+
+        assert_eq!(
+            decode::<Secp256k1Verifier>(&[1, 2, 3]),
+            Err(ProofError::InvalidEncoding)
+        );
+        assert_eq!(
+            decode::<Secp256k1Verifier>(&encode(operators.clone(), vec![1], 1, signatures.clone())),
+            Err(ProofError::WeightsLengthMismatch)
+        );
+        assert_eq!(
+            decode::<Secp256k1Verifier>(&encode(
+                operators.clone(),
+                vec![1, 1],
+                1,
+                vec![vec![1u8; 64]]
+            )),
+            Err(ProofError::InvalidSignatureLength)
+        );
+
+        let overflow = |weight: ethabi::Uint, threshold: ethabi::Uint| {
+            ethabi::encode(&[
+                Token::Array(
+                    operators
+                        .iter()
+                        .map(|x| Token::Address((*x).into()))
+                        .collect(),
+                ),
+                Token::Array(vec![Token::Uint(1.into()), Token::Uint(weight)]),
+                Token::Uint(threshold),
+                Token::Array(vec![Token::Bytes(signatures[0].clone())]),
+            ])
+        };
+        let max = ethabi::Uint::from(u128::MAX);
+        assert!(decode::<Secp256k1Verifier>(&overflow(max, max)).is_ok());
+        assert_eq!(
+            decode::<Secp256k1Verifier>(&overflow(max + 1, 1.into())),
+            Err(ProofError::ValueOverflow)
+        );
+        assert_eq!(
+            decode::<Secp256k1Verifier>(&overflow(1.into(), ethabi::Uint::MAX)),
+            Err(ProofError::ValueOverflow)
+        );
+
+        // Tokens of the wrong type fail instead of being dropped
+        let tokens = |operator: Token, signature: Token| {
+            vec![
+                Token::Array(vec![operator]),
+                Token::Array(vec![Token::Uint(1.into())]),
+                Token::Uint(1.into()),
+                Token::Array(vec![signature]),
+            ]
+        };
+        let address = Token::Address(operators[0].into());
+        let signature = Token::Bytes(signatures[0].clone());
+        assert!(Proof::try_from(tokens(address.clone(), signature.clone())).is_ok());
+        assert_eq!(
+            Proof::try_from(tokens(Token::Bool(true), signature.clone())),
+            Err(ProofError::InvalidToken)
+        );
+        assert_eq!(
+            Proof::try_from(tokens(address, Token::String("sig".into()))),
+            Err(ProofError::InvalidToken)
+        );
+        assert_eq!(
+            Proof::try_from(vec![signature]),
+            Err(ProofError::InvalidToken)
+        );
+    }
+
+    #[test]
+    fn estimate_proof_counts() {
+        let signatures = vec![vec![1u8; 65], vec![2u8; 65]];
+        let encoded = encode(
+            vec![[1u8; 20], [2u8; 20], [3u8; 20]],
+            vec![1, 1, 1],
+            2,
+            signatures,
+        );
+        assert_eq!(estimate_counts(ProofVersion::Legacy, &encoded), (2, 3));
+
+        // Lengths can't exceed what the payload holds
+        let mut forged = encoded.clone();
+        let offset = U256::from_big_endian(&encoded[3 * WORD..4 * WORD]).as_usize();
+        forged[offset..offset + WORD].copy_from_slice(&[0xff; WORD]);
+        assert_eq!(
+            estimate_counts(ProofVersion::Legacy, &forged),
+            (encoded.len() as u32 / 160, 3)
+        );
+
+        assert_eq!(estimate_counts(ProofVersion::Legacy, &[]), (0, 0));
+        assert_eq!(estimate_counts(ProofVersion::WeightedSigners, &[]), (0, 0));
+    }
+
     #[test]
     fn test_validate_signatures() {
         let input = hex::decode("00000000000000000000000000000000000000000000000000000000000000400000000000000000000000000000000000000000000000000000000000001a4000000000000000000000000000000000000000000000000000000000000019e00000000000000000000000000000000000000000000000000000000000000089000000000000000000000000000000000000000000000000000000000000008000000000000000000000000000000000000000000000000000000000000003200000000000000000000000000000000000000000000000000000000000000ac00000000000000000000000000000000000000000000000000000000000000014024bd9868ae3a24be4618c06e99c4b8c8203c3ed5a7624926c7c1b2d5d4f2f830b61d8ae73e1fa78be41dca6e9eddaf58455c1fede28d9b659db46c2852199641f1bd2329cff9e675a7cfb713c3389e1cce070a52815dfe181e7bee84cebdf1632d682f903de5becca1d53ae70e10e89f9bbb08e34a44659c96cec5400c50aad3af28d1cd27a32b62d17b5b4f1f5dda694c038816ac210cd6041e69b307f68653d3102ef7fab39c68cf4877239f7fecf2390635ccd62188837cbaa92096f6a9f4258411491a045f5712f44d69d579a61fb32fc0e9e0a752da922722036807bdf4a471b6425920feaac5ea7e89a8184f31a8e07bfa7e2b3d8d13afe38c6f002075eed8a192323fa2a6426a9a76844c8795235c257122914b59460b7b93307a8085f25615b4a884455bdd7c3e15ab53bb7ce52c2da7de4c3f0271ed0e501d6b46b66bf20bc5dd1291380cd1968228d6f741ce65f11d65566440a14813dd29a1c45712848d49c6804fd0ee931a63d0aaffe723503c20154cf0ccbf40f4ead91ea247563e35f83efd31cd7ad9e8207b49ad3cac90e3ec0a3bfffbac92d54642b2d1e79538b3ca6bdb613981b8fb3300c64b904f61faf69fa8379419f5a256c076b949158484c76b6a6b3b6ca812fd48a75ad6fba67f234f5600e129de90aeb769fe1932a2dc27048ac32152e068c5d25c5ca69751f8a16d44f4426246817f3e54744b145640a7b52b2cd935d98c0358e1133af7d819ff48b65a5de5b461531574203c3a2c6ece32b9ff28b6004c21544f1235bb311dd00aa857dd1420fcfc0360eb1e055d1f67fde0977937822f0f553b812e92e4f5fe266e3c7dbd188e3ebbc8683f5e534eb6a5772039075f58b3c589a37d377783626a0a08ffbd822d19ff672510000000000000000000000000000000000000000000000000000000000000014000000000000000000000000000000000000000000000000000000000000028000000000000000000000000000000000000000000000000000000000000002c000000000000000000000000000000000000000000000000000000000000003000000000000000000000000000000000000000000000000000000000000000340000000000000000000000000000000000000000000000000000000000000038000000000000000000000000000000000000000000000000000000000000003c000000000000000000000000000000000000000000000000000000000000004000000000000000000000000000000000000000000000000000000000000000440000000000000000000000000000000000000000000000000000000000000048000000000000000000000000000000000000000000000000000000000000004c000000000000000000000000000000000000000000000000000000000000005000000000000000000000000000000000000000000000000000000000000000540000000000000000000000000000000000000000000000000000000000000058000000000000000000000000000000000000000000000000000000000000005c000000000000000000000000000000000000000000000000000000000000006000000000000000000000000000000000000000000000000000000000000000640000000000000000000000000000000000000000000000000000000000000068000000000000000000000000000000000000000000000000000000000000006c00000000000000000000000000000000000000000000000000000000000000700000000000000000000000000000000000000000000000000000000000000074000000000000000000000000000000000000000000000000000000000000000096275726e546f6b656e000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000096275726e546f6b656e000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000096275726e546f6b656e000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000096275726e546f6b656e000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000096275726e546f6b656e000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000096275726e546f6b656e000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000096275726e546f6b656e000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000096275726e546f6b656e000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000096275726e546f6b656e000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000096275726e546f6b656e000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000096275726e546f6b656e000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000096275726e546f6b656e000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000096275726e546f6b656e000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000096275726e546f6b656e000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000096275726e546f6b656e000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000096275726e546f6b656e000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000096275726e546f6b656e000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000096275726e546f6b656e000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000096275726e546f6b656e000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000096275726e546f6b656e000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000140000000000000000000000000000000000000000000000000000000000000280000000000000000000000000000000000000000000000000000000000000032000000000000000000000000000000000000000000000000000000000000003c00000000000000000000000000000000000000000000000000000000000000460000000000000000000000000000000000000000000000000000000000000050000000000000000000000000000000000000000000000000000000000000005a0000000000000000000000000000000000000000000000000000000000000064000000000000000000000000000000000000000000000000000000000000006e00000000000000000000000000000000000000000000000000000000000000780000000000000000000000000000000000000000000000000000000000000082000000000000000000000000000000000000000000000000000000000000008c000000000000000000000000000000000000000000000000000000000000009600000000000000000000000000000000000000000000000000000000000000a000000000000000000000000000000000000000000000000000000000000000aa00000000000000000000000000000000000000000000000000000000000000b400000000000000000000000000000000000000000000000000000000000000be00000000000000000000000000000000000000000000000000000000000000c800000000000000000000000000000000000000000000000000000000000000d200000000000000000000000000000000000000000000000000000000000000dc00000000000000000000000000000000000000000000000000000000000000e60000000000000000000000000000000000000000000000000000000000000008000000000000000000000000000000000000000000000000000000000000000400a6d1770549b534347933ca2f6f45745e6d5b479e7925fe84e63538bdfa8bbb2000000000000000000000000000000000000000000000000000000000000000341584c00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000080000000000000000000000000000000000000000000000000000000000000004064f0cf9822dc7d35f96e1b5a8d710b89c56c781993bb8c0b121cec9d0b0e433a000000000000000000000000000000000000000000000000000000000000000341584c000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000800000000000000000000000000000000000000000000000000000000000000040f2b3576aee3d5ef0c8fa5bab2d67744de2b7f5f12e044ae4c5815f59c1c32637000000000000000000000000000000000000000000000000000000000000000761786c555344430000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000800000000000000000000000000000000000000000000000000000000000000040b4adc7f4169073d4b9acb8e333af363a5c1908198b31e7118fb712566583b784000000000000000000000000000000000000000000000000000000000000000341584c000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000800000000000000000000000000000000000000000000000000000000000000040ca2827d2512e5fc79794d5a9d1c796ad96286c1910187ad96e7ecf1ec5229d11000000000000000000000000000000000000000000000000000000000000000341584c0000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000008000000000000000000000000000000000000000000000000000000000000000407df01a951c4c9ca80588e361a4e16d8da8d86c1027a83ac362e88bdbc9d39d0c000000000000000000000000000000000000000000000000000000000000000341584c00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000080000000000000000000000000000000000000000000000000000000000000004031b2e37c24f57b9f6c3e6293a2a9533a1aea2ab985a2736fdf408fe96e6a4190000000000000000000000000000000000000000000000000000000000000000341584c000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000800000000000000000000000000000000000000000000000000000000000000040fd9da3a2d08ea314fdce35b3679584ae7d0cf7fecc8598d5e5d6d32cdd17ff35000000000000000000000000000000000000000000000000000000000000000761786c555344430000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000800000000000000000000000000000000000000000000000000000000000000040a5b9348079667e180c95852ba7641fe3603b2446d0904dbcb11e08273ff6674d000000000000000000000000000000000000000000000000000000000000000761786c55534443000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000080000000000000000000000000000000000000000000000000000000000000004053d466178493c68d7df38e30e291dd9f7837d25ddef9eb126b23610d75eb8b3e000000000000000000000000000000000000000000000000000000000000000341584c000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000800000000000000000000000000000000000000000000000000000000000000040d4f3de95c9dc8f66795edc5219609bb874371e6d7fe688fbe15a92bc0ee0a889000000000000000000000000000000000000000000000000000000000000000341584c00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000080000000000000000000000000000000000000000000000000000000000000004066b358b85bb956f4d44b79ddd2092e7e14b76ba91f4454b7665a9a6cc3504810000000000000000000000000000000000000000000000000000000000000000761786c5553444300000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000008000000000000000000000000000000000000000000000000000000000000000408c5ad2ffaa8ac0a1c403f589cb8acc726978dc85ef04431316676657611325a3000000000000000000000000000000000000000000000000000000000000000761786c555344430000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000800000000000000000000000000000000000000000000000000000000000000040dbead87253bba7961d78a8dc70d6f93bad72a5cc3808710aeb2e0b7a1f48136d000000000000000000000000000000000000000000000000000000000000000341584c000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000800000000000000000000000000000000000000000000000000000000000000040175a1d7d0687d58eba549b920c58f72acd85b3f32974bf715444d05a3fe5a7a7000000000000000000000000000000000000000000000000000000000000000341584c000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000800000000000000000000000000000000000000000000000000000000000000040b7fbff8df422b68c0e4260f635da3aa5d25e4f28c7bc55cdcd7d2ee05696c48f000000000000000000000000000000000000000000000000000000000000000341584c000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000800000000000000000000000000000000000000000000000000000000000000040fdc954879a4f2ec0f9d1f848a1e9bc16e554a89b3d07e09c7f1a5033ea9955c9000000000000000000000000000000000000000000000000000000000000000341584c0000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000008000000000000000000000000000000000000000000000000000000000000000400891068bb957a7ea53b723a07a56c6c58af9629c5074705e51dffafc7931100d000000000000000000000000000000000000000000000000000000000000000341584c00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000080000000000000000000000000000000000000000000000000000000000000004033fcc5e8d848789577f23f4e23a9629f9d6a2aa0ad1d1b5e4a61fc860b0933fb000000000000000000000000000000000000000000000000000000000000000341584c000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000800000000000000000000000000000000000000000000000000000000000000040ddd2c66bd3fa3ee3ae6786a502ae3db87eb5d20c53534c0ac6c2c480c7f79581000000000000000000000000000000000000000000000000000000000000000341584c00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000001fe00000000000000000000000000000000000000000000000000000000000000080000000000000000000000000000000000000000000000000000000000000080000000000000000000000000000000000000000000000000000000000000178400000000000000000000000000000000000000000000000000000000000000f80000000000000000000000000000000000000000000000000000000000000003b00000000000000000000000006cef1a89423a4bd1ab859588e7c95e33e5dd5030000000000000000000000002416b62fae3cc9aaaa3d83560faaa099d65b9c6200000000000000000000000025b9437e280fd897e7b92fb969695a9ecfc679a600000000000000000000000025d57ab822c2d16ba0c26b2605204008bb2d5b760000000000000000000000002ab9393d3c4d39fe246f36740e349343aa45926e0000000000000000000000002e0be57ed7d7d0015e15495f5b2cffd1415ca9f40000000000000000000000002f885750185e9b0b50299f67619ccc5029b4fe780000000000000000000000003025973fbc55b3db307b22f5cf950f6e721e1e590000000000000000000000004cfa354d1391366409d8e15cfdaacb548166045300000000000000000000000052ab14b8f00bde612fd3a0efedb874a392e912a100000000000000000000000056a82852cf6fbe733d3bebd203345c8a3af4625f0000000000000000000000005c267d29094484dc6148a314f1da01476e81e3790000000000000000000000005d1bb3c6252377b0fee9a2f5784211e3bf6be9eb0000000000000000000000005f0d2b668452b194da9c4bb44039b46838a7fb4d0000000000000000000000005f709ffda63add6d93f9d5b0d6c8a93687f2060500000000000000000000000063897e57716a3136b367e6b04bdd148bb50743280000000000000000000000006c819a78f2f50957257f3f939533b355b9f53bc10000000000000000000000006c8e5111f0d7fa75f2bbff2c94e3743f4f509c890000000000000000000000006dc62faf15b9c63a30d60e8c51221628cd73441d0000000000000000000000006e9814e6d369d893d178ba086b79cab14456422b000000000000000000000000713643c8fdaf910c92caa2879133d3cf80e47cb7000000000000000000000000766075716f592113e3d89195670c3662b05b71a40000000000000000000000007a05ca9c569695b133c2c3f4363a2f05b87ae40b0000000000000000000000007cd4d2a644f9e480ea5180d8596e98f3bdf4e13900000000000000000000000083166fe68653e9431b36848d59bfaf17aceaed3c0000000000000000000000008b44ba5eb8e346dfe00a413c9d915b07a12b5492000000000000000000000000938e2dd4c5b0082c33d240c551ef7cc4b4bd89cc00000000000000000000000094e610ed1204199b69432a86e5ce2bc09b7b5c8d000000000000000000000000960cb2d7dd310d8f1011fa5e43efbac609e12f6a00000000000000000000000096c71ad79e004d547a6f4a7ca7ceb00e098a75db00000000000000000000000097dc92c1047638ce6cfb1e48680217cbf61605070000000000000000000000009c09e7e02e62d907565a6f8bd9a3f7f9519bb9a60000000000000000000000009ea41855d3caa93e41a7d09dff2161acad20a37f0000000000000000000000009eab40758dd776e32a3beb52c3eadf2d9dc8f59a000000000000000000000000a2f7ec24bf2e5b283d5ede4a70f2010353f43992000000000000000000000000a349424aeb5ebabd27e5ef0e948d1cf4e5bb5c93000000000000000000000000a3f5423cb27974aaa0c3f6626a14d7632ea47e2f000000000000000000000000a8be6d3f1ffa56a41a5e8328ab6684a0a8f3f797000000000000000000000000bb8302cc61bbca73cb5f1e33700b184dd27df39f000000000000000000000000bc550c0a985b8ba108425b81282d240d5efe025b000000000000000000000000be21095401b21b6f96a2f62368f38e374e3cdd4d000000000000000000000000bf1c1bf1135a9a8254af6d54df62acffe403df7d000000000000000000000000c00121d03080a59523397e495453a1bae10ac2ee000000000000000000000000caaacc0c574ed10f513cf4abee0878b80c5b2357000000000000000000000000ce283f39132fd4975d8fc3253f9979c9b614c458000000000000000000000000ce860111db05bd9d62099de889d53e998bbf212d000000000000000000000000d2031ae34371610669a5552e77eb83b2f72401d9000000000000000000000000d5f8c62eba364c3268462648dc1780c65a62f798000000000000000000000000e3a3b4dc387a86ae7b414c1931e17b2cda491b65000000000000000000000000e40ca7c2ddf7d30bdb504bf44e2f7bea087ccb92000000000000000000000000e4b2e50e5a780a3b28029f860101e0d0c64dc448000000000000000000000000e589143e35797e8a6f94d8be3c4dda6e7bc08cba000000000000000000000000e630337486f75373496acb6c25b3990c686637fd000000000000000000000000e7d3711884b278ea71c04ba478b669b0bc1f4501000000000000000000000000eb44d60895554f5bf4bff9734caa648ebf2598ce000000000000000000000000ef40aaa580dd27270cda1a2f58ab133b14d1aa6a000000000000000000000000f21d62e811aa647f8f8bec34acaf48a25a1e8b9a000000000000000000000000f8c8d60dca2de390fe4eda46cf801a46ee6cc529000000000000000000000000fc6f79efe7e3dbb8b63f74c8bf6ffc0225e11bca000000000000000000000000000000000000000000000000000000000000003b000000000000000000000000000000000000000000000000000000000000108d00000000000000000000000000000000000000000000000000000000000008fe0000000000000000000000000000000000000000000000000000000000000c2f0000000000000000000000000000000000000000000000000000000000000d9800000000000000000000000000000000000000000000000000000000000006de0000000000000000000000000000000000000000000000000000000000000e9600000000000000000000000000000000000000000000000000000000000008f10000000000000000000000000000000000000000000000000000000000000c030000000000000000000000000000000000000000000000000000000000000c8e00000000000000000000000000000000000000000000000000000000000002230000000000000000000000000000000000000000000000000000000000000891000000000000000000000000000000000000000000000000000000000000078300000000000000000000000000000000000000000000000000000000000002360000000000000000000000000000000000000000000000000000000000000cd90000000000000000000000000000000000000000000000000000000000000a140000000000000000000000000000000000000000000000000000000000000b0c00000000000000000000000000000000000000000000000000000000000002430000000000000000000000000000000000000000000000000000000000000224000000000000000000000000000000000000000000000000000000000000082600000000000000000000000000000000000000000000000000000000000001d90000000000000000000000000000000000000000000000000000000000000d08000000000000000000000000000000000000000000000000000000000000177500000000000000000000000000000000000000000000000000000000000019ff0000000000000000000000000000000000000000000000000000000000000a4f0000000000000000000000000000000000000000000000000000000000000978000000000000000000000000000000000000000000000000000000000000020f0000000000000000000000000000000000000000000000000000000000000baa0000000000000000000000000000000000000000000000000000000000000bef00000000000000000000000000000000000000000000000000000000000008ad000000000000000000000000000000000000000000000000000000000000058900000000000000000000000000000000000000000000000000000000000009b80000000000000000000000000000000000000000000000000000000000000c650000000000000000000000000000000000000000000000000000000000000dac0000000000000000000000000000000000000000000000000000000000000bc4000000000000000000000000000000000000000000000000000000000000118500000000000000000000000000000000000000000000000000000000000007b1000000000000000000000000000000000000000000000000000000000000103f000000000000000000000000000000000000000000000000000000000000022a0000000000000000000000000000000000000000000000000000000000000227000000000000000000000000000000000000000000000000000000000000098c00000000000000000000000000000000000000000000000000000000000005860000000000000000000000000000000000000000000000000000000000000bf90000000000000000000000000000000000000000000000000000000000000fea00000000000000000000000000000000000000000000000000000000000004c80000000000000000000000000000000000000000000000000000000000000f990000000000000000000000000000000000000000000000000000000000000c8500000000000000000000000000000000000000000000000000000000000006c00000000000000000000000000000000000000000000000000000000000000c2c00000000000000000000000000000000000000000000000000000000000006f70000000000000000000000000000000000000000000000000000000000000a220000000000000000000000000000000000000000000000000000000000000f3a00000000000000000000000000000000000000000000000000000000000008710000000000000000000000000000000000000000000000000000000000000cbc000000000000000000000000000000000000000000000000000000000000098900000000000000000000000000000000000000000000000000000000000013fa0000000000000000000000000000000000000000000000000000000000000f3f0000000000000000000000000000000000000000000000000000000000000d8300000000000000000000000000000000000000000000000000000000000004cc0000000000000000000000000000000000000000000000000000000000000c6c000000000000000000000000000000000000000000000000000000000000001a000000000000000000000000000000000000000000000000000000000000034000000000000000000000000000000000000000000000000000000000000003c0000000000000000000000000000000000000000000000000000000000000044000000000000000000000000000000000000000000000000000000000000004c0000000000000000000000000000000000000000000000000000000000000054000000000000000000000000000000000000000000000000000000000000005c0000000000000000000000000000000000000000000000000000000000000064000000000000000000000000000000000000000000000000000000000000006c0000000000000000000000000000000000000000000000000000000000000074000000000000000000000000000000000000000000000000000000000000007c0000000000000000000000000000000000000000000000000000000000000084000000000000000000000000000000000000000000000000000000000000008c0000000000000000000000000000000000000000000000000000000000000094000000000000000000000000000000000000000000000000000000000000009c00000000000000000000000000000000000000000000000000000000000000a400000000000000000000000000000000000000000000000000000000000000ac00000000000000000000000000000000000000000000000000000000000000b400000000000000000000000000000000000000000000000000000000000000bc00000000000000000000000000000000000000000000000000000000000000c400000000000000000000000000000000000000000000000000000000000000cc00000000000000000000000000000000000000000000000000000000000000d400000000000000000000000000000000000000000000000000000000000000dc00000000000000000000000000000000000000000000000000000000000000e400000000000000000000000000000000000000000000000000000000000000ec00000000000000000000000000000000000000000000000000000000000000f400000000000000000000000000000000000000000000000000000000000000fc0000000000000000000000000000000000000000000000000000000000000004178b04a351c892e310f836ce84111f226e2b59612dfb12b7e4620546fc07e830c28febdc4e7e1e979f65c25fb559a583e07c263b1a91c44ab67866d65ef9612631b000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000041d96eb274116cf66570235cf174c45ac6ac5fc3484fa705f855b59aa7966b68141d7bc3faeaefec754546ca59d0d13e17a1cdba29068fd01c92df9f0b71b686411c000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000041995574eae7662e388ec38823b1b177b21af0b834775131a4877759b91c3f9b5900f62999a22042b3d4ccf35caf0c67326d6e5673aabeb9e876927430fbb0c9251c00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000004163e8fb22ec49dda417d33578cb254d4731dcd5bb3951fea20f9d2b4daee7f82e27f1edd0b4ae6df97f03089bb988852d2aefe760cfd13a368ee876c41a65cbb31b0000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000416d8576ece58978dfb244bc86cf03676b937a7e249dae8ac402781f92211a756c46883fd3e981cff438fa1c0ca367bb65070d085f2fdd620e2567b9d8d2c9dd831b000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000041bc2cf7de5a9d47955f16e861e86e63ccfbb80c94b261ecb8c7b49dce6b6d5add713c6873cddd9b5420d02e12cad6db949d351e23014508ba9d6aba63ae9391311c00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000004158ce14379b885a73234072b602109a58f00b094bc30ce224d3316322a8cce7167bb821cd15fa5b000c03956e203ccbf59a47890f119af5037ab98eb1f40aa87a1b00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000004150ab711dc34a02af44cc5497ae054574e2efe643ac99323b1ef2b8294566c0e0449331e64fedb6f80b472ad31bf84150f1ded1a1a24e55c3bed8f25003536ea61c000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000041af76d69ba2fcf9007e7301d71c1efe55a2175da759b01664c3a188203e8fb8684a4db0987da4c933676da809687369e9dffa067f48137c21f99cf012cc820e791b0000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000418dbeface745d74cabbdf5863e397bc06d40f3f11ac18c501d8204a87ed2d182f16d3bc5bf0c3726cbb1940165c6cda1735105b9ec437ac888e7aa9036182eadc1b0000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000415b748001a6d8ca7eb7e90a109cef53bceb26444885f343d8299d51d391bf784c7a0cb2659c95b373fd42db2a7af890431cd615710af2d81cbc7df484f21e2cd11c000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000041249210b837fa86b9f391b4ef14e212c9e039035653452815a5f8acf5c48228a657f47d8de2cbb497b093b101b1261d52131913ebc633f15ced26f62ad66a02341b000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000041b71d9b7d991deb9c7bebd82cb595d0d056abe9288a3759278a9bdf95fb3adf0c34a946b9ac4ec07c0ba1f0af04904d2621a7369cbc9af1b63aac6c3f81b4b0a41b00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000004129e52b2f5f2779d46e17e1a842cf4433984c5c15c65dbc734f4289ee08387033557bf3127149ed9333aca3606585b115b60f315b22507c7d4ee6d6f43241be8b1b0000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000417f2f6840dac370c2afa6ab6a7e268688b7cd993a722557f47eced29bda96b47b7fc5328bc54e960d0680ab63a9ec38c699e12e45778809ccb1b206ee1446c5f41c000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000041577e94d9c6ce954f74cda9f1916bcabe6f79f5bdd13f8343d1a840df3e5cef663eef034e5807f8d8ada1ae657345fcf6cab721a8b457b85680261d6ac15ff0641b000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000041c71b153ec6b33ebbc4d85058cd7a5af7560326c198e874a4d9ebe3424444a5ec1e0adc7f17d309753c9e8c490b97be70b08a6a09bd647f4cf872e04bcf1198071c00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000004102641dcf544f277e60f29f675f7332393c77e4ca0b6910713c9f63c46cf2cff20d5c5a621b6d96a3c809ef32af91552321836360255eda813d622026f931eefb1c00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000004198f5b24330bc6c544f306de92a06c9f0a736c35ba4980d24ddef0889395288c81b21730e1c7c4d8b8ed10f167b46a1a1c0f8b9b8e032c0c29e940e6a3fd743011c000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000041e64ec926886ad800bc3a515b1ec587f918d45132e4117371f2c04f44d3cee0340d06b8a7719d0907b4b8add86165fd9544c81cb3a313bae51c4e486f765c88f31b000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000041919de19252b914c5a24e4e17a15334b55e3516736d77f9e3bbdd9eb2ed3772f95cf64bbebc5700d340df29e192b06c62cb9210bdb2a02f2a241f91b04d4b85e11c000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000041f36a88acf9563c11dd6b7260f1ae97875da0f283426d4c6d18af3e8b494c2d33153db701cf9e2dee49554d8ffeb6e1e6668870ab3d7f52b476d9f9dbeab0557f1b000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000041904b8ed91d65f227284331905b7c27c1c444d07cf117176c800a56403ce3bf717ebf9f9f22bda72e15f8927c2de0b588215ddeb843550b5d1ed64de30170273e1b000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000041bee456d591a1f9e09e445226d6ee80e929ae1f1e6938457d2073461b7389171360277233d20eb10a2a8e53645dd8a1aa95bb4a103d0d66276e4c1e51f36ed1f11b00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000004127ae576319940182ab062e4342bd818e6fe4e7937cb81eb37a748f251fd04943484c58bf06dcc36eff3f12f01f6b29a2a6fb8a05aca2a67f501416b8b30ae18f1b000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000041c33ff523371e70dee70e0dfef77abfd1702f13c2998b95456f01bdcaf4dd4ebc31708efdad83ef022f52c00dbc9452ad67ed41e2f6253536b5035ce3572222891c00000000000000000000000000000000000000000000000000000000000000").expect("bad input");
         let (msg, raw_proof) = decode_input(&input).expect("Input should decode");
-        let proof = decode(&raw_proof).expect("Proof should decode");
+        let proof = decode::<Secp256k1Verifier>(&raw_proof).expect("Proof should decode");
 
         let msg_hash = H256::from_slice(&to_eth_signed_message_hash(keccak_256(msg.as_slice())));
 
-        assert!(validate_signatures(msg_hash, proof,).is_ok());
+        assert!(validate_signatures::<Secp256k1Verifier>(msg_hash, proof,).is_ok());
+    }
+
+    #[test]
+    fn signers_counted_once_and_in_order() {
+        let msg_hash = H256::from_slice(&to_eth_signed_message_hash(keccak_256(&[1, 2, 3])));
+        let keys: Vec<_> = (0..3).map(|_| ecdsa::generate_keypair()).collect();
+        let operators: Vec<Address> = keys
+            .iter()
+            .map(|(public, _)| {
+                H160::from(H256::from_slice(&keccak_256(public)))
+                    .to_fixed_bytes()
+                    .into()
+            })
+            .collect();
+        let sigs: Vec<Vec<u8>> = keys
+            .iter()
+            .map(|(_, secret)| ecdsa::sign_message(msg_hash, secret))
+            .collect();
+        let validate = |signed_by: &[usize]| {
+            validate_signatures::<Secp256k1Verifier>(
+                msg_hash,
+                Proof {
+                    operators: operators.clone(),
+                    weights: vec![1, 1, 1],
+                    threshold: 3,
+                    signatures: signed_by.iter().map(|i| sigs[*i].clone()).collect(),
+                },
+            )
+        };
+
+        assert_eq!(validate(&[0, 1, 2]), Ok(3));
+
+        // Replaying a single signature doesn't reach the threshold
+        assert_eq!(validate(&[0, 0, 0]), Err(SignatureError::DuplicateSigner));
+        assert_eq!(validate(&[2, 2, 2]), Err(SignatureError::DuplicateSigner));
+        assert_eq!(validate(&[0, 1, 0]), Err(SignatureError::DuplicateSigner));
+
+        // Signatures follow the order of the operators
+        assert_eq!(validate(&[0, 2, 1]), Err(SignatureError::UnorderedSigners));
+        assert_eq!(validate(&[1, 0, 2]), Err(SignatureError::UnorderedSigners));
+
+        // Operators can be skipped, their weight is just not counted
+        assert_eq!(validate(&[0, 2]), Err(SignatureError::LowSignaturesWeight));
+
+        // Signed by someone else
+        let (_, other) = ecdsa::generate_keypair();
+        let mut proof = decode::<Secp256k1Verifier>(&encode(
+            operators.iter().map(|x| x.0).collect(),
+            vec![1, 1, 1],
+            1,
+            vec![ecdsa::sign_message(msg_hash, &other)],
+        ))
+        .expect("Should decode proof");
+        assert_eq!(
+            validate_signatures::<Secp256k1Verifier>(msg_hash, proof),
+            Err(SignatureError::MalformedSigners)
+        );
+
+        // Weights missing for some operators
+        proof = Proof {
+            operators: operators.clone(),
+            weights: vec![1],
+            threshold: 2,
+            signatures: vec![sigs[0].clone(), sigs[2].clone()],
+        };
+        assert_eq!(
+            validate_signatures::<Secp256k1Verifier>(msg_hash, proof),
+            Err(SignatureError::MalformedSigners)
+        );
+    }
+
+    #[test]
+    fn signers_found_in_large_sets() {
+        let msg_hash = H256::from_slice(&to_eth_signed_message_hash(keccak_256(&[1, 2, 3])));
+        let keys: Vec<_> = (0..70).map(|_| ecdsa::generate_keypair()).collect();
+        let operators: Vec<Address> = keys
+            .iter()
+            .map(|(public, _)| {
+                H160::from(H256::from_slice(&keccak_256(public)))
+                    .to_fixed_bytes()
+                    .into()
+            })
+            .collect();
+        let validate = |signed_by: Vec<usize>| {
+            validate_signatures::<Secp256k1Verifier>(
+                msg_hash,
+                Proof {
+                    operators: operators.clone(),
+                    weights: vec![1; 70],
+                    threshold: 35,
+                    signatures: signed_by
+                        .iter()
+                        .map(|i| ecdsa::sign_message(msg_hash, &keys[*i].1))
+                        .collect(),
+                },
+            )
+        };
+
+        assert_eq!(validate((35..70).collect()), Ok(35));
+        assert_eq!(validate((0..70).step_by(2).collect()), Ok(35));
+
+        let mut swapped: Vec<usize> = (35..70).collect();
+        swapped.swap(33, 34);
+        assert_eq!(validate(swapped), Err(SignatureError::UnorderedSigners));
+    }
+
+    #[test]
+    fn weighted_signers_proofs() {
+        let domain_separator = H256::repeat_byte(0xd0);
+        let data_hash = H256(keccak_256(&[1, 2, 3]));
+        let mut keys: Vec<_> = (0..2)
+            .map(|_| {
+                let (public, secret) = ecdsa::generate_keypair();
+                let address: Address = H160::from(H256::from_slice(&keccak_256(&public)))
+                    .to_fixed_bytes()
+                    .into();
+                (address, secret)
+            })
+            .collect();
+        keys.sort_by_key(|(address, _)| *address);
+        let signers = WeightedSigners {
+            signers: keys.iter().map(|(address, _)| (*address, 1)).collect(),
+            threshold: 2,
+            nonce: H256::repeat_byte(1),
+        };
+        let msg_hash = message_hash(
+            ProofVersion::WeightedSigners,
+            domain_separator,
+            signers.hash(),
+            data_hash,
+        );
+        let sign = |msg_hash: H256| -> Vec<Vec<u8>> {
+            keys.iter()
+                .map(|(_, secret)| ecdsa::sign_message(msg_hash, secret))
+                .collect()
+        };
+
+        let encoded = encode_weighted_signers(&signers, sign(msg_hash));
+        let proof =
+            VersionedProof::decode::<Secp256k1Verifier>(ProofVersion::WeightedSigners, &encoded)
+                .expect("Should decode proof");
+        assert_eq!(
+            proof,
+            VersionedProof::WeightedSigners(WeightedSignersProof {
+                signers: signers.clone(),
+                signatures: sign(msg_hash),
+            })
+        );
+        assert_eq!(proof.operators_hash(), signers.hash());
+        assert_eq!(proof.operators_len(), 2);
+        assert_eq!(
+            estimate_counts(ProofVersion::WeightedSigners, &encoded),
+            (2, 2)
+        );
+        assert_eq!(
+            proof.validate_signatures::<Secp256k1Verifier>(domain_separator, data_hash),
+            Ok(2)
+        );
+
+        // The nonce is part of the signer set
+        let renewed = WeightedSigners {
+            nonce: H256::repeat_byte(2),
+            ..signers.clone()
+        };
+        assert_ne!(renewed.hash(), signers.hash());
+
+        // Signatures are bound to the domain separator and the format
+        let decode = |signatures| {
+            VersionedProof::decode::<Secp256k1Verifier>(
+                ProofVersion::WeightedSigners,
+                &encode_weighted_signers(&signers, signatures),
+            )
+            .expect("Should decode proof")
+        };
+        assert!(decode(sign(msg_hash))
+            .validate_signatures::<Secp256k1Verifier>(H256::repeat_byte(0xd1), data_hash)
+            .is_err());
+        let legacy_hash = H256(to_eth_signed_message_hash(data_hash.0));
+        assert!(decode(sign(legacy_hash))
+            .validate_signatures::<Secp256k1Verifier>(domain_separator, data_hash)
+            .is_err());
+        assert_eq!(
+            decode_weighted_signers::<Secp256k1Verifier>(&encode_weighted_signers(
+                &signers,
+                vec![vec![1u8; 64]]
+            )),
+            Err(ProofError::InvalidSignatureLength)
+        );
     }
 
     #[test]
@@ -221,25 +857,20 @@ pub mod proof_tests {
         ])
     }
 
+    /// Test utils function that encodes a `WeightedSignersProof` to ethabi::Bytes
+    pub fn encode_weighted_signers(
+        signers: &WeightedSigners,
+        signatures: Vec<Vec<u8>>,
+    ) -> ethabi::Bytes {
+        ethabi::encode(&[Token::Tuple(vec![
+            signers.to_token(),
+            Token::Array(signatures.into_iter().map(Token::Bytes).collect()),
+        ])])
+    }
+
     /// Test utils function to decode the input of a `execute` message, expected to contain a
     /// bytearray with the data of the call to be executed and a bytearray containing the proof.
     pub fn decode_input(input: &[u8]) -> Result<(Vec<u8>, Vec<u8>), ethabi::Error> {
-        let res = ethabi::decode(
-            &[
-                // data
-                ParamType::Bytes,
-                // proof
-                ParamType::Bytes,
-            ],
-            input,
-        );
-
-        match res {
-            Ok(params) => match params.as_slice() {
-                [Token::Bytes(data), Token::Bytes(proof)] => Ok((data.clone(), proof.clone())),
-                _ => panic!("todo(nuno): wrong input"),
-            },
-            _ => panic!("todo(nuno): failed to decode input"),
-        }
+        crate::abi::decode_input(input)
     }
 }
@@ -1,12 +1,14 @@
 use super::*;
-use codec::Encode;
+use codec::{Decode, Encode};
 use ethabi::{ParamType, Token};
+use frame_support::traits::{Currency, GetStorageVersion, Hooks, StorageVersion};
+use frame_support::weights::Weight;
 use frame_support::{assert_noop, assert_ok};
 use frame_system::Call as SystemCall;
 use mock::*;
 use pallet::Call as AxelarGatewayCall;
 use sp_core::{keccak_256, H160, H256, U256};
-use sp_runtime::traits::BadOrigin;
+use sp_runtime::traits::{BadOrigin, Dispatchable};
 use sp_runtime::DispatchError;
 
 #[test]
@@ -38,6 +40,16 @@ fn validate_operatorship_params() {
             Error::<Runtime>::InvalidOperators,
         );
 
+        // new_operators is larger than MaxOperators
+        assert_noop!(
+            AxelarGateway::validate_operatorship(
+                (1..=101u8).map(|i| [i; 20]).collect(),
+                vec![1u128; 101],
+                1u128
+            ),
+            Error::<Runtime>::TooManyOperators,
+        );
+
         let mut new_operators: Vec<[u8; 20]> = vec![[1; 20]];
         let mut new_weights: Vec<u128> = vec![];
         // new_weights do not match new_operators length
@@ -46,6 +58,16 @@ fn validate_operatorship_params() {
             Error::<Runtime>::InvalidWeights,
         );
 
+        // total weight overflows
+        assert_noop!(
+            AxelarGateway::validate_operatorship(
+                vec![[1; 20], [2; 20]],
+                vec![u128::MAX, 1u128],
+                1u128
+            ),
+            Error::<Runtime>::InvalidWeights,
+        );
+
         new_operators = vec![[1; 20], [2; 20], [3; 20]];
         new_weights = vec![10u128, 10u128, 20u128];
         // new_weights will never reach threshold
@@ -70,6 +92,83 @@ fn validate_operatorship_params() {
     });
 }
 
+#[test]
+fn genesis_operators() {
+    let operators = vec![[1; 20], [2; 20], [3; 20]];
+    let weights = vec![10u128, 10u128, 20u128];
+    let precomputed_hash = H256::from([
+        232, 1, 82, 130, 189, 175, 253, 64, 101, 205, 209, 35, 92, 250, 52, 60, 120, 107, 11, 183,
+        201, 98, 82, 106, 176, 13, 108, 109, 18, 47, 214, 160,
+    ]);
+
+    ExtBuilder::default()
+        .with_operators(operators, weights, 20u128)
+        .build()
+        .execute_with(|| {
+            assert_eq!(CurrentEpoch::<Runtime>::get(), 1);
+            assert_eq!(HashForEpoch::<Runtime>::get(1), precomputed_hash);
+            assert_eq!(EpochForHash::<Runtime>::get(precomputed_hash), 1);
+        });
+
+    // No operators configured leaves the gateway uninitialised
+    ExtBuilder::default().build().execute_with(|| {
+        assert_eq!(CurrentEpoch::<Runtime>::get(), 0);
+        assert!(!HashForEpoch::<Runtime>::contains_key(1));
+    });
+}
+
+#[test]
+#[should_panic(expected = "Genesis operators must be valid")]
+fn genesis_invalid_operators() {
+    // Unsorted operators
+    ExtBuilder::default()
+        .with_operators(vec![[2; 20], [1; 20]], vec![10u128, 10u128], 10u128)
+        .build();
+}
+
+#[test]
+fn genesis_source_chains() {
+    let address = String::from("0x5f927395213ee6b95de97bddcb1b2b1c0f16844d");
+    ExtBuilder::default()
+        .with_source_chains(
+            vec![String::from("ethereum"), String::from("moonbeam")],
+            vec![(String::from("moonbeam"), address.clone())],
+        )
+        .build()
+        .execute_with(|| {
+            let ethereum = AxelarGateway::chain_name("ethereum").unwrap();
+            let moonbeam = AxelarGateway::chain_name("moonbeam").unwrap();
+            assert_eq!(
+                AxelarGateway::trusted_source_chain(ethereum),
+                Some(AddressPolicy::Any)
+            );
+            assert_eq!(
+                AxelarGateway::trusted_source_chain(&moonbeam),
+                Some(AddressPolicy::TrustedOnly)
+            );
+            assert!(AxelarGateway::trusted_source_address(
+                moonbeam,
+                AxelarGateway::address(&address).unwrap()
+            )
+            .is_some());
+            assert_eq!(
+                AxelarGateway::trusted_source_chain(AxelarGateway::chain_name("polygon").unwrap()),
+                None
+            );
+        });
+}
+
+#[test]
+#[should_panic]
+fn genesis_source_addresses_of_unknown_chain() {
+    ExtBuilder::default()
+        .with_source_chains(
+            vec![String::from("ethereum")],
+            vec![(String::from("moonbeam"), String::from("0x01"))],
+        )
+        .build();
+}
+
 #[test]
 fn transfer_operatorship() {
     ExtBuilder::default().build().execute_with(|| {
@@ -131,6 +230,339 @@ fn transfer_operatorship() {
     });
 }
 
+#[test]
+fn force_set_operators() {
+    let genesis_operators = vec![[1; 20], [2; 20], [3; 20]];
+    let genesis_weights = vec![10u128, 10u128, 20u128];
+    let genesis_hash = H256::from([
+        232, 1, 82, 130, 189, 175, 253, 64, 101, 205, 209, 35, 92, 250, 52, 60, 120, 107, 11, 183,
+        201, 98, 82, 106, 176, 13, 108, 109, 18, 47, 214, 160,
+    ]);
+
+    ExtBuilder::default()
+        .with_operators(genesis_operators.clone(), genesis_weights.clone(), 20u128)
+        .build()
+        .execute_with(|| {
+            // Wrong Origin
+            assert_noop!(
+                AxelarGateway::force_set_operators(
+                    RuntimeOrigin::signed(ALICE),
+                    vec![[4; 20]],
+                    vec![10u128],
+                    10u128,
+                    H256::zero(),
+                    false
+                ),
+                BadOrigin,
+            );
+            assert_noop!(
+                AxelarGateway::force_set_operators(
+                    RawOrigin::Bridge.into(),
+                    vec![[4; 20]],
+                    vec![10u128],
+                    10u128,
+                    H256::zero(),
+                    false
+                ),
+                BadOrigin,
+            );
+
+            // Same validation as a regular transfer
+            assert_noop!(
+                AxelarGateway::force_set_operators(
+                    RuntimeOrigin::root(),
+                    vec![],
+                    vec![],
+                    0u128,
+                    H256::zero(),
+                    false
+                ),
+                Error::<Runtime>::InvalidOperators,
+            );
+            assert_noop!(
+                AxelarGateway::force_set_operators(
+                    RuntimeOrigin::root(),
+                    genesis_operators,
+                    genesis_weights,
+                    20u128,
+                    H256::zero(),
+                    false
+                ),
+                Error::<Runtime>::DuplicateOperators,
+            );
+
+            // Keep previous operators
+            assert_ok!(AxelarGateway::force_set_operators(
+                RuntimeOrigin::root(),
+                vec![[4; 20]],
+                vec![10u128],
+                10u128,
+                H256::zero(),
+                false
+            ));
+            let second_hash = HashForEpoch::<Runtime>::get(2);
+            assert_eq!(CurrentEpoch::<Runtime>::get(), 2);
+            assert_eq!(EpochForHash::<Runtime>::get(second_hash), 2);
+            assert_eq!(EpochForHash::<Runtime>::get(genesis_hash), 1);
+            event_exists(Event::<Runtime>::OperatorsForceSet {
+                new_operator_hash: second_hash,
+                new_epoch: 2,
+                revoked_previous: false,
+            });
+
+            // Revoke previous operators
+            assert_ok!(AxelarGateway::force_set_operators(
+                RuntimeOrigin::root(),
+                vec![[5; 20]],
+                vec![10u128],
+                10u128,
+                H256::zero(),
+                true
+            ));
+            let third_hash = HashForEpoch::<Runtime>::get(3);
+            assert_eq!(CurrentEpoch::<Runtime>::get(), 3);
+            assert_eq!(EpochForHash::<Runtime>::get(third_hash), 3);
+            assert!(!EpochForHash::<Runtime>::contains_key(genesis_hash));
+            assert!(!EpochForHash::<Runtime>::contains_key(second_hash));
+            event_exists(Event::<Runtime>::OperatorsForceSet {
+                new_operator_hash: third_hash,
+                new_epoch: 3,
+                revoked_previous: true,
+            });
+        });
+}
+
+#[test]
+fn transfer_operatorship_prunes_expired_epochs() {
+    ExtBuilder::default()
+        .with_operators(vec![[1; 20]], vec![10u128], 10u128)
+        .build()
+        .execute_with(|| {
+            let genesis_hash = HashForEpoch::<Runtime>::get(1);
+
+            // Fill up the retention period
+            for i in 2..=OLD_KEY_RETENTION {
+                assert_ok!(AxelarGateway::transfer_operatorship(
+                    RawOrigin::Bridge.into(),
+                    vec![[i as u8; 20]],
+                    vec![10u128],
+                    10u128
+                ));
+            }
+            assert_eq!(HashForEpoch::<Runtime>::get(1), genesis_hash);
+            assert_eq!(EpochForHash::<Runtime>::get(genesis_hash), 1);
+
+            // Epoch 1 falls out of retention
+            assert_ok!(AxelarGateway::transfer_operatorship(
+                RawOrigin::Bridge.into(),
+                vec![[OLD_KEY_RETENTION as u8 + 1; 20]],
+                vec![10u128],
+                10u128
+            ));
+            assert!(!HashForEpoch::<Runtime>::contains_key(1));
+            assert!(!EpochForHash::<Runtime>::contains_key(genesis_hash));
+            assert!(HashForEpoch::<Runtime>::contains_key(2));
+            event_exists(Event::<Runtime>::OperatorsExpired {
+                epoch: 1,
+                operators_hash: genesis_hash,
+            });
+
+            // An expired set can be installed again
+            assert_ok!(AxelarGateway::transfer_operatorship(
+                RawOrigin::Bridge.into(),
+                vec![[1; 20]],
+                vec![10u128],
+                10u128
+            ));
+            assert_eq!(
+                EpochForHash::<Runtime>::get(genesis_hash),
+                OLD_KEY_RETENTION + 2
+            );
+        });
+}
+
+#[test]
+fn operator_retention_is_configurable() {
+    // Only the current operators are accepted
+    ExtBuilder::default()
+        .with_operator_retention(1)
+        .with_operators(vec![[1; 20]], vec![10u128], 10u128)
+        .build()
+        .execute_with(|| {
+            let genesis_hash = AxelarGateway::current_operators_hash();
+            assert!(AxelarGateway::is_operators_hash_valid(genesis_hash));
+
+            assert_ok!(AxelarGateway::transfer_operatorship(
+                RawOrigin::Bridge.into(),
+                vec![[2; 20]],
+                vec![10u128],
+                10u128
+            ));
+            assert!(!AxelarGateway::is_operators_hash_valid(genesis_hash));
+            assert!(AxelarGateway::is_operators_hash_valid(
+                AxelarGateway::current_operators_hash()
+            ));
+            // Pruned straight away
+            assert!(!HashForEpoch::<Runtime>::contains_key(1));
+        });
+
+    // The current and the two previous operators are accepted
+    ExtBuilder::default()
+        .with_operator_retention(3)
+        .with_operators(vec![[1; 20]], vec![10u128], 10u128)
+        .build()
+        .execute_with(|| {
+            let genesis_hash = AxelarGateway::current_operators_hash();
+
+            for i in 2..=3u8 {
+                assert_ok!(AxelarGateway::transfer_operatorship(
+                    RawOrigin::Bridge.into(),
+                    vec![[i; 20]],
+                    vec![10u128],
+                    10u128
+                ));
+                assert!(AxelarGateway::is_operators_hash_valid(genesis_hash));
+            }
+
+            assert_ok!(AxelarGateway::transfer_operatorship(
+                RawOrigin::Bridge.into(),
+                vec![[4; 20]],
+                vec![10u128],
+                10u128
+            ));
+            assert!(!AxelarGateway::is_operators_hash_valid(genesis_hash));
+            assert!(AxelarGateway::is_operators_hash_valid(
+                AxelarGateway::hash_for_epoch(2)
+            ));
+            assert!(!HashForEpoch::<Runtime>::contains_key(1));
+            assert_eq!(HashForEpoch::<Runtime>::iter().count(), 3);
+        });
+
+    // Lowering the retention leaves expired epochs to the migration
+    ExtBuilder::default()
+        .with_operators(vec![[1; 20]], vec![10u128], 10u128)
+        .build()
+        .execute_with(|| {
+            for i in 2..=5u8 {
+                assert_ok!(AxelarGateway::transfer_operatorship(
+                    RawOrigin::Bridge.into(),
+                    vec![[i; 20]],
+                    vec![10u128],
+                    10u128
+                ));
+            }
+
+            OperatorRetention::set(&2);
+            run_upgrade::<migrations::PruneExpiredOperators<Runtime>>();
+
+            assert_eq!(HashForEpoch::<Runtime>::iter().count(), 2);
+            assert!(HashForEpoch::<Runtime>::contains_key(4));
+            assert!(HashForEpoch::<Runtime>::contains_key(5));
+        });
+}
+
+#[test]
+fn migration_prunes_expired_epochs() {
+    ExtBuilder::default().build().execute_with(|| {
+        let current_epoch = OLD_KEY_RETENTION + 4;
+        for epoch in 1..=current_epoch {
+            HashForEpoch::<Runtime>::insert(epoch, H256::from_low_u64_be(epoch));
+            EpochForHash::<Runtime>::insert(H256::from_low_u64_be(epoch), epoch);
+        }
+        CurrentEpoch::<Runtime>::set(current_epoch);
+
+        run_upgrade::<migrations::PruneExpiredOperators<Runtime>>();
+
+        for epoch in 1..=4 {
+            assert!(!HashForEpoch::<Runtime>::contains_key(epoch));
+            assert!(!EpochForHash::<Runtime>::contains_key(
+                H256::from_low_u64_be(epoch)
+            ));
+            event_exists(Event::<Runtime>::OperatorsExpired {
+                epoch,
+                operators_hash: H256::from_low_u64_be(epoch),
+            });
+        }
+        for epoch in 5..=current_epoch {
+            assert_eq!(
+                HashForEpoch::<Runtime>::get(epoch),
+                H256::from_low_u64_be(epoch)
+            );
+            assert_eq!(
+                EpochForHash::<Runtime>::get(H256::from_low_u64_be(epoch)),
+                epoch
+            );
+        }
+        assert_eq!(
+            HashForEpoch::<Runtime>::iter().count(),
+            OLD_KEY_RETENTION as usize
+        );
+    });
+}
+
+#[test]
+fn migrate_to_v1() {
+    // Unversioned gateways had no source chain registry
+    ExtBuilder::default()
+        .with_source_chains(vec![], vec![])
+        .build()
+        .execute_with(|| {
+            StorageVersion::new(0).put::<AxelarGateway>();
+            let current_epoch = OLD_KEY_RETENTION + 2;
+            for epoch in 1..=current_epoch {
+                HashForEpoch::<Runtime>::insert(epoch, H256::from_low_u64_be(epoch));
+                EpochForHash::<Runtime>::insert(H256::from_low_u64_be(epoch), epoch);
+            }
+            CurrentEpoch::<Runtime>::set(current_epoch);
+            CommandExecuted::<Runtime>::insert(H256::repeat_byte(1), 36);
+            ContractCallApproved::<Runtime>::insert(H256::repeat_byte(2), ());
+
+            run_upgrade::<migrations::v1::MigrateToV1<Runtime, MigratedSourceChains>>();
+
+            assert_eq!(AxelarGateway::on_chain_storage_version(), 1);
+            assert_eq!(
+                AxelarGateway::on_chain_storage_version(),
+                AxelarGateway::current_storage_version()
+            );
+            assert!(!HashForEpoch::<Runtime>::contains_key(1));
+            assert!(!HashForEpoch::<Runtime>::contains_key(2));
+            assert_eq!(
+                HashForEpoch::<Runtime>::iter().count(),
+                OLD_KEY_RETENTION as usize
+            );
+            assert_eq!(CurrentEpoch::<Runtime>::get(), current_epoch);
+            assert!(CommandExecuted::<Runtime>::contains_key(H256::repeat_byte(
+                1
+            )));
+            assert!(ContractCallApproved::<Runtime>::contains_key(
+                H256::repeat_byte(2)
+            ));
+            assert_eq!(TrustedSourceChains::<Runtime>::iter().count(), 2);
+            assert_eq!(
+                AxelarGateway::trusted_source_chain(AxelarGateway::chain_name("moonbeam").unwrap()),
+                Some(AddressPolicy::Any)
+            );
+            assert_eq!(
+                AxelarGateway::trusted_source_chain(AxelarGateway::chain_name("osmosis").unwrap()),
+                Some(AddressPolicy::TrustedOnly)
+            );
+
+            // Already on version 1, later expired epochs are left to the pallet and the registry
+            // to the admin
+            CurrentEpoch::<Runtime>::set(current_epoch + 1);
+            assert_ok!(AxelarGateway::remove_source_chain(
+                RuntimeOrigin::root(),
+                String::from("moonbeam")
+            ));
+            run_upgrade::<migrations::v1::MigrateToV1<Runtime, MigratedSourceChains>>();
+            assert!(HashForEpoch::<Runtime>::contains_key(3));
+            assert_eq!(
+                AxelarGateway::trusted_source_chain(AxelarGateway::chain_name("moonbeam").unwrap()),
+                None
+            );
+        });
+}
+
 #[test]
 fn approve_contract_call() {
     ExtBuilder::default().build().execute_with(|| {
@@ -153,8 +585,8 @@ fn approve_contract_call() {
         assert_noop!(
             AxelarGateway::approve_contract_call(
                 RuntimeOrigin::signed(ALICE),
-                source_chain.clone(),
-                source_address.clone(),
+                bounded_str(&source_chain),
+                bounded_str(&source_address),
                 contract_address,
                 payload_hash,
                 source_tx_hash,
@@ -166,8 +598,8 @@ fn approve_contract_call() {
 
         assert_ok!(AxelarGateway::approve_contract_call(
             RawOrigin::Bridge.into(),
-            source_chain.clone(),
-            source_address.clone(),
+            bounded_str(&source_chain),
+            bounded_str(&source_address),
             contract_address,
             payload_hash,
             source_tx_hash,
@@ -191,6 +623,65 @@ fn approve_contract_call() {
     });
 }
 
+#[test]
+fn gateway_state_queries() {
+    let operators = vec![[1; 20], [2; 20], [3; 20]];
+    let weights = vec![10u128, 10u128, 20u128];
+    let operators_hash = H256::from([
+        232, 1, 82, 130, 189, 175, 253, 64, 101, 205, 209, 35, 92, 250, 52, 60, 120, 107, 11, 183,
+        201, 98, 82, 106, 176, 13, 108, 109, 18, 47, 214, 160,
+    ]);
+
+    ExtBuilder::default()
+        .with_operators(operators, weights, 20u128)
+        .build()
+        .execute_with(|| {
+            assert_eq!(AxelarGateway::current_epoch(), 1);
+            assert_eq!(AxelarGateway::current_operators_hash(), operators_hash);
+            assert!(AxelarGateway::is_operators_hash_valid(operators_hash));
+            assert!(!AxelarGateway::is_operators_hash_valid(H256::random()));
+
+            // Operators fall out of the retention period
+            CurrentEpoch::<Runtime>::set(1 + OLD_KEY_RETENTION);
+            assert!(!AxelarGateway::is_operators_hash_valid(operators_hash));
+
+            let source_chain = String::from("ethereum");
+            let source_address = String::from("0x5f927395213ee6b95de97bddcb1b2b1c0f16844d");
+            let contract_address = H160::random();
+            let payload_hash = H256::random();
+            let command_id = H256::random();
+
+            assert!(!AxelarGateway::is_contract_call_approved(
+                command_id,
+                source_chain.as_bytes(),
+                source_address.as_bytes(),
+                contract_address,
+                payload_hash
+            ));
+            assert_ok!(AxelarGateway::approve_contract_call(
+                RawOrigin::Bridge.into(),
+                bounded_str(&source_chain),
+                bounded_str(&source_address),
+                contract_address,
+                payload_hash,
+                H256::random(),
+                U256::from(100),
+                command_id
+            ));
+            assert!(AxelarGateway::is_contract_call_approved(
+                command_id,
+                source_chain.as_bytes(),
+                source_address.as_bytes(),
+                contract_address,
+                payload_hash
+            ));
+
+            assert!(!AxelarGateway::is_command_executed(command_id));
+            CommandExecuted::<Runtime>::insert(command_id, 36);
+            assert!(AxelarGateway::is_command_executed(command_id));
+        });
+}
+
 #[test]
 // A proof with valid signatures and an epoch that's the current one should return Ok(true).
 fn validate_proof_works() {
@@ -198,14 +689,14 @@ fn validate_proof_works() {
         // This payload has been taken from a live tx in Axelar
         let input = hex::decode("00000000000000000000000000000000000000000000000000000000000000400000000000000000000000000000000000000000000000000000000000001a4000000000000000000000000000000000000000000000000000000000000019e00000000000000000000000000000000000000000000000000000000000000089000000000000000000000000000000000000000000000000000000000000008000000000000000000000000000000000000000000000000000000000000003200000000000000000000000000000000000000000000000000000000000000ac00000000000000000000000000000000000000000000000000000000000000014024bd9868ae3a24be4618c06e99c4b8c8203c3ed5a7624926c7c1b2d5d4f2f830b61d8ae73e1fa78be41dca6e9eddaf58455c1fede28d9b659db46c2852199641f1bd2329cff9e675a7cfb713c3389e1cce070a52815dfe181e7bee84cebdf1632d682f903de5becca1d53ae70e10e89f9bbb08e34a44659c96cec5400c50aad3af28d1cd27a32b62d17b5b4f1f5dda694c038816ac210cd6041e69b307f68653d3102ef7fab39c68cf4877239f7fecf2390635ccd62188837cbaa92096f6a9f4258411491a045f5712f44d69d579a61fb32fc0e9e0a752da922722036807bdf4a471b6425920feaac5ea7e89a8184f31a8e07bfa7e2b3d8d13afe38c6f002075eed8a192323fa2a6426a9a76844c8795235c257122914b59460b7b93307a8085f25615b4a884455bdd7c3e15ab53bb7ce52c2da7de4c3f0271ed0e501d6b46b66bf20bc5dd1291380cd1968228d6f741ce65f11d65566440a14813dd29a1c45712848d49c6804fd0ee931a63d0aaffe723503c20154cf0ccbf40f4ead91ea247563e35f83efd31cd7ad9e8207b49ad3cac90e3ec0a3bfffbac92d54642b2d1e79538b3ca6bdb613981b8fb3300c64b904f61faf69fa8379419f5a256c076b949158484c76b6a6b3b6ca812fd48a75ad6fba67f234f5600e129de90aeb769fe1932a2dc27048ac32152e068c5d25c5ca69751f8a16d44f4426246817f3e54744b145640a7b52b2cd935d98c0358e1133af7d819ff48b65a5de5b461531574203c3a2c6ece32b9ff28b6004c21544f1235bb311dd00aa857dd1420fcfc0360eb1e055d1f67fde0977937822f0f553b812e92e4f5fe266e3c7dbd188e3ebbc8683f5e534eb6a5772039075f58b3c589a37d377783626a0a08ffbd822d19ff672510000000000000000000000000000000000000000000000000000000000000014000000000000000000000000000000000000000000000000000000000000028000000000000000000000000000000000000000000000000000000000000002c000000000000000000000000000000000000000000000000000000000000003000000000000000000000000000000000000000000000000000000000000000340000000000000000000000000000000000000000000000000000000000000038000000000000000000000000000000000000000000000000000000000000003c000000000000000000000000000000000000000000000000000000000000004000000000000000000000000000000000000000000000000000000000000000440000000000000000000000000000000000000000000000000000000000000048000000000000000000000000000000000000000000000000000000000000004c000000000000000000000000000000000000000000000000000000000000005000000000000000000000000000000000000000000000000000000000000000540000000000000000000000000000000000000000000000000000000000000058000000000000000000000000000000000000000000000000000000000000005c000000000000000000000000000000000000000000000000000000000000006000000000000000000000000000000000000000000000000000000000000000640000000000000000000000000000000000000000000000000000000000000068000000000000000000000000000000000000000000000000000000000000006c00000000000000000000000000000000000000000000000000000000000000700000000000000000000000000000000000000000000000000000000000000074000000000000000000000000000000000000000000000000000000000000000096275726e546f6b656e000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000096275726e546f6b656e000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000096275726e546f6b656e000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000096275726e546f6b656e000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000096275726e546f6b656e000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000096275726e546f6b656e000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000096275726e546f6b656e000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000096275726e546f6b656e000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000096275726e546f6b656e000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000096275726e546f6b656e000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000096275726e546f6b656e000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000096275726e546f6b656e000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000096275726e546f6b656e000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000096275726e546f6b656e000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000096275726e546f6b656e000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000096275726e546f6b656e000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000096275726e546f6b656e000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000096275726e546f6b656e000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000096275726e546f6b656e000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000096275726e546f6b656e000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000140000000000000000000000000000000000000000000000000000000000000280000000000000000000000000000000000000000000000000000000000000032000000000000000000000000000000000000000000000000000000000000003c00000000000000000000000000000000000000000000000000000000000000460000000000000000000000000000000000000000000000000000000000000050000000000000000000000000000000000000000000000000000000000000005a0000000000000000000000000000000000000000000000000000000000000064000000000000000000000000000000000000000000000000000000000000006e00000000000000000000000000000000000000000000000000000000000000780000000000000000000000000000000000000000000000000000000000000082000000000000000000000000000000000000000000000000000000000000008c000000000000000000000000000000000000000000000000000000000000009600000000000000000000000000000000000000000000000000000000000000a000000000000000000000000000000000000000000000000000000000000000aa00000000000000000000000000000000000000000000000000000000000000b400000000000000000000000000000000000000000000000000000000000000be00000000000000000000000000000000000000000000000000000000000000c800000000000000000000000000000000000000000000000000000000000000d200000000000000000000000000000000000000000000000000000000000000dc00000000000000000000000000000000000000000000000000000000000000e60000000000000000000000000000000000000000000000000000000000000008000000000000000000000000000000000000000000000000000000000000000400a6d1770549b534347933ca2f6f45745e6d5b479e7925fe84e63538bdfa8bbb2000000000000000000000000000000000000000000000000000000000000000341584c00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000080000000000000000000000000000000000000000000000000000000000000004064f0cf9822dc7d35f96e1b5a8d710b89c56c781993bb8c0b121cec9d0b0e433a000000000000000000000000000000000000000000000000000000000000000341584c000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000800000000000000000000000000000000000000000000000000000000000000040f2b3576aee3d5ef0c8fa5bab2d67744de2b7f5f12e044ae4c5815f59c1c32637000000000000000000000000000000000000000000000000000000000000000761786c555344430000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000800000000000000000000000000000000000000000000000000000000000000040b4adc7f4169073d4b9acb8e333af363a5c1908198b31e7118fb712566583b784000000000000000000000000000000000000000000000000000000000000000341584c000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000800000000000000000000000000000000000000000000000000000000000000040ca2827d2512e5fc79794d5a9d1c796ad96286c1910187ad96e7ecf1ec5229d11000000000000000000000000000000000000000000000000000000000000000341584c0000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000008000000000000000000000000000000000000000000000000000000000000000407df01a951c4c9ca80588e361a4e16d8da8d86c1027a83ac362e88bdbc9d39d0c000000000000000000000000000000000000000000000000000000000000000341584c00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000080000000000000000000000000000000000000000000000000000000000000004031b2e37c24f57b9f6c3e6293a2a9533a1aea2ab985a2736fdf408fe96e6a4190000000000000000000000000000000000000000000000000000000000000000341584c000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000800000000000000000000000000000000000000000000000000000000000000040fd9da3a2d08ea314fdce35b3679584ae7d0cf7fecc8598d5e5d6d32cdd17ff35000000000000000000000000000000000000000000000000000000000000000761786c555344430000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000800000000000000000000000000000000000000000000000000000000000000040a5b9348079667e180c95852ba7641fe3603b2446d0904dbcb11e08273ff6674d000000000000000000000000000000000000000000000000000000000000000761786c55534443000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000080000000000000000000000000000000000000000000000000000000000000004053d466178493c68d7df38e30e291dd9f7837d25ddef9eb126b23610d75eb8b3e000000000000000000000000000000000000000000000000000000000000000341584c000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000800000000000000000000000000000000000000000000000000000000000000040d4f3de95c9dc8f66795edc5219609bb874371e6d7fe688fbe15a92bc0ee0a889000000000000000000000000000000000000000000000000000000000000000341584c00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000080000000000000000000000000000000000000000000000000000000000000004066b358b85bb956f4d44b79ddd2092e7e14b76ba91f4454b7665a9a6cc3504810000000000000000000000000000000000000000000000000000000000000000761786c5553444300000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000008000000000000000000000000000000000000000000000000000000000000000408c5ad2ffaa8ac0a1c403f589cb8acc726978dc85ef04431316676657611325a3000000000000000000000000000000000000000000000000000000000000000761786c555344430000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000800000000000000000000000000000000000000000000000000000000000000040dbead87253bba7961d78a8dc70d6f93bad72a5cc3808710aeb2e0b7a1f48136d000000000000000000000000000000000000000000000000000000000000000341584c000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000800000000000000000000000000000000000000000000000000000000000000040175a1d7d0687d58eba549b920c58f72acd85b3f32974bf715444d05a3fe5a7a7000000000000000000000000000000000000000000000000000000000000000341584c000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000800000000000000000000000000000000000000000000000000000000000000040b7fbff8df422b68c0e4260f635da3aa5d25e4f28c7bc55cdcd7d2ee05696c48f000000000000000000000000000000000000000000000000000000000000000341584c000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000800000000000000000000000000000000000000000000000000000000000000040fdc954879a4f2ec0f9d1f848a1e9bc16e554a89b3d07e09c7f1a5033ea9955c9000000000000000000000000000000000000000000000000000000000000000341584c0000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000008000000000000000000000000000000000000000000000000000000000000000400891068bb957a7ea53b723a07a56c6c58af9629c5074705e51dffafc7931100d000000000000000000000000000000000000000000000000000000000000000341584c00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000080000000000000000000000000000000000000000000000000000000000000004033fcc5e8d848789577f23f4e23a9629f9d6a2aa0ad1d1b5e4a61fc860b0933fb000000000000000000000000000000000000000000000000000000000000000341584c000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000800000000000000000000000000000000000000000000000000000000000000040ddd2c66bd3fa3ee3ae6786a502ae3db87eb5d20c53534c0ac6c2c480c7f79581000000000000000000000000000000000000000000000000000000000000000341584c00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000001fe00000000000000000000000000000000000000000000000000000000000000080000000000000000000000000000000000000000000000000000000000000080000000000000000000000000000000000000000000000000000000000000178400000000000000000000000000000000000000000000000000000000000000f80000000000000000000000000000000000000000000000000000000000000003b00000000000000000000000006cef1a89423a4bd1ab859588e7c95e33e5dd5030000000000000000000000002416b62fae3cc9aaaa3d83560faaa099d65b9c6200000000000000000000000025b9437e280fd897e7b92fb969695a9ecfc679a600000000000000000000000025d57ab822c2d16ba0c26b2605204008bb2d5b760000000000000000000000002ab9393d3c4d39fe246f36740e349343aa45926e0000000000000000000000002e0be57ed7d7d0015e15495f5b2cffd1415ca9f40000000000000000000000002f885750185e9b0b50299f67619ccc5029b4fe780000000000000000000000003025973fbc55b3db307b22f5cf950f6e721e1e590000000000000000000000004cfa354d1391366409d8e15cfdaacb548166045300000000000000000000000052ab14b8f00bde612fd3a0efedb874a392e912a100000000000000000000000056a82852cf6fbe733d3bebd203345c8a3af4625f0000000000000000000000005c267d29094484dc6148a314f1da01476e81e3790000000000000000000000005d1bb3c6252377b0fee9a2f5784211e3bf6be9eb0000000000000000000000005f0d2b668452b194da9c4bb44039b46838a7fb4d0000000000000000000000005f709ffda63add6d93f9d5b0d6c8a93687f2060500000000000000000000000063897e57716a3136b367e6b04bdd148bb50743280000000000000000000000006c819a78f2f50957257f3f939533b355b9f53bc10000000000000000000000006c8e5111f0d7fa75f2bbff2c94e3743f4f509c890000000000000000000000006dc62faf15b9c63a30d60e8c51221628cd73441d0000000000000000000000006e9814e6d369d893d178ba086b79cab14456422b000000000000000000000000713643c8fdaf910c92caa2879133d3cf80e47cb7000000000000000000000000766075716f592113e3d89195670c3662b05b71a40000000000000000000000007a05ca9c569695b133c2c3f4363a2f05b87ae40b0000000000000000000000007cd4d2a644f9e480ea5180d8596e98f3bdf4e13900000000000000000000000083166fe68653e9431b36848d59bfaf17aceaed3c0000000000000000000000008b44ba5eb8e346dfe00a413c9d915b07a12b5492000000000000000000000000938e2dd4c5b0082c33d240c551ef7cc4b4bd89cc00000000000000000000000094e610ed1204199b69432a86e5ce2bc09b7b5c8d000000000000000000000000960cb2d7dd310d8f1011fa5e43efbac609e12f6a00000000000000000000000096c71ad79e004d547a6f4a7ca7ceb00e098a75db00000000000000000000000097dc92c1047638ce6cfb1e48680217cbf61605070000000000000000000000009c09e7e02e62d907565a6f8bd9a3f7f9519bb9a60000000000000000000000009ea41855d3caa93e41a7d09dff2161acad20a37f0000000000000000000000009eab40758dd776e32a3beb52c3eadf2d9dc8f59a000000000000000000000000a2f7ec24bf2e5b283d5ede4a70f2010353f43992000000000000000000000000a349424aeb5ebabd27e5ef0e948d1cf4e5bb5c93000000000000000000000000a3f5423cb27974aaa0c3f6626a14d7632ea47e2f000000000000000000000000a8be6d3f1ffa56a41a5e8328ab6684a0a8f3f797000000000000000000000000bb8302cc61bbca73cb5f1e33700b184dd27df39f000000000000000000000000bc550c0a985b8ba108425b81282d240d5efe025b000000000000000000000000be21095401b21b6f96a2f62368f38e374e3cdd4d000000000000000000000000bf1c1bf1135a9a8254af6d54df62acffe403df7d000000000000000000000000c00121d03080a59523397e495453a1bae10ac2ee000000000000000000000000caaacc0c574ed10f513cf4abee0878b80c5b2357000000000000000000000000ce283f39132fd4975d8fc3253f9979c9b614c458000000000000000000000000ce860111db05bd9d62099de889d53e998bbf212d000000000000000000000000d2031ae34371610669a5552e77eb83b2f72401d9000000000000000000000000d5f8c62eba364c3268462648dc1780c65a62f798000000000000000000000000e3a3b4dc387a86ae7b414c1931e17b2cda491b65000000000000000000000000e40ca7c2ddf7d30bdb504bf44e2f7bea087ccb92000000000000000000000000e4b2e50e5a780a3b28029f860101e0d0c64dc448000000000000000000000000e589143e35797e8a6f94d8be3c4dda6e7bc08cba000000000000000000000000e630337486f75373496acb6c25b3990c686637fd000000000000000000000000e7d3711884b278ea71c04ba478b669b0bc1f4501000000000000000000000000eb44d60895554f5bf4bff9734caa648ebf2598ce000000000000000000000000ef40aaa580dd27270cda1a2f58ab133b14d1aa6a000000000000000000000000f21d62e811aa647f8f8bec34acaf48a25a1e8b9a000000000000000000000000f8c8d60dca2de390fe4eda46cf801a46ee6cc529000000000000000000000000fc6f79efe7e3dbb8b63f74c8bf6ffc0225e11bca000000000000000000000000000000000000000000000000000000000000003b000000000000000000000000000000000000000000000000000000000000108d00000000000000000000000000000000000000000000000000000000000008fe0000000000000000000000000000000000000000000000000000000000000c2f0000000000000000000000000000000000000000000000000000000000000d9800000000000000000000000000000000000000000000000000000000000006de0000000000000000000000000000000000000000000000000000000000000e9600000000000000000000000000000000000000000000000000000000000008f10000000000000000000000000000000000000000000000000000000000000c030000000000000000000000000000000000000000000000000000000000000c8e00000000000000000000000000000000000000000000000000000000000002230000000000000000000000000000000000000000000000000000000000000891000000000000000000000000000000000000000000000000000000000000078300000000000000000000000000000000000000000000000000000000000002360000000000000000000000000000000000000000000000000000000000000cd90000000000000000000000000000000000000000000000000000000000000a140000000000000000000000000000000000000000000000000000000000000b0c00000000000000000000000000000000000000000000000000000000000002430000000000000000000000000000000000000000000000000000000000000224000000000000000000000000000000000000000000000000000000000000082600000000000000000000000000000000000000000000000000000000000001d90000000000000000000000000000000000000000000000000000000000000d08000000000000000000000000000000000000000000000000000000000000177500000000000000000000000000000000000000000000000000000000000019ff0000000000000000000000000000000000000000000000000000000000000a4f0000000000000000000000000000000000000000000000000000000000000978000000000000000000000000000000000000000000000000000000000000020f0000000000000000000000000000000000000000000000000000000000000baa0000000000000000000000000000000000000000000000000000000000000bef00000000000000000000000000000000000000000000000000000000000008ad000000000000000000000000000000000000000000000000000000000000058900000000000000000000000000000000000000000000000000000000000009b80000000000000000000000000000000000000000000000000000000000000c650000000000000000000000000000000000000000000000000000000000000dac0000000000000000000000000000000000000000000000000000000000000bc4000000000000000000000000000000000000000000000000000000000000118500000000000000000000000000000000000000000000000000000000000007b1000000000000000000000000000000000000000000000000000000000000103f000000000000000000000000000000000000000000000000000000000000022a0000000000000000000000000000000000000000000000000000000000000227000000000000000000000000000000000000000000000000000000000000098c00000000000000000000000000000000000000000000000000000000000005860000000000000000000000000000000000000000000000000000000000000bf90000000000000000000000000000000000000000000000000000000000000fea00000000000000000000000000000000000000000000000000000000000004c80000000000000000000000000000000000000000000000000000000000000f990000000000000000000000000000000000000000000000000000000000000c8500000000000000000000000000000000000000000000000000000000000006c00000000000000000000000000000000000000000000000000000000000000c2c00000000000000000000000000000000000000000000000000000000000006f70000000000000000000000000000000000000000000000000000000000000a220000000000000000000000000000000000000000000000000000000000000f3a00000000000000000000000000000000000000000000000000000000000008710000000000000000000000000000000000000000000000000000000000000cbc000000000000000000000000000000000000000000000000000000000000098900000000000000000000000000000000000000000000000000000000000013fa0000000000000000000000000000000000000000000000000000000000000f3f0000000000000000000000000000000000000000000000000000000000000d8300000000000000000000000000000000000000000000000000000000000004cc0000000000000000000000000000000000000000000000000000000000000c6c000000000000000000000000000000000000000000000000000000000000001a000000000000000000000000000000000000000000000000000000000000034000000000000000000000000000000000000000000000000000000000000003c0000000000000000000000000000000000000000000000000000000000000044000000000000000000000000000000000000000000000000000000000000004c0000000000000000000000000000000000000000000000000000000000000054000000000000000000000000000000000000000000000000000000000000005c0000000000000000000000000000000000000000000000000000000000000064000000000000000000000000000000000000000000000000000000000000006c0000000000000000000000000000000000000000000000000000000000000074000000000000000000000000000000000000000000000000000000000000007c0000000000000000000000000000000000000000000000000000000000000084000000000000000000000000000000000000000000000000000000000000008c0000000000000000000000000000000000000000000000000000000000000094000000000000000000000000000000000000000000000000000000000000009c00000000000000000000000000000000000000000000000000000000000000a400000000000000000000000000000000000000000000000000000000000000ac00000000000000000000000000000000000000000000000000000000000000b400000000000000000000000000000000000000000000000000000000000000bc00000000000000000000000000000000000000000000000000000000000000c400000000000000000000000000000000000000000000000000000000000000cc00000000000000000000000000000000000000000000000000000000000000d400000000000000000000000000000000000000000000000000000000000000dc00000000000000000000000000000000000000000000000000000000000000e400000000000000000000000000000000000000000000000000000000000000ec00000000000000000000000000000000000000000000000000000000000000f400000000000000000000000000000000000000000000000000000000000000fc0000000000000000000000000000000000000000000000000000000000000004178b04a351c892e310f836ce84111f226e2b59612dfb12b7e4620546fc07e830c28febdc4e7e1e979f65c25fb559a583e07c263b1a91c44ab67866d65ef9612631b000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000041d96eb274116cf66570235cf174c45ac6ac5fc3484fa705f855b59aa7966b68141d7bc3faeaefec754546ca59d0d13e17a1cdba29068fd01c92df9f0b71b686411c000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000041995574eae7662e388ec38823b1b177b21af0b834775131a4877759b91c3f9b5900f62999a22042b3d4ccf35caf0c67326d6e5673aabeb9e876927430fbb0c9251c00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000004163e8fb22ec49dda417d33578cb254d4731dcd5bb3951fea20f9d2b4daee7f82e27f1edd0b4ae6df97f03089bb988852d2aefe760cfd13a368ee876c41a65cbb31b0000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000416d8576ece58978dfb244bc86cf03676b937a7e249dae8ac402781f92211a756c46883fd3e981cff438fa1c0ca367bb65070d085f2fdd620e2567b9d8d2c9dd831b000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000041bc2cf7de5a9d47955f16e861e86e63ccfbb80c94b261ecb8c7b49dce6b6d5add713c6873cddd9b5420d02e12cad6db949d351e23014508ba9d6aba63ae9391311c00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000004158ce14379b885a73234072b602109a58f00b094bc30ce224d3316322a8cce7167bb821cd15fa5b000c03956e203ccbf59a47890f119af5037ab98eb1f40aa87a1b00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000004150ab711dc34a02af44cc5497ae054574e2efe643ac99323b1ef2b8294566c0e0449331e64fedb6f80b472ad31bf84150f1ded1a1a24e55c3bed8f25003536ea61c000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000041af76d69ba2fcf9007e7301d71c1efe55a2175da759b01664c3a188203e8fb8684a4db0987da4c933676da809687369e9dffa067f48137c21f99cf012cc820e791b0000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000418dbeface745d74cabbdf5863e397bc06d40f3f11ac18c501d8204a87ed2d182f16d3bc5bf0c3726cbb1940165c6cda1735105b9ec437ac888e7aa9036182eadc1b0000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000415b748001a6d8ca7eb7e90a109cef53bceb26444885f343d8299d51d391bf784c7a0cb2659c95b373fd42db2a7af890431cd615710af2d81cbc7df484f21e2cd11c000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000041249210b837fa86b9f391b4ef14e212c9e039035653452815a5f8acf5c48228a657f47d8de2cbb497b093b101b1261d52131913ebc633f15ced26f62ad66a02341b000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000041b71d9b7d991deb9c7bebd82cb595d0d056abe9288a3759278a9bdf95fb3adf0c34a946b9ac4ec07c0ba1f0af04904d2621a7369cbc9af1b63aac6c3f81b4b0a41b00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000004129e52b2f5f2779d46e17e1a842cf4433984c5c15c65dbc734f4289ee08387033557bf3127149ed9333aca3606585b115b60f315b22507c7d4ee6d6f43241be8b1b0000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000417f2f6840dac370c2afa6ab6a7e268688b7cd993a722557f47eced29bda96b47b7fc5328bc54e960d0680ab63a9ec38c699e12e45778809ccb1b206ee1446c5f41c000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000041577e94d9c6ce954f74cda9f1916bcabe6f79f5bdd13f8343d1a840df3e5cef663eef034e5807f8d8ada1ae657345fcf6cab721a8b457b85680261d6ac15ff0641b000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000041c71b153ec6b33ebbc4d85058cd7a5af7560326c198e874a4d9ebe3424444a5ec1e0adc7f17d309753c9e8c490b97be70b08a6a09bd647f4cf872e04bcf1198071c00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000004102641dcf544f277e60f29f675f7332393c77e4ca0b6910713c9f63c46cf2cff20d5c5a621b6d96a3c809ef32af91552321836360255eda813d622026f931eefb1c00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000004198f5b24330bc6c544f306de92a06c9f0a736c35ba4980d24ddef0889395288c81b21730e1c7c4d8b8ed10f167b46a1a1c0f8b9b8e032c0c29e940e6a3fd743011c000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000041e64ec926886ad800bc3a515b1ec587f918d45132e4117371f2c04f44d3cee0340d06b8a7719d0907b4b8add86165fd9544c81cb3a313bae51c4e486f765c88f31b000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000041919de19252b914c5a24e4e17a15334b55e3516736d77f9e3bbdd9eb2ed3772f95cf64bbebc5700d340df29e192b06c62cb9210bdb2a02f2a241f91b04d4b85e11c000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000041f36a88acf9563c11dd6b7260f1ae97875da0f283426d4c6d18af3e8b494c2d33153db701cf9e2dee49554d8ffeb6e1e6668870ab3d7f52b476d9f9dbeab0557f1b000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000041904b8ed91d65f227284331905b7c27c1c444d07cf117176c800a56403ce3bf717ebf9f9f22bda72e15f8927c2de0b588215ddeb843550b5d1ed64de30170273e1b000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000041bee456d591a1f9e09e445226d6ee80e929ae1f1e6938457d2073461b7389171360277233d20eb10a2a8e53645dd8a1aa95bb4a103d0d66276e4c1e51f36ed1f11b00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000004127ae576319940182ab062e4342bd818e6fe4e7937cb81eb37a748f251fd04943484c58bf06dcc36eff3f12f01f6b29a2a6fb8a05aca2a67f501416b8b30ae18f1b000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000041c33ff523371e70dee70e0dfef77abfd1702f13c2998b95456f01bdcaf4dd4ebc31708efdad83ef022f52c00dbc9452ad67ed41e2f6253536b5035ce3572222891c00000000000000000000000000000000000000000000000000000000000000").expect("bad input");
         let (msg, raw_proof) = proof::proof_tests::decode_input(&input).expect("Input should decode");
-        let msg_hash = H256::from_slice(&ecdsa::to_eth_signed_message_hash(keccak_256(msg.as_slice())));
+        let data_hash = H256(keccak_256(msg.as_slice()));
 
         let operators_hash = H256::from_slice(&hex::decode("eeccf7fbb3e9555e6b6111e1ccf0382b0ffa7c66cde0d69d1bdb1b80b6fc2ee3").expect(""));
         EpochForHash::<Runtime>::insert(operators_hash, 100);
         CurrentEpoch::<Runtime>::set(100);
 
         assert_eq!(
-            AxelarGateway::validate_proof(msg_hash, &raw_proof),
+            AxelarGateway::validate_proof(data_hash, &raw_proof),
             Ok(true)
         );
     });
@@ -218,14 +709,14 @@ fn validate_proof_old_epoch() {
         // This payload has been taken from a live tx in Axelar
         let input = hex::decode("00000000000000000000000000000000000000000000000000000000000000400000000000000000000000000000000000000000000000000000000000001a4000000000000000000000000000000000000000000000000000000000000019e00000000000000000000000000000000000000000000000000000000000000089000000000000000000000000000000000000000000000000000000000000008000000000000000000000000000000000000000000000000000000000000003200000000000000000000000000000000000000000000000000000000000000ac00000000000000000000000000000000000000000000000000000000000000014024bd9868ae3a24be4618c06e99c4b8c8203c3ed5a7624926c7c1b2d5d4f2f830b61d8ae73e1fa78be41dca6e9eddaf58455c1fede28d9b659db46c2852199641f1bd2329cff9e675a7cfb713c3389e1cce070a52815dfe181e7bee84cebdf1632d682f903de5becca1d53ae70e10e89f9bbb08e34a44659c96cec5400c50aad3af28d1cd27a32b62d17b5b4f1f5dda694c038816ac210cd6041e69b307f68653d3102ef7fab39c68cf4877239f7fecf2390635ccd62188837cbaa92096f6a9f4258411491a045f5712f44d69d579a61fb32fc0e9e0a752da922722036807bdf4a471b6425920feaac5ea7e89a8184f31a8e07bfa7e2b3d8d13afe38c6f002075eed8a192323fa2a6426a9a76844c8795235c257122914b59460b7b93307a8085f25615b4a884455bdd7c3e15ab53bb7ce52c2da7de4c3f0271ed0e501d6b46b66bf20bc5dd1291380cd1968228d6f741ce65f11d65566440a14813dd29a1c45712848d49c6804fd0ee931a63d0aaffe723503c20154cf0ccbf40f4ead91ea247563e35f83efd31cd7ad9e8207b49ad3cac90e3ec0a3bfffbac92d54642b2d1e79538b3ca6bdb613981b8fb3300c64b904f61faf69fa8379419f5a256c076b949158484c76b6a6b3b6ca812fd48a75ad6fba67f234f5600e129de90aeb769fe1932a2dc27048ac32152e068c5d25c5ca69751f8a16d44f4426246817f3e54744b145640a7b52b2cd935d98c0358e1133af7d819ff48b65a5de5b461531574203c3a2c6ece32b9ff28b6004c21544f1235bb311dd00aa857dd1420fcfc0360eb1e055d1f67fde0977937822f0f553b812e92e4f5fe266e3c7dbd188e3ebbc8683f5e534eb6a5772039075f58b3c589a37d377783626a0a08ffbd822d19ff672510000000000000000000000000000000000000000000000000000000000000014000000000000000000000000000000000000000000000000000000000000028000000000000000000000000000000000000000000000000000000000000002c000000000000000000000000000000000000000000000000000000000000003000000000000000000000000000000000000000000000000000000000000000340000000000000000000000000000000000000000000000000000000000000038000000000000000000000000000000000000000000000000000000000000003c000000000000000000000000000000000000000000000000000000000000004000000000000000000000000000000000000000000000000000000000000000440000000000000000000000000000000000000000000000000000000000000048000000000000000000000000000000000000000000000000000000000000004c000000000000000000000000000000000000000000000000000000000000005000000000000000000000000000000000000000000000000000000000000000540000000000000000000000000000000000000000000000000000000000000058000000000000000000000000000000000000000000000000000000000000005c000000000000000000000000000000000000000000000000000000000000006000000000000000000000000000000000000000000000000000000000000000640000000000000000000000000000000000000000000000000000000000000068000000000000000000000000000000000000000000000000000000000000006c00000000000000000000000000000000000000000000000000000000000000700000000000000000000000000000000000000000000000000000000000000074000000000000000000000000000000000000000000000000000000000000000096275726e546f6b656e000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000096275726e546f6b656e000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000096275726e546f6b656e000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000096275726e546f6b656e000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000096275726e546f6b656e000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000096275726e546f6b656e000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000096275726e546f6b656e000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000096275726e546f6b656e000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000096275726e546f6b656e000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000096275726e546f6b656e000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000096275726e546f6b656e000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000096275726e546f6b656e000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000096275726e546f6b656e000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000096275726e546f6b656e000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000096275726e546f6b656e000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000096275726e546f6b656e000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000096275726e546f6b656e000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000096275726e546f6b656e000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000096275726e546f6b656e000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000096275726e546f6b656e000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000140000000000000000000000000000000000000000000000000000000000000280000000000000000000000000000000000000000000000000000000000000032000000000000000000000000000000000000000000000000000000000000003c00000000000000000000000000000000000000000000000000000000000000460000000000000000000000000000000000000000000000000000000000000050000000000000000000000000000000000000000000000000000000000000005a0000000000000000000000000000000000000000000000000000000000000064000000000000000000000000000000000000000000000000000000000000006e00000000000000000000000000000000000000000000000000000000000000780000000000000000000000000000000000000000000000000000000000000082000000000000000000000000000000000000000000000000000000000000008c000000000000000000000000000000000000000000000000000000000000009600000000000000000000000000000000000000000000000000000000000000a000000000000000000000000000000000000000000000000000000000000000aa00000000000000000000000000000000000000000000000000000000000000b400000000000000000000000000000000000000000000000000000000000000be00000000000000000000000000000000000000000000000000000000000000c800000000000000000000000000000000000000000000000000000000000000d200000000000000000000000000000000000000000000000000000000000000dc00000000000000000000000000000000000000000000000000000000000000e60000000000000000000000000000000000000000000000000000000000000008000000000000000000000000000000000000000000000000000000000000000400a6d1770549b534347933ca2f6f45745e6d5b479e7925fe84e63538bdfa8bbb2000000000000000000000000000000000000000000000000000000000000000341584c00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000080000000000000000000000000000000000000000000000000000000000000004064f0cf9822dc7d35f96e1b5a8d710b89c56c781993bb8c0b121cec9d0b0e433a000000000000000000000000000000000000000000000000000000000000000341584c000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000800000000000000000000000000000000000000000000000000000000000000040f2b3576aee3d5ef0c8fa5bab2d67744de2b7f5f12e044ae4c5815f59c1c32637000000000000000000000000000000000000000000000000000000000000000761786c555344430000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000800000000000000000000000000000000000000000000000000000000000000040b4adc7f4169073d4b9acb8e333af363a5c1908198b31e7118fb712566583b784000000000000000000000000000000000000000000000000000000000000000341584c000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000800000000000000000000000000000000000000000000000000000000000000040ca2827d2512e5fc79794d5a9d1c796ad96286c1910187ad96e7ecf1ec5229d11000000000000000000000000000000000000000000000000000000000000000341584c0000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000008000000000000000000000000000000000000000000000000000000000000000407df01a951c4c9ca80588e361a4e16d8da8d86c1027a83ac362e88bdbc9d39d0c000000000000000000000000000000000000000000000000000000000000000341584c00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000080000000000000000000000000000000000000000000000000000000000000004031b2e37c24f57b9f6c3e6293a2a9533a1aea2ab985a2736fdf408fe96e6a4190000000000000000000000000000000000000000000000000000000000000000341584c000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000800000000000000000000000000000000000000000000000000000000000000040fd9da3a2d08ea314fdce35b3679584ae7d0cf7fecc8598d5e5d6d32cdd17ff35000000000000000000000000000000000000000000000000000000000000000761786c555344430000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000800000000000000000000000000000000000000000000000000000000000000040a5b9348079667e180c95852ba7641fe3603b2446d0904dbcb11e08273ff6674d000000000000000000000000000000000000000000000000000000000000000761786c55534443000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000080000000000000000000000000000000000000000000000000000000000000004053d466178493c68d7df38e30e291dd9f7837d25ddef9eb126b23610d75eb8b3e000000000000000000000000000000000000000000000000000000000000000341584c000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000800000000000000000000000000000000000000000000000000000000000000040d4f3de95c9dc8f66795edc5219609bb874371e6d7fe688fbe15a92bc0ee0a889000000000000000000000000000000000000000000000000000000000000000341584c00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000080000000000000000000000000000000000000000000000000000000000000004066b358b85bb956f4d44b79ddd2092e7e14b76ba91f4454b7665a9a6cc3504810000000000000000000000000000000000000000000000000000000000000000761786c5553444300000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000008000000000000000000000000000000000000000000000000000000000000000408c5ad2ffaa8ac0a1c403f589cb8acc726978dc85ef04431316676657611325a3000000000000000000000000000000000000000000000000000000000000000761786c555344430000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000800000000000000000000000000000000000000000000000000000000000000040dbead87253bba7961d78a8dc70d6f93bad72a5cc3808710aeb2e0b7a1f48136d000000000000000000000000000000000000000000000000000000000000000341584c000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000800000000000000000000000000000000000000000000000000000000000000040175a1d7d0687d58eba549b920c58f72acd85b3f32974bf715444d05a3fe5a7a7000000000000000000000000000000000000000000000000000000000000000341584c000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000800000000000000000000000000000000000000000000000000000000000000040b7fbff8df422b68c0e4260f635da3aa5d25e4f28c7bc55cdcd7d2ee05696c48f000000000000000000000000000000000000000000000000000000000000000341584c000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000800000000000000000000000000000000000000000000000000000000000000040fdc954879a4f2ec0f9d1f848a1e9bc16e554a89b3d07e09c7f1a5033ea9955c9000000000000000000000000000000000000000000000000000000000000000341584c0000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000008000000000000000000000000000000000000000000000000000000000000000400891068bb957a7ea53b723a07a56c6c58af9629c5074705e51dffafc7931100d000000000000000000000000000000000000000000000000000000000000000341584c00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000080000000000000000000000000000000000000000000000000000000000000004033fcc5e8d848789577f23f4e23a9629f9d6a2aa0ad1d1b5e4a61fc860b0933fb000000000000000000000000000000000000000000000000000000000000000341584c000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000800000000000000000000000000000000000000000000000000000000000000040ddd2c66bd3fa3ee3ae6786a502ae3db87eb5d20c53534c0ac6c2c480c7f79581000000000000000000000000000000000000000000000000000000000000000341584c00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000001fe00000000000000000000000000000000000000000000000000000000000000080000000000000000000000000000000000000000000000000000000000000080000000000000000000000000000000000000000000000000000000000000178400000000000000000000000000000000000000000000000000000000000000f80000000000000000000000000000000000000000000000000000000000000003b00000000000000000000000006cef1a89423a4bd1ab859588e7c95e33e5dd5030000000000000000000000002416b62fae3cc9aaaa3d83560faaa099d65b9c6200000000000000000000000025b9437e280fd897e7b92fb969695a9ecfc679a600000000000000000000000025d57ab822c2d16ba0c26b2605204008bb2d5b760000000000000000000000002ab9393d3c4d39fe246f36740e349343aa45926e0000000000000000000000002e0be57ed7d7d0015e15495f5b2cffd1415ca9f40000000000000000000000002f885750185e9b0b50299f67619ccc5029b4fe780000000000000000000000003025973fbc55b3db307b22f5cf950f6e721e1e590000000000000000000000004cfa354d1391366409d8e15cfdaacb548166045300000000000000000000000052ab14b8f00bde612fd3a0efedb874a392e912a100000000000000000000000056a82852cf6fbe733d3bebd203345c8a3af4625f0000000000000000000000005c267d29094484dc6148a314f1da01476e81e3790000000000000000000000005d1bb3c6252377b0fee9a2f5784211e3bf6be9eb0000000000000000000000005f0d2b668452b194da9c4bb44039b46838a7fb4d0000000000000000000000005f709ffda63add6d93f9d5b0d6c8a93687f2060500000000000000000000000063897e57716a3136b367e6b04bdd148bb50743280000000000000000000000006c819a78f2f50957257f3f939533b355b9f53bc10000000000000000000000006c8e5111f0d7fa75f2bbff2c94e3743f4f509c890000000000000000000000006dc62faf15b9c63a30d60e8c51221628cd73441d0000000000000000000000006e9814e6d369d893d178ba086b79cab14456422b000000000000000000000000713643c8fdaf910c92caa2879133d3cf80e47cb7000000000000000000000000766075716f592113e3d89195670c3662b05b71a40000000000000000000000007a05ca9c569695b133c2c3f4363a2f05b87ae40b0000000000000000000000007cd4d2a644f9e480ea5180d8596e98f3bdf4e13900000000000000000000000083166fe68653e9431b36848d59bfaf17aceaed3c0000000000000000000000008b44ba5eb8e346dfe00a413c9d915b07a12b5492000000000000000000000000938e2dd4c5b0082c33d240c551ef7cc4b4bd89cc00000000000000000000000094e610ed1204199b69432a86e5ce2bc09b7b5c8d000000000000000000000000960cb2d7dd310d8f1011fa5e43efbac609e12f6a00000000000000000000000096c71ad79e004d547a6f4a7ca7ceb00e098a75db00000000000000000000000097dc92c1047638ce6cfb1e48680217cbf61605070000000000000000000000009c09e7e02e62d907565a6f8bd9a3f7f9519bb9a60000000000000000000000009ea41855d3caa93e41a7d09dff2161acad20a37f0000000000000000000000009eab40758dd776e32a3beb52c3eadf2d9dc8f59a000000000000000000000000a2f7ec24bf2e5b283d5ede4a70f2010353f43992000000000000000000000000a349424aeb5ebabd27e5ef0e948d1cf4e5bb5c93000000000000000000000000a3f5423cb27974aaa0c3f6626a14d7632ea47e2f000000000000000000000000a8be6d3f1ffa56a41a5e8328ab6684a0a8f3f797000000000000000000000000bb8302cc61bbca73cb5f1e33700b184dd27df39f000000000000000000000000bc550c0a985b8ba108425b81282d240d5efe025b000000000000000000000000be21095401b21b6f96a2f62368f38e374e3cdd4d000000000000000000000000bf1c1bf1135a9a8254af6d54df62acffe403df7d000000000000000000000000c00121d03080a59523397e495453a1bae10ac2ee000000000000000000000000caaacc0c574ed10f513cf4abee0878b80c5b2357000000000000000000000000ce283f39132fd4975d8fc3253f9979c9b614c458000000000000000000000000ce860111db05bd9d62099de889d53e998bbf212d000000000000000000000000d2031ae34371610669a5552e77eb83b2f72401d9000000000000000000000000d5f8c62eba364c3268462648dc1780c65a62f798000000000000000000000000e3a3b4dc387a86ae7b414c1931e17b2cda491b65000000000000000000000000e40ca7c2ddf7d30bdb504bf44e2f7bea087ccb92000000000000000000000000e4b2e50e5a780a3b28029f860101e0d0c64dc448000000000000000000000000e589143e35797e8a6f94d8be3c4dda6e7bc08cba000000000000000000000000e630337486f75373496acb6c25b3990c686637fd000000000000000000000000e7d3711884b278ea71c04ba478b669b0bc1f4501000000000000000000000000eb44d60895554f5bf4bff9734caa648ebf2598ce000000000000000000000000ef40aaa580dd27270cda1a2f58ab133b14d1aa6a000000000000000000000000f21d62e811aa647f8f8bec34acaf48a25a1e8b9a000000000000000000000000f8c8d60dca2de390fe4eda46cf801a46ee6cc529000000000000000000000000fc6f79efe7e3dbb8b63f74c8bf6ffc0225e11bca000000000000000000000000000000000000000000000000000000000000003b000000000000000000000000000000000000000000000000000000000000108d00000000000000000000000000000000000000000000000000000000000008fe0000000000000000000000000000000000000000000000000000000000000c2f0000000000000000000000000000000000000000000000000000000000000d9800000000000000000000000000000000000000000000000000000000000006de0000000000000000000000000000000000000000000000000000000000000e9600000000000000000000000000000000000000000000000000000000000008f10000000000000000000000000000000000000000000000000000000000000c030000000000000000000000000000000000000000000000000000000000000c8e00000000000000000000000000000000000000000000000000000000000002230000000000000000000000000000000000000000000000000000000000000891000000000000000000000000000000000000000000000000000000000000078300000000000000000000000000000000000000000000000000000000000002360000000000000000000000000000000000000000000000000000000000000cd90000000000000000000000000000000000000000000000000000000000000a140000000000000000000000000000000000000000000000000000000000000b0c00000000000000000000000000000000000000000000000000000000000002430000000000000000000000000000000000000000000000000000000000000224000000000000000000000000000000000000000000000000000000000000082600000000000000000000000000000000000000000000000000000000000001d90000000000000000000000000000000000000000000000000000000000000d08000000000000000000000000000000000000000000000000000000000000177500000000000000000000000000000000000000000000000000000000000019ff0000000000000000000000000000000000000000000000000000000000000a4f0000000000000000000000000000000000000000000000000000000000000978000000000000000000000000000000000000000000000000000000000000020f0000000000000000000000000000000000000000000000000000000000000baa0000000000000000000000000000000000000000000000000000000000000bef00000000000000000000000000000000000000000000000000000000000008ad000000000000000000000000000000000000000000000000000000000000058900000000000000000000000000000000000000000000000000000000000009b80000000000000000000000000000000000000000000000000000000000000c650000000000000000000000000000000000000000000000000000000000000dac0000000000000000000000000000000000000000000000000000000000000bc4000000000000000000000000000000000000000000000000000000000000118500000000000000000000000000000000000000000000000000000000000007b1000000000000000000000000000000000000000000000000000000000000103f000000000000000000000000000000000000000000000000000000000000022a0000000000000000000000000000000000000000000000000000000000000227000000000000000000000000000000000000000000000000000000000000098c00000000000000000000000000000000000000000000000000000000000005860000000000000000000000000000000000000000000000000000000000000bf90000000000000000000000000000000000000000000000000000000000000fea00000000000000000000000000000000000000000000000000000000000004c80000000000000000000000000000000000000000000000000000000000000f990000000000000000000000000000000000000000000000000000000000000c8500000000000000000000000000000000000000000000000000000000000006c00000000000000000000000000000000000000000000000000000000000000c2c00000000000000000000000000000000000000000000000000000000000006f70000000000000000000000000000000000000000000000000000000000000a220000000000000000000000000000000000000000000000000000000000000f3a00000000000000000000000000000000000000000000000000000000000008710000000000000000000000000000000000000000000000000000000000000cbc000000000000000000000000000000000000000000000000000000000000098900000000000000000000000000000000000000000000000000000000000013fa0000000000000000000000000000000000000000000000000000000000000f3f0000000000000000000000000000000000000000000000000000000000000d8300000000000000000000000000000000000000000000000000000000000004cc0000000000000000000000000000000000000000000000000000000000000c6c000000000000000000000000000000000000000000000000000000000000001a000000000000000000000000000000000000000000000000000000000000034000000000000000000000000000000000000000000000000000000000000003c0000000000000000000000000000000000000000000000000000000000000044000000000000000000000000000000000000000000000000000000000000004c0000000000000000000000000000000000000000000000000000000000000054000000000000000000000000000000000000000000000000000000000000005c0000000000000000000000000000000000000000000000000000000000000064000000000000000000000000000000000000000000000000000000000000006c0000000000000000000000000000000000000000000000000000000000000074000000000000000000000000000000000000000000000000000000000000007c0000000000000000000000000000000000000000000000000000000000000084000000000000000000000000000000000000000000000000000000000000008c0000000000000000000000000000000000000000000000000000000000000094000000000000000000000000000000000000000000000000000000000000009c00000000000000000000000000000000000000000000000000000000000000a400000000000000000000000000000000000000000000000000000000000000ac00000000000000000000000000000000000000000000000000000000000000b400000000000000000000000000000000000000000000000000000000000000bc00000000000000000000000000000000000000000000000000000000000000c400000000000000000000000000000000000000000000000000000000000000cc00000000000000000000000000000000000000000000000000000000000000d400000000000000000000000000000000000000000000000000000000000000dc00000000000000000000000000000000000000000000000000000000000000e400000000000000000000000000000000000000000000000000000000000000ec00000000000000000000000000000000000000000000000000000000000000f400000000000000000000000000000000000000000000000000000000000000fc0000000000000000000000000000000000000000000000000000000000000004178b04a351c892e310f836ce84111f226e2b59612dfb12b7e4620546fc07e830c28febdc4e7e1e979f65c25fb559a583e07c263b1a91c44ab67866d65ef9612631b000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000041d96eb274116cf66570235cf174c45ac6ac5fc3484fa705f855b59aa7966b68141d7bc3faeaefec754546ca59d0d13e17a1cdba29068fd01c92df9f0b71b686411c000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000041995574eae7662e388ec38823b1b177b21af0b834775131a4877759b91c3f9b5900f62999a22042b3d4ccf35caf0c67326d6e5673aabeb9e876927430fbb0c9251c00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000004163e8fb22ec49dda417d33578cb254d4731dcd5bb3951fea20f9d2b4daee7f82e27f1edd0b4ae6df97f03089bb988852d2aefe760cfd13a368ee876c41a65cbb31b0000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000416d8576ece58978dfb244bc86cf03676b937a7e249dae8ac402781f92211a756c46883fd3e981cff438fa1c0ca367bb65070d085f2fdd620e2567b9d8d2c9dd831b000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000041bc2cf7de5a9d47955f16e861e86e63ccfbb80c94b261ecb8c7b49dce6b6d5add713c6873cddd9b5420d02e12cad6db949d351e23014508ba9d6aba63ae9391311c00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000004158ce14379b885a73234072b602109a58f00b094bc30ce224d3316322a8cce7167bb821cd15fa5b000c03956e203ccbf59a47890f119af5037ab98eb1f40aa87a1b00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000004150ab711dc34a02af44cc5497ae054574e2efe643ac99323b1ef2b8294566c0e0449331e64fedb6f80b472ad31bf84150f1ded1a1a24e55c3bed8f25003536ea61c000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000041af76d69ba2fcf9007e7301d71c1efe55a2175da759b01664c3a188203e8fb8684a4db0987da4c933676da809687369e9dffa067f48137c21f99cf012cc820e791b0000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000418dbeface745d74cabbdf5863e397bc06d40f3f11ac18c501d8204a87ed2d182f16d3bc5bf0c3726cbb1940165c6cda1735105b9ec437ac888e7aa9036182eadc1b0000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000415b748001a6d8ca7eb7e90a109cef53bceb26444885f343d8299d51d391bf784c7a0cb2659c95b373fd42db2a7af890431cd615710af2d81cbc7df484f21e2cd11c000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000041249210b837fa86b9f391b4ef14e212c9e039035653452815a5f8acf5c48228a657f47d8de2cbb497b093b101b1261d52131913ebc633f15ced26f62ad66a02341b000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000041b71d9b7d991deb9c7bebd82cb595d0d056abe9288a3759278a9bdf95fb3adf0c34a946b9ac4ec07c0ba1f0af04904d2621a7369cbc9af1b63aac6c3f81b4b0a41b00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000004129e52b2f5f2779d46e17e1a842cf4433984c5c15c65dbc734f4289ee08387033557bf3127149ed9333aca3606585b115b60f315b22507c7d4ee6d6f43241be8b1b0000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000417f2f6840dac370c2afa6ab6a7e268688b7cd993a722557f47eced29bda96b47b7fc5328bc54e960d0680ab63a9ec38c699e12e45778809ccb1b206ee1446c5f41c000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000041577e94d9c6ce954f74cda9f1916bcabe6f79f5bdd13f8343d1a840df3e5cef663eef034e5807f8d8ada1ae657345fcf6cab721a8b457b85680261d6ac15ff0641b000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000041c71b153ec6b33ebbc4d85058cd7a5af7560326c198e874a4d9ebe3424444a5ec1e0adc7f17d309753c9e8c490b97be70b08a6a09bd647f4cf872e04bcf1198071c00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000004102641dcf544f277e60f29f675f7332393c77e4ca0b6910713c9f63c46cf2cff20d5c5a621b6d96a3c809ef32af91552321836360255eda813d622026f931eefb1c00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000004198f5b24330bc6c544f306de92a06c9f0a736c35ba4980d24ddef0889395288c81b21730e1c7c4d8b8ed10f167b46a1a1c0f8b9b8e032c0c29e940e6a3fd743011c000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000041e64ec926886ad800bc3a515b1ec587f918d45132e4117371f2c04f44d3cee0340d06b8a7719d0907b4b8add86165fd9544c81cb3a313bae51c4e486f765c88f31b000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000041919de19252b914c5a24e4e17a15334b55e3516736d77f9e3bbdd9eb2ed3772f95cf64bbebc5700d340df29e192b06c62cb9210bdb2a02f2a241f91b04d4b85e11c000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000041f36a88acf9563c11dd6b7260f1ae97875da0f283426d4c6d18af3e8b494c2d33153db701cf9e2dee49554d8ffeb6e1e6668870ab3d7f52b476d9f9dbeab0557f1b000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000041904b8ed91d65f227284331905b7c27c1c444d07cf117176c800a56403ce3bf717ebf9f9f22bda72e15f8927c2de0b588215ddeb843550b5d1ed64de30170273e1b000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000041bee456d591a1f9e09e445226d6ee80e929ae1f1e6938457d2073461b7389171360277233d20eb10a2a8e53645dd8a1aa95bb4a103d0d66276e4c1e51f36ed1f11b00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000004127ae576319940182ab062e4342bd818e6fe4e7937cb81eb37a748f251fd04943484c58bf06dcc36eff3f12f01f6b29a2a6fb8a05aca2a67f501416b8b30ae18f1b000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000041c33ff523371e70dee70e0dfef77abfd1702f13c2998b95456f01bdcaf4dd4ebc31708efdad83ef022f52c00dbc9452ad67ed41e2f6253536b5035ce3572222891c00000000000000000000000000000000000000000000000000000000000000").expect("bad input");
         let (msg, raw_proof) = proof::proof_tests::decode_input(&input).expect("Input should decode");
-        let msg_hash = H256::from_slice(&ecdsa::to_eth_signed_message_hash(keccak_256(msg.as_slice())));
+        let data_hash = H256(keccak_256(msg.as_slice()));
 
         let operators_hash = H256::from_slice(&hex::decode("eeccf7fbb3e9555e6b6111e1ccf0382b0ffa7c66cde0d69d1bdb1b80b6fc2ee3").expect(""));
         EpochForHash::<Runtime>::insert(operators_hash, 100);
         CurrentEpoch::<Runtime>::set(140);
 
         assert_noop!(
-            AxelarGateway::validate_proof(msg_hash, &raw_proof),
+            AxelarGateway::validate_proof(data_hash, &raw_proof),
             Error::<Runtime>::InvalidOperators
         );
     });
@@ -238,14 +729,14 @@ fn validate_proof_not_current_epoch() {
         // This payload has been taken from a live tx in Axelar
         let input = hex::decode("00000000000000000000000000000000000000000000000000000000000000400000000000000000000000000000000000000000000000000000000000001a4000000000000000000000000000000000000000000000000000000000000019e00000000000000000000000000000000000000000000000000000000000000089000000000000000000000000000000000000000000000000000000000000008000000000000000000000000000000000000000000000000000000000000003200000000000000000000000000000000000000000000000000000000000000ac00000000000000000000000000000000000000000000000000000000000000014024bd9868ae3a24be4618c06e99c4b8c8203c3ed5a7624926c7c1b2d5d4f2f830b61d8ae73e1fa78be41dca6e9eddaf58455c1fede28d9b659db46c2852199641f1bd2329cff9e675a7cfb713c3389e1cce070a52815dfe181e7bee84cebdf1632d682f903de5becca1d53ae70e10e89f9bbb08e34a44659c96cec5400c50aad3af28d1cd27a32b62d17b5b4f1f5dda694c038816ac210cd6041e69b307f68653d3102ef7fab39c68cf4877239f7fecf2390635ccd62188837cbaa92096f6a9f4258411491a045f5712f44d69d579a61fb32fc0e9e0a752da922722036807bdf4a471b6425920feaac5ea7e89a8184f31a8e07bfa7e2b3d8d13afe38c6f002075eed8a192323fa2a6426a9a76844c8795235c257122914b59460b7b93307a8085f25615b4a884455bdd7c3e15ab53bb7ce52c2da7de4c3f0271ed0e501d6b46b66bf20bc5dd1291380cd1968228d6f741ce65f11d65566440a14813dd29a1c45712848d49c6804fd0ee931a63d0aaffe723503c20154cf0ccbf40f4ead91ea247563e35f83efd31cd7ad9e8207b49ad3cac90e3ec0a3bfffbac92d54642b2d1e79538b3ca6bdb613981b8fb3300c64b904f61faf69fa8379419f5a256c076b949158484c76b6a6b3b6ca812fd48a75ad6fba67f234f5600e129de90aeb769fe1932a2dc27048ac32152e068c5d25c5ca69751f8a16d44f4426246817f3e54744b145640a7b52b2cd935d98c0358e1133af7d819ff48b65a5de5b461531574203c3a2c6ece32b9ff28b6004c21544f1235bb311dd00aa857dd1420fcfc0360eb1e055d1f67fde0977937822f0f553b812e92e4f5fe266e3c7dbd188e3ebbc8683f5e534eb6a5772039075f58b3c589a37d377783626a0a08ffbd822d19ff672510000000000000000000000000000000000000000000000000000000000000014000000000000000000000000000000000000000000000000000000000000028000000000000000000000000000000000000000000000000000000000000002c000000000000000000000000000000000000000000000000000000000000003000000000000000000000000000000000000000000000000000000000000000340000000000000000000000000000000000000000000000000000000000000038000000000000000000000000000000000000000000000000000000000000003c000000000000000000000000000000000000000000000000000000000000004000000000000000000000000000000000000000000000000000000000000000440000000000000000000000000000000000000000000000000000000000000048000000000000000000000000000000000000000000000000000000000000004c000000000000000000000000000000000000000000000000000000000000005000000000000000000000000000000000000000000000000000000000000000540000000000000000000000000000000000000000000000000000000000000058000000000000000000000000000000000000000000000000000000000000005c000000000000000000000000000000000000000000000000000000000000006000000000000000000000000000000000000000000000000000000000000000640000000000000000000000000000000000000000000000000000000000000068000000000000000000000000000000000000000000000000000000000000006c00000000000000000000000000000000000000000000000000000000000000700000000000000000000000000000000000000000000000000000000000000074000000000000000000000000000000000000000000000000000000000000000096275726e546f6b656e000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000096275726e546f6b656e000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000096275726e546f6b656e000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000096275726e546f6b656e000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000096275726e546f6b656e000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000096275726e546f6b656e000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000096275726e546f6b656e000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000096275726e546f6b656e000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000096275726e546f6b656e000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000096275726e546f6b656e000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000096275726e546f6b656e000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000096275726e546f6b656e000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000096275726e546f6b656e000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000096275726e546f6b656e000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000096275726e546f6b656e000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000096275726e546f6b656e000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000096275726e546f6b656e000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000096275726e546f6b656e000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000096275726e546f6b656e000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000096275726e546f6b656e000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000140000000000000000000000000000000000000000000000000000000000000280000000000000000000000000000000000000000000000000000000000000032000000000000000000000000000000000000000000000000000000000000003c00000000000000000000000000000000000000000000000000000000000000460000000000000000000000000000000000000000000000000000000000000050000000000000000000000000000000000000000000000000000000000000005a0000000000000000000000000000000000000000000000000000000000000064000000000000000000000000000000000000000000000000000000000000006e00000000000000000000000000000000000000000000000000000000000000780000000000000000000000000000000000000000000000000000000000000082000000000000000000000000000000000000000000000000000000000000008c000000000000000000000000000000000000000000000000000000000000009600000000000000000000000000000000000000000000000000000000000000a000000000000000000000000000000000000000000000000000000000000000aa00000000000000000000000000000000000000000000000000000000000000b400000000000000000000000000000000000000000000000000000000000000be00000000000000000000000000000000000000000000000000000000000000c800000000000000000000000000000000000000000000000000000000000000d200000000000000000000000000000000000000000000000000000000000000dc00000000000000000000000000000000000000000000000000000000000000e60000000000000000000000000000000000000000000000000000000000000008000000000000000000000000000000000000000000000000000000000000000400a6d1770549b534347933ca2f6f45745e6d5b479e7925fe84e63538bdfa8bbb2000000000000000000000000000000000000000000000000000000000000000341584c00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000080000000000000000000000000000000000000000000000000000000000000004064f0cf9822dc7d35f96e1b5a8d710b89c56c781993bb8c0b121cec9d0b0e433a000000000000000000000000000000000000000000000000000000000000000341584c000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000800000000000000000000000000000000000000000000000000000000000000040f2b3576aee3d5ef0c8fa5bab2d67744de2b7f5f12e044ae4c5815f59c1c32637000000000000000000000000000000000000000000000000000000000000000761786c555344430000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000800000000000000000000000000000000000000000000000000000000000000040b4adc7f4169073d4b9acb8e333af363a5c1908198b31e7118fb712566583b784000000000000000000000000000000000000000000000000000000000000000341584c000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000800000000000000000000000000000000000000000000000000000000000000040ca2827d2512e5fc79794d5a9d1c796ad96286c1910187ad96e7ecf1ec5229d11000000000000000000000000000000000000000000000000000000000000000341584c0000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000008000000000000000000000000000000000000000000000000000000000000000407df01a951c4c9ca80588e361a4e16d8da8d86c1027a83ac362e88bdbc9d39d0c000000000000000000000000000000000000000000000000000000000000000341584c00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000080000000000000000000000000000000000000000000000000000000000000004031b2e37c24f57b9f6c3e6293a2a9533a1aea2ab985a2736fdf408fe96e6a4190000000000000000000000000000000000000000000000000000000000000000341584c000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000800000000000000000000000000000000000000000000000000000000000000040fd9da3a2d08ea314fdce35b3679584ae7d0cf7fecc8598d5e5d6d32cdd17ff35000000000000000000000000000000000000000000000000000000000000000761786c555344430000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000800000000000000000000000000000000000000000000000000000000000000040a5b9348079667e180c95852ba7641fe3603b2446d0904dbcb11e08273ff6674d000000000000000000000000000000000000000000000000000000000000000761786c55534443000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000080000000000000000000000000000000000000000000000000000000000000004053d466178493c68d7df38e30e291dd9f7837d25ddef9eb126b23610d75eb8b3e000000000000000000000000000000000000000000000000000000000000000341584c000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000800000000000000000000000000000000000000000000000000000000000000040d4f3de95c9dc8f66795edc5219609bb874371e6d7fe688fbe15a92bc0ee0a889000000000000000000000000000000000000000000000000000000000000000341584c00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000080000000000000000000000000000000000000000000000000000000000000004066b358b85bb956f4d44b79ddd2092e7e14b76ba91f4454b7665a9a6cc3504810000000000000000000000000000000000000000000000000000000000000000761786c5553444300000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000008000000000000000000000000000000000000000000000000000000000000000408c5ad2ffaa8ac0a1c403f589cb8acc726978dc85ef04431316676657611325a3000000000000000000000000000000000000000000000000000000000000000761786c555344430000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000800000000000000000000000000000000000000000000000000000000000000040dbead87253bba7961d78a8dc70d6f93bad72a5cc3808710aeb2e0b7a1f48136d000000000000000000000000000000000000000000000000000000000000000341584c000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000800000000000000000000000000000000000000000000000000000000000000040175a1d7d0687d58eba549b920c58f72acd85b3f32974bf715444d05a3fe5a7a7000000000000000000000000000000000000000000000000000000000000000341584c000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000800000000000000000000000000000000000000000000000000000000000000040b7fbff8df422b68c0e4260f635da3aa5d25e4f28c7bc55cdcd7d2ee05696c48f000000000000000000000000000000000000000000000000000000000000000341584c000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000800000000000000000000000000000000000000000000000000000000000000040fdc954879a4f2ec0f9d1f848a1e9bc16e554a89b3d07e09c7f1a5033ea9955c9000000000000000000000000000000000000000000000000000000000000000341584c0000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000008000000000000000000000000000000000000000000000000000000000000000400891068bb957a7ea53b723a07a56c6c58af9629c5074705e51dffafc7931100d000000000000000000000000000000000000000000000000000000000000000341584c00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000080000000000000000000000000000000000000000000000000000000000000004033fcc5e8d848789577f23f4e23a9629f9d6a2aa0ad1d1b5e4a61fc860b0933fb000000000000000000000000000000000000000000000000000000000000000341584c000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000800000000000000000000000000000000000000000000000000000000000000040ddd2c66bd3fa3ee3ae6786a502ae3db87eb5d20c53534c0ac6c2c480c7f79581000000000000000000000000000000000000000000000000000000000000000341584c00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000001fe00000000000000000000000000000000000000000000000000000000000000080000000000000000000000000000000000000000000000000000000000000080000000000000000000000000000000000000000000000000000000000000178400000000000000000000000000000000000000000000000000000000000000f80000000000000000000000000000000000000000000000000000000000000003b00000000000000000000000006cef1a89423a4bd1ab859588e7c95e33e5dd5030000000000000000000000002416b62fae3cc9aaaa3d83560faaa099d65b9c6200000000000000000000000025b9437e280fd897e7b92fb969695a9ecfc679a600000000000000000000000025d57ab822c2d16ba0c26b2605204008bb2d5b760000000000000000000000002ab9393d3c4d39fe246f36740e349343aa45926e0000000000000000000000002e0be57ed7d7d0015e15495f5b2cffd1415ca9f40000000000000000000000002f885750185e9b0b50299f67619ccc5029b4fe780000000000000000000000003025973fbc55b3db307b22f5cf950f6e721e1e590000000000000000000000004cfa354d1391366409d8e15cfdaacb548166045300000000000000000000000052ab14b8f00bde612fd3a0efedb874a392e912a100000000000000000000000056a82852cf6fbe733d3bebd203345c8a3af4625f0000000000000000000000005c267d29094484dc6148a314f1da01476e81e3790000000000000000000000005d1bb3c6252377b0fee9a2f5784211e3bf6be9eb0000000000000000000000005f0d2b668452b194da9c4bb44039b46838a7fb4d0000000000000000000000005f709ffda63add6d93f9d5b0d6c8a93687f2060500000000000000000000000063897e57716a3136b367e6b04bdd148bb50743280000000000000000000000006c819a78f2f50957257f3f939533b355b9f53bc10000000000000000000000006c8e5111f0d7fa75f2bbff2c94e3743f4f509c890000000000000000000000006dc62faf15b9c63a30d60e8c51221628cd73441d0000000000000000000000006e9814e6d369d893d178ba086b79cab14456422b000000000000000000000000713643c8fdaf910c92caa2879133d3cf80e47cb7000000000000000000000000766075716f592113e3d89195670c3662b05b71a40000000000000000000000007a05ca9c569695b133c2c3f4363a2f05b87ae40b0000000000000000000000007cd4d2a644f9e480ea5180d8596e98f3bdf4e13900000000000000000000000083166fe68653e9431b36848d59bfaf17aceaed3c0000000000000000000000008b44ba5eb8e346dfe00a413c9d915b07a12b5492000000000000000000000000938e2dd4c5b0082c33d240c551ef7cc4b4bd89cc00000000000000000000000094e610ed1204199b69432a86e5ce2bc09b7b5c8d000000000000000000000000960cb2d7dd310d8f1011fa5e43efbac609e12f6a00000000000000000000000096c71ad79e004d547a6f4a7ca7ceb00e098a75db00000000000000000000000097dc92c1047638ce6cfb1e48680217cbf61605070000000000000000000000009c09e7e02e62d907565a6f8bd9a3f7f9519bb9a60000000000000000000000009ea41855d3caa93e41a7d09dff2161acad20a37f0000000000000000000000009eab40758dd776e32a3beb52c3eadf2d9dc8f59a000000000000000000000000a2f7ec24bf2e5b283d5ede4a70f2010353f43992000000000000000000000000a349424aeb5ebabd27e5ef0e948d1cf4e5bb5c93000000000000000000000000a3f5423cb27974aaa0c3f6626a14d7632ea47e2f000000000000000000000000a8be6d3f1ffa56a41a5e8328ab6684a0a8f3f797000000000000000000000000bb8302cc61bbca73cb5f1e33700b184dd27df39f000000000000000000000000bc550c0a985b8ba108425b81282d240d5efe025b000000000000000000000000be21095401b21b6f96a2f62368f38e374e3cdd4d000000000000000000000000bf1c1bf1135a9a8254af6d54df62acffe403df7d000000000000000000000000c00121d03080a59523397e495453a1bae10ac2ee000000000000000000000000caaacc0c574ed10f513cf4abee0878b80c5b2357000000000000000000000000ce283f39132fd4975d8fc3253f9979c9b614c458000000000000000000000000ce860111db05bd9d62099de889d53e998bbf212d000000000000000000000000d2031ae34371610669a5552e77eb83b2f72401d9000000000000000000000000d5f8c62eba364c3268462648dc1780c65a62f798000000000000000000000000e3a3b4dc387a86ae7b414c1931e17b2cda491b65000000000000000000000000e40ca7c2ddf7d30bdb504bf44e2f7bea087ccb92000000000000000000000000e4b2e50e5a780a3b28029f860101e0d0c64dc448000000000000000000000000e589143e35797e8a6f94d8be3c4dda6e7bc08cba000000000000000000000000e630337486f75373496acb6c25b3990c686637fd000000000000000000000000e7d3711884b278ea71c04ba478b669b0bc1f4501000000000000000000000000eb44d60895554f5bf4bff9734caa648ebf2598ce000000000000000000000000ef40aaa580dd27270cda1a2f58ab133b14d1aa6a000000000000000000000000f21d62e811aa647f8f8bec34acaf48a25a1e8b9a000000000000000000000000f8c8d60dca2de390fe4eda46cf801a46ee6cc529000000000000000000000000fc6f79efe7e3dbb8b63f74c8bf6ffc0225e11bca000000000000000000000000000000000000000000000000000000000000003b000000000000000000000000000000000000000000000000000000000000108d00000000000000000000000000000000000000000000000000000000000008fe0000000000000000000000000000000000000000000000000000000000000c2f0000000000000000000000000000000000000000000000000000000000000d9800000000000000000000000000000000000000000000000000000000000006de0000000000000000000000000000000000000000000000000000000000000e9600000000000000000000000000000000000000000000000000000000000008f10000000000000000000000000000000000000000000000000000000000000c030000000000000000000000000000000000000000000000000000000000000c8e00000000000000000000000000000000000000000000000000000000000002230000000000000000000000000000000000000000000000000000000000000891000000000000000000000000000000000000000000000000000000000000078300000000000000000000000000000000000000000000000000000000000002360000000000000000000000000000000000000000000000000000000000000cd90000000000000000000000000000000000000000000000000000000000000a140000000000000000000000000000000000000000000000000000000000000b0c00000000000000000000000000000000000000000000000000000000000002430000000000000000000000000000000000000000000000000000000000000224000000000000000000000000000000000000000000000000000000000000082600000000000000000000000000000000000000000000000000000000000001d90000000000000000000000000000000000000000000000000000000000000d08000000000000000000000000000000000000000000000000000000000000177500000000000000000000000000000000000000000000000000000000000019ff0000000000000000000000000000000000000000000000000000000000000a4f0000000000000000000000000000000000000000000000000000000000000978000000000000000000000000000000000000000000000000000000000000020f0000000000000000000000000000000000000000000000000000000000000baa0000000000000000000000000000000000000000000000000000000000000bef00000000000000000000000000000000000000000000000000000000000008ad000000000000000000000000000000000000000000000000000000000000058900000000000000000000000000000000000000000000000000000000000009b80000000000000000000000000000000000000000000000000000000000000c650000000000000000000000000000000000000000000000000000000000000dac0000000000000000000000000000000000000000000000000000000000000bc4000000000000000000000000000000000000000000000000000000000000118500000000000000000000000000000000000000000000000000000000000007b1000000000000000000000000000000000000000000000000000000000000103f000000000000000000000000000000000000000000000000000000000000022a0000000000000000000000000000000000000000000000000000000000000227000000000000000000000000000000000000000000000000000000000000098c00000000000000000000000000000000000000000000000000000000000005860000000000000000000000000000000000000000000000000000000000000bf90000000000000000000000000000000000000000000000000000000000000fea00000000000000000000000000000000000000000000000000000000000004c80000000000000000000000000000000000000000000000000000000000000f990000000000000000000000000000000000000000000000000000000000000c8500000000000000000000000000000000000000000000000000000000000006c00000000000000000000000000000000000000000000000000000000000000c2c00000000000000000000000000000000000000000000000000000000000006f70000000000000000000000000000000000000000000000000000000000000a220000000000000000000000000000000000000000000000000000000000000f3a00000000000000000000000000000000000000000000000000000000000008710000000000000000000000000000000000000000000000000000000000000cbc000000000000000000000000000000000000000000000000000000000000098900000000000000000000000000000000000000000000000000000000000013fa0000000000000000000000000000000000000000000000000000000000000f3f0000000000000000000000000000000000000000000000000000000000000d8300000000000000000000000000000000000000000000000000000000000004cc0000000000000000000000000000000000000000000000000000000000000c6c000000000000000000000000000000000000000000000000000000000000001a000000000000000000000000000000000000000000000000000000000000034000000000000000000000000000000000000000000000000000000000000003c0000000000000000000000000000000000000000000000000000000000000044000000000000000000000000000000000000000000000000000000000000004c0000000000000000000000000000000000000000000000000000000000000054000000000000000000000000000000000000000000000000000000000000005c0000000000000000000000000000000000000000000000000000000000000064000000000000000000000000000000000000000000000000000000000000006c0000000000000000000000000000000000000000000000000000000000000074000000000000000000000000000000000000000000000000000000000000007c0000000000000000000000000000000000000000000000000000000000000084000000000000000000000000000000000000000000000000000000000000008c0000000000000000000000000000000000000000000000000000000000000094000000000000000000000000000000000000000000000000000000000000009c00000000000000000000000000000000000000000000000000000000000000a400000000000000000000000000000000000000000000000000000000000000ac00000000000000000000000000000000000000000000000000000000000000b400000000000000000000000000000000000000000000000000000000000000bc00000000000000000000000000000000000000000000000000000000000000c400000000000000000000000000000000000000000000000000000000000000cc00000000000000000000000000000000000000000000000000000000000000d400000000000000000000000000000000000000000000000000000000000000dc00000000000000000000000000000000000000000000000000000000000000e400000000000000000000000000000000000000000000000000000000000000ec00000000000000000000000000000000000000000000000000000000000000f400000000000000000000000000000000000000000000000000000000000000fc0000000000000000000000000000000000000000000000000000000000000004178b04a351c892e310f836ce84111f226e2b59612dfb12b7e4620546fc07e830c28febdc4e7e1e979f65c25fb559a583e07c263b1a91c44ab67866d65ef9612631b000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000041d96eb274116cf66570235cf174c45ac6ac5fc3484fa705f855b59aa7966b68141d7bc3faeaefec754546ca59d0d13e17a1cdba29068fd01c92df9f0b71b686411c000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000041995574eae7662e388ec38823b1b177b21af0b834775131a4877759b91c3f9b5900f62999a22042b3d4ccf35caf0c67326d6e5673aabeb9e876927430fbb0c9251c00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000004163e8fb22ec49dda417d33578cb254d4731dcd5bb3951fea20f9d2b4daee7f82e27f1edd0b4ae6df97f03089bb988852d2aefe760cfd13a368ee876c41a65cbb31b0000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000416d8576ece58978dfb244bc86cf03676b937a7e249dae8ac402781f92211a756c46883fd3e981cff438fa1c0ca367bb65070d085f2fdd620e2567b9d8d2c9dd831b000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000041bc2cf7de5a9d47955f16e861e86e63ccfbb80c94b261ecb8c7b49dce6b6d5add713c6873cddd9b5420d02e12cad6db949d351e23014508ba9d6aba63ae9391311c00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000004158ce14379b885a73234072b602109a58f00b094bc30ce224d3316322a8cce7167bb821cd15fa5b000c03956e203ccbf59a47890f119af5037ab98eb1f40aa87a1b00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000004150ab711dc34a02af44cc5497ae054574e2efe643ac99323b1ef2b8294566c0e0449331e64fedb6f80b472ad31bf84150f1ded1a1a24e55c3bed8f25003536ea61c000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000041af76d69ba2fcf9007e7301d71c1efe55a2175da759b01664c3a188203e8fb8684a4db0987da4c933676da809687369e9dffa067f48137c21f99cf012cc820e791b0000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000418dbeface745d74cabbdf5863e397bc06d40f3f11ac18c501d8204a87ed2d182f16d3bc5bf0c3726cbb1940165c6cda1735105b9ec437ac888e7aa9036182eadc1b0000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000415b748001a6d8ca7eb7e90a109cef53bceb26444885f343d8299d51d391bf784c7a0cb2659c95b373fd42db2a7af890431cd615710af2d81cbc7df484f21e2cd11c000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000041249210b837fa86b9f391b4ef14e212c9e039035653452815a5f8acf5c48228a657f47d8de2cbb497b093b101b1261d52131913ebc633f15ced26f62ad66a02341b000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000041b71d9b7d991deb9c7bebd82cb595d0d056abe9288a3759278a9bdf95fb3adf0c34a946b9ac4ec07c0ba1f0af04904d2621a7369cbc9af1b63aac6c3f81b4b0a41b00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000004129e52b2f5f2779d46e17e1a842cf4433984c5c15c65dbc734f4289ee08387033557bf3127149ed9333aca3606585b115b60f315b22507c7d4ee6d6f43241be8b1b0000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000417f2f6840dac370c2afa6ab6a7e268688b7cd993a722557f47eced29bda96b47b7fc5328bc54e960d0680ab63a9ec38c699e12e45778809ccb1b206ee1446c5f41c000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000041577e94d9c6ce954f74cda9f1916bcabe6f79f5bdd13f8343d1a840df3e5cef663eef034e5807f8d8ada1ae657345fcf6cab721a8b457b85680261d6ac15ff0641b000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000041c71b153ec6b33ebbc4d85058cd7a5af7560326c198e874a4d9ebe3424444a5ec1e0adc7f17d309753c9e8c490b97be70b08a6a09bd647f4cf872e04bcf1198071c00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000004102641dcf544f277e60f29f675f7332393c77e4ca0b6910713c9f63c46cf2cff20d5c5a621b6d96a3c809ef32af91552321836360255eda813d622026f931eefb1c00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000004198f5b24330bc6c544f306de92a06c9f0a736c35ba4980d24ddef0889395288c81b21730e1c7c4d8b8ed10f167b46a1a1c0f8b9b8e032c0c29e940e6a3fd743011c000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000041e64ec926886ad800bc3a515b1ec587f918d45132e4117371f2c04f44d3cee0340d06b8a7719d0907b4b8add86165fd9544c81cb3a313bae51c4e486f765c88f31b000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000041919de19252b914c5a24e4e17a15334b55e3516736d77f9e3bbdd9eb2ed3772f95cf64bbebc5700d340df29e192b06c62cb9210bdb2a02f2a241f91b04d4b85e11c000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000041f36a88acf9563c11dd6b7260f1ae97875da0f283426d4c6d18af3e8b494c2d33153db701cf9e2dee49554d8ffeb6e1e6668870ab3d7f52b476d9f9dbeab0557f1b000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000041904b8ed91d65f227284331905b7c27c1c444d07cf117176c800a56403ce3bf717ebf9f9f22bda72e15f8927c2de0b588215ddeb843550b5d1ed64de30170273e1b000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000041bee456d591a1f9e09e445226d6ee80e929ae1f1e6938457d2073461b7389171360277233d20eb10a2a8e53645dd8a1aa95bb4a103d0d66276e4c1e51f36ed1f11b00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000004127ae576319940182ab062e4342bd818e6fe4e7937cb81eb37a748f251fd04943484c58bf06dcc36eff3f12f01f6b29a2a6fb8a05aca2a67f501416b8b30ae18f1b000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000041c33ff523371e70dee70e0dfef77abfd1702f13c2998b95456f01bdcaf4dd4ebc31708efdad83ef022f52c00dbc9452ad67ed41e2f6253536b5035ce3572222891c00000000000000000000000000000000000000000000000000000000000000").expect("bad input");
         let (msg, raw_proof) = proof::proof_tests::decode_input(&input).expect("Input should decode");
-        let msg_hash = H256::from_slice(&ecdsa::to_eth_signed_message_hash(keccak_256(msg.as_slice())));
+        let data_hash = H256(keccak_256(msg.as_slice()));
 
         let operators_hash = H256::from_slice(&hex::decode("eeccf7fbb3e9555e6b6111e1ccf0382b0ffa7c66cde0d69d1bdb1b80b6fc2ee3").expect(""));
         EpochForHash::<Runtime>::insert(operators_hash, 100);
         CurrentEpoch::<Runtime>::set(115);
 
         assert_eq!(
-            AxelarGateway::validate_proof(msg_hash, &raw_proof),
+            AxelarGateway::validate_proof(data_hash, &raw_proof),
             Ok(false)
         );
     });
@@ -369,11 +860,11 @@ fn execute_simple_batch_success() {
         CurrentEpoch::<Runtime>::set(101);
         assert_ok!(AxelarGateway::execute(
             RuntimeOrigin::signed(ALICE),
-            proof_bytes.clone(),
+            bounded(proof_bytes.clone()),
             chain_id,
-            vec![command_id],
-            vec![command_x.clone()],
-            vec![inner_call.clone()]
+            bounded(vec![command_id]),
+            command_names(vec![command_x.clone()]),
+            bounded(vec![inner_call.clone()])
         ));
 
         event_exists(Event::<Runtime>::BatchCompleted {});
@@ -384,11 +875,11 @@ fn execute_simple_batch_success() {
         CurrentEpoch::<Runtime>::set(100);
         assert_ok!(AxelarGateway::execute(
             RuntimeOrigin::signed(ALICE),
-            proof_bytes,
+            bounded(proof_bytes),
             chain_id,
-            vec![command_id],
-            vec![command_x],
-            vec![inner_call]
+            bounded(vec![command_id]),
+            command_names(vec![command_x]),
+            bounded(vec![inner_call])
         ));
 
         event_exists(Event::<Runtime>::BatchCompleted {});
@@ -398,139 +889,349 @@ fn execute_simple_batch_success() {
 }
 
 #[test]
-fn execute_simple_batch_invalid_proof() {
+fn execute_refunds_unchecked_signatures() {
     ExtBuilder::default().build().execute_with(|| {
-        // Invalid Operators
-        let inner_call = RuntimeCall::AxelarGateway(AxelarGatewayCall::transfer_operatorship {
-            new_operators: vec![],
-            new_weights: vec![],
-            new_threshold: 0u128,
+        let command_id = H256::random();
+        let inner_call = RuntimeCall::AxelarGateway(AxelarGatewayCall::approve_contract_call {
+            source_chain: bounded_str("ethereum"),
+            source_address: bounded_str("0x5f927395213ee6b95de97bddcb1b2b1c0f16844d"),
+            contract_address: H160::random(),
+            payload_hash: H256::random(),
+            source_tx_hash: H256::random(),
+            source_event_index: U256::from(100),
+            command_id,
         });
 
         let chain_id = 36_u32;
-        let command_id = H256::from_slice(
-            &hex::decode("eeccf7fbb3e9555e6b6111e1ccf0382b0ffa7c66cde0d69d1bdb1b80b6fc2ee3")
-                .expect(""),
+        let command_x: String = String::from("approveContractCall");
+
+        let batch_msg: ethabi::Bytes = AxelarGateway::abi_encode_batch_params(
+            chain_id,
+            vec![command_id.clone()],
+            vec![command_x.clone()],
+            vec![inner_call.clone()],
         );
-        let command_x: String = String::from("transferOperatorship");
+        let sign_msg = ecdsa::to_eth_signed_message_hash(keccak_256(batch_msg.as_slice()));
 
         let operator_0 = ecdsa::generate_keypair();
         let operator_1 = ecdsa::generate_keypair();
         let operator_0_public = H160::from(H256::from_slice(keccak_256(&operator_0.0).as_slice()));
         let operator_1_public = H160::from(H256::from_slice(keccak_256(&operator_1.0).as_slice()));
+        let sig_0 = ecdsa::sign_message(H256::from_slice(&sign_msg), &operator_0.1);
+        let sig_1 = ecdsa::sign_message(H256::from_slice(&sign_msg), &operator_1.1);
+        // The first signature already meets the threshold
         let proof_bytes = proof::proof_tests::encode(
             vec![
                 operator_0_public.to_fixed_bytes(),
                 operator_1_public.to_fixed_bytes(),
             ],
-            vec![50, 50],
+            vec![50u128, 50u128],
             50u128,
-            vec![vec![0u8], vec![0u8]],
+            vec![sig_0, sig_1],
         )
         .to_vec();
 
-        // Calculate Operators Hash dynamically
-        let op0_addr = Token::Address(operator_0_public.to_fixed_bytes().into())
-            .into_address()
-            .unwrap();
-        let op1_addr = Token::Address(operator_1_public.to_fixed_bytes().into())
-            .into_address()
-            .unwrap();
-        let operators_hash = proof::operators_hash(vec![op0_addr, op1_addr], vec![50, 50], 50u128);
+        let operators_hash = proof::operators_hash(
+            vec![
+                operator_0_public.to_fixed_bytes().into(),
+                operator_1_public.to_fixed_bytes().into(),
+            ],
+            vec![50u128, 50u128],
+            50u128,
+        );
+        EpochForHash::<Runtime>::insert(operators_hash, 1);
+        CurrentEpoch::<Runtime>::set(1);
 
-        EpochForHash::<Runtime>::insert(operators_hash, 100);
-        CurrentEpoch::<Runtime>::set(115);
+        let call = RuntimeCall::AxelarGateway(AxelarGatewayCall::execute {
+            proof: bounded(proof_bytes.clone()),
+            chain_id,
+            command_ids: bounded(vec![command_id]),
+            commands: command_names(vec![command_x]),
+            calls: bounded(vec![inner_call.clone()]),
+        });
+        let inner_weight = inner_call.get_dispatch_info().weight;
+        let inner_size = inner_call.encoded_size() as u32;
+
+        // Charged upfront for every signature in the proof
+        assert_eq!(
+            call.get_dispatch_info().weight,
+            <() as WeightInfo>::execute(1, 2, 2, inner_size).saturating_add(inner_weight)
+        );
+
+        // Refunded down to the signatures checked
+        let post_info = call
+            .dispatch(RuntimeOrigin::signed(ALICE))
+            .expect("Batch should execute");
+        assert_eq!(
+            post_info.actual_weight,
+            Some(<() as WeightInfo>::execute(1, 1, 2, inner_size).saturating_add(inner_weight))
+        );
+        assert_eq!(CommandExecuted::<Runtime>::get(command_id), chain_id);
+    });
+}
+
+#[test]
+fn execute_checks_commands_against_calls() {
+    ExtBuilder::default().build().execute_with(|| {
+        let approve_call = |command_id: H256| {
+            RuntimeCall::AxelarGateway(AxelarGatewayCall::approve_contract_call {
+                source_chain: bounded_str("ethereum"),
+                source_address: bounded_str("0x5f927395213ee6b95de97bddcb1b2b1c0f16844d"),
+                contract_address: H160::random(),
+                payload_hash: H256::random(),
+                source_tx_hash: H256::random(),
+                source_event_index: U256::from(100),
+                command_id,
+            })
+        };
+
+        let chain_id = 36_u32;
+        let command_ids: Vec<H256> = (1..=4).map(H256::from_low_u64_be).collect();
+        // An approval signed as an operatorship transfer, a valid approval, a command
+        // the gateway doesn't execute and an approval carrying another command id
+        let commands: Vec<String> = vec![
+            Command::TransferOperatorship.as_abi().into(),
+            Command::ApproveContractCall.as_abi().into(),
+            String::from("mintToken"),
+            Command::ApproveContractCall.as_abi().into(),
+        ];
+        let mut calls: Vec<RuntimeCall> = command_ids.iter().map(|id| approve_call(*id)).collect();
+        calls[3] = approve_call(H256::from_low_u64_be(5));
+
+        let batch_msg: ethabi::Bytes = AxelarGateway::abi_encode_batch_params(
+            chain_id,
+            command_ids.clone(),
+            commands.clone(),
+            calls.clone(),
+        );
+        let sign_msg = ecdsa::to_eth_signed_message_hash(keccak_256(batch_msg.as_slice()));
+
+        let operator_0 = ecdsa::generate_keypair();
+        let operator_0_public = H160::from(H256::from_slice(keccak_256(&operator_0.0).as_slice()));
+        let sig_0 = ecdsa::sign_message(H256::from_slice(&sign_msg), &operator_0.1);
+        let proof_bytes = proof::proof_tests::encode(
+            vec![operator_0_public.to_fixed_bytes()],
+            vec![50u128],
+            50u128,
+            vec![sig_0],
+        )
+        .to_vec();
+        let operators_hash = proof::operators_hash(
+            vec![operator_0_public.to_fixed_bytes().into()],
+            vec![50u128],
+            50u128,
+        );
+        EpochForHash::<Runtime>::insert(operators_hash, 1);
+        CurrentEpoch::<Runtime>::set(1);
 
+        // Every command id needs its command
         assert_noop!(
             AxelarGateway::execute(
                 RuntimeOrigin::signed(ALICE),
-                proof_bytes,
+                bounded(proof_bytes.clone()),
                 chain_id,
-                vec![command_id],
-                vec![command_x],
-                vec![inner_call]
+                bounded(command_ids.clone()),
+                command_names(commands[..2].to_vec()),
+                bounded(calls.clone())
             ),
-            Error::<Runtime>::InvalidProof
+            Error::<Runtime>::CommandIdsLengthMismatch
         );
+
+        assert_ok!(AxelarGateway::execute(
+            RuntimeOrigin::signed(ALICE),
+            bounded(proof_bytes),
+            chain_id,
+            bounded(command_ids.clone()),
+            command_names(commands),
+            bounded(calls)
+        ));
+
+        event_exists(Event::<Runtime>::ItemFailed {
+            index: 0,
+            error: Error::<Runtime>::CommandMismatch.into(),
+        });
+        event_exists(Event::<Runtime>::ItemFailed {
+            index: 2,
+            error: Error::<Runtime>::UnknownCommand.into(),
+        });
+        event_exists(Event::<Runtime>::ItemFailed {
+            index: 3,
+            error: Error::<Runtime>::CommandMismatch.into(),
+        });
+        event_exists(Event::<Runtime>::BatchCompletedWithErrors);
+        assert!(!CommandExecuted::<Runtime>::contains_key(command_ids[0]));
+        assert_eq!(CommandExecuted::<Runtime>::get(command_ids[1]), chain_id);
+        assert!(!CommandExecuted::<Runtime>::contains_key(command_ids[2]));
+        assert!(!CommandExecuted::<Runtime>::contains_key(command_ids[3]));
+        assert!(!CommandExecuted::<Runtime>::contains_key(
+            H256::from_low_u64_be(5)
+        ));
+        assert_eq!(AxelarGateway::current_epoch(), 1);
     });
 }
 
 #[test]
-fn execute_simple_batch_invalid_operators() {
+fn command_abi_names() {
+    for command in [Command::ApproveContractCall, Command::TransferOperatorship] {
+        assert_eq!(Command::from_abi(command.as_abi()), Some(command));
+    }
+    assert_eq!(Command::from_abi("approve_contract_call"), None);
+    assert_eq!(Command::from_abi("burnToken"), None);
+}
+
+#[test]
+fn validate_batch_report() {
     ExtBuilder::default().build().execute_with(|| {
-        // Invalid Operators
-        let inner_call = RuntimeCall::AxelarGateway(AxelarGatewayCall::transfer_operatorship {
-            new_operators: vec![],
-            new_weights: vec![],
-            new_threshold: 0u128,
+        let transfer_call = RuntimeCall::AxelarGateway(AxelarGatewayCall::transfer_operatorship {
+            new_operators: vec![[1; 20]],
+            new_weights: vec![10],
+            new_threshold: 10u128,
         });
+        let approve_call = |command_id: H256| {
+            RuntimeCall::AxelarGateway(AxelarGatewayCall::approve_contract_call {
+                source_chain: bounded_str("ethereum"),
+                source_address: bounded_str("0x5f927395213ee6b95de97bddcb1b2b1c0f16844d"),
+                contract_address: H160::random(),
+                payload_hash: H256::random(),
+                source_tx_hash: H256::random(),
+                source_event_index: U256::from(100),
+                command_id,
+            })
+        };
+        let remark_call = RuntimeCall::System(SystemCall::remark { remark: vec![10] });
 
         let chain_id = 36_u32;
-        let command_id = H256::from_slice(
-            &hex::decode("eeccf7fbb3e9555e6b6111e1ccf0382b0ffa7c66cde0d69d1bdb1b80b6fc2ee3")
-                .expect(""),
-        );
-        let command_x: String = String::from("transferOperatorship");
+        let executed_id = H256::random();
+        let command_ids = vec![
+            H256::random(),
+            H256::random(),
+            H256::random(),
+            executed_id,
+            H256::random(),
+            H256::random(),
+        ];
+        let commands: Vec<String> = vec![
+            String::from("transferOperatorship"),
+            String::from("transferOperatorship"),
+            String::from("approveContractCall"),
+            String::from("approveContractCall"),
+            String::from("remark"),
+            String::from("approveContractCall"),
+        ];
+        let calls = vec![
+            transfer_call.clone(),
+            transfer_call,
+            approve_call(command_ids[2]),
+            approve_call(executed_id),
+            remark_call.clone(),
+            remark_call,
+        ];
 
         let batch_msg: ethabi::Bytes = AxelarGateway::abi_encode_batch_params(
             chain_id,
-            vec![command_id.clone()],
-            vec![command_x.clone()],
-            vec![inner_call.clone()],
+            command_ids.clone(),
+            commands.clone(),
+            calls.clone(),
         );
         let sign_msg = ecdsa::to_eth_signed_message_hash(keccak_256(batch_msg.as_slice()));
 
         let operator_0 = ecdsa::generate_keypair();
-        let operator_1 = ecdsa::generate_keypair();
         let operator_0_public = H160::from(H256::from_slice(keccak_256(&operator_0.0).as_slice()));
-        let operator_1_public = H160::from(H256::from_slice(keccak_256(&operator_1.0).as_slice()));
         let sig_0 = ecdsa::sign_message(H256::from_slice(&sign_msg), &operator_0.1);
-        let sig_1 = ecdsa::sign_message(H256::from_slice(&sign_msg), &operator_1.1);
         let proof_bytes = proof::proof_tests::encode(
-            vec![
-                operator_0_public.to_fixed_bytes(),
-                operator_1_public.to_fixed_bytes(),
-            ],
-            vec![50, 50],
+            vec![operator_0_public.to_fixed_bytes()],
+            vec![50u128],
             50u128,
-            vec![sig_0, sig_1],
+            vec![sig_0],
         )
         .to_vec();
+        let operators_hash = proof::operators_hash(
+            vec![operator_0_public.to_fixed_bytes().into()],
+            vec![50u128],
+            50u128,
+        );
 
-        assert_noop!(
-            AxelarGateway::execute(
-                RuntimeOrigin::signed(ALICE),
-                proof_bytes,
+        // Wrong chain
+        assert_eq!(
+            AxelarGateway::validate_batch(
+                &proof_bytes,
+                1,
+                command_ids.clone(),
+                commands.clone(),
+                calls.clone()
+            ),
+            BatchValidation {
+                error: Some(Error::<Runtime>::WrongChainId.into()),
+                ..Default::default()
+            }
+        );
+
+        // Unknown operators
+        assert_eq!(
+            AxelarGateway::validate_batch(
+                &proof_bytes,
                 chain_id,
-                vec![command_id],
-                vec![command_x],
-                vec![inner_call]
+                command_ids.clone(),
+                commands.clone(),
+                calls.clone()
             ),
-            Error::<Runtime>::InvalidOperators
+            BatchValidation {
+                error: Some(Error::<Runtime>::InvalidOperators.into()),
+                ..Default::default()
+            }
         );
+
+        EpochForHash::<Runtime>::insert(operators_hash, 1);
+        CurrentEpoch::<Runtime>::set(1);
+        CommandExecuted::<Runtime>::insert(executed_id, chain_id);
+
+        assert_eq!(
+            AxelarGateway::validate_batch(
+                &proof_bytes,
+                chain_id,
+                command_ids.clone(),
+                commands.clone(),
+                calls.clone()
+            ),
+            BatchValidation {
+                error: None,
+                proof_valid: true,
+                active_operators: true,
+                commands: vec![
+                    CommandStatus::Run,
+                    CommandStatus::NotActiveOperators,
+                    CommandStatus::Run,
+                    CommandStatus::AlreadyExecuted,
+                    CommandStatus::UnknownCommand,
+                    CommandStatus::CommandMismatch,
+                ],
+            }
+        );
+
+        // Nothing has been dispatched
+        assert_eq!(CurrentEpoch::<Runtime>::get(), 1);
+        assert!(!CommandExecuted::<Runtime>::contains_key(command_ids[0]));
+        assert!(frame_system::Pallet::<Runtime>::events().is_empty());
+
+        // Signed by operators which are not the current ones
+        CurrentEpoch::<Runtime>::set(2);
+        let report =
+            AxelarGateway::validate_batch(&proof_bytes, chain_id, command_ids, commands, calls);
+        assert!(report.proof_valid);
+        assert!(!report.active_operators);
+        assert_eq!(report.commands[0], CommandStatus::NotActiveOperators);
     });
 }
 
 #[test]
-fn execute_simple_batch_error_invalid_order_operators() {
+fn execute_simple_batch_invalid_proof() {
     ExtBuilder::default().build().execute_with(|| {
-        // Prep new operators call
-        let operator_2 = ecdsa::generate_keypair();
-        let operator_3 = ecdsa::generate_keypair();
-        let operator_2_public = H160::from(H256::from_slice(keccak_256(&operator_2.0).as_slice()));
-        let operator_3_public = H160::from(H256::from_slice(keccak_256(&operator_3.0).as_slice()));
-        let mut new_operators = vec![
-            operator_2_public.to_fixed_bytes(),
-            operator_3_public.to_fixed_bytes(),
-        ];
-        // Sort descendent instead of ascendent
-        new_operators.sort_by(|a, b| b.cmp(a));
+        // Invalid Operators
         let inner_call = RuntimeCall::AxelarGateway(AxelarGatewayCall::transfer_operatorship {
-            new_operators,
-            new_weights: vec![40, 60],
-            new_threshold: 50u128,
+            new_operators: vec![],
+            new_weights: vec![],
+            new_threshold: 0u128,
         });
-        //
 
         let chain_id = 36_u32;
         let command_id = H256::from_slice(
@@ -539,26 +1240,459 @@ fn execute_simple_batch_error_invalid_order_operators() {
         );
         let command_x: String = String::from("transferOperatorship");
 
-        let batch_msg: ethabi::Bytes = AxelarGateway::abi_encode_batch_params(
-            chain_id,
-            vec![command_id.clone()],
-            vec![command_x.clone()],
-            vec![inner_call.clone()],
-        );
-        let sign_msg = ecdsa::to_eth_signed_message_hash(keccak_256(batch_msg.as_slice()));
-
         let operator_0 = ecdsa::generate_keypair();
         let operator_1 = ecdsa::generate_keypair();
         let operator_0_public = H160::from(H256::from_slice(keccak_256(&operator_0.0).as_slice()));
         let operator_1_public = H160::from(H256::from_slice(keccak_256(&operator_1.0).as_slice()));
-        let sig_0 = ecdsa::sign_message(H256::from_slice(&sign_msg), &operator_0.1);
-        let sig_1 = ecdsa::sign_message(H256::from_slice(&sign_msg), &operator_1.1);
         let proof_bytes = proof::proof_tests::encode(
             vec![
                 operator_0_public.to_fixed_bytes(),
                 operator_1_public.to_fixed_bytes(),
             ],
-            vec![50u128, 50u128],
+            vec![50, 50],
+            50u128,
+            vec![vec![0u8; 65], vec![0u8; 65]],
+        )
+        .to_vec();
+
+        // Calculate Operators Hash dynamically
+        let op0_addr = Token::Address(operator_0_public.to_fixed_bytes().into())
+            .into_address()
+            .unwrap();
+        let op1_addr = Token::Address(operator_1_public.to_fixed_bytes().into())
+            .into_address()
+            .unwrap();
+        let operators_hash = proof::operators_hash(vec![op0_addr, op1_addr], vec![50, 50], 50u128);
+
+        EpochForHash::<Runtime>::insert(operators_hash, 100);
+        CurrentEpoch::<Runtime>::set(115);
+
+        assert_noop!(
+            AxelarGateway::execute(
+                RuntimeOrigin::signed(ALICE),
+                bounded(proof_bytes),
+                chain_id,
+                bounded(vec![command_id]),
+                command_names(vec![command_x]),
+                bounded(vec![inner_call])
+            ),
+            Error::<Runtime>::InvalidProof
+        );
+    });
+}
+
+#[test]
+fn validate_proof_rejects_replayed_signatures() {
+    ExtBuilder::default().build().execute_with(|| {
+        let data_hash = H256(keccak_256(b"batch"));
+        let msg_hash = H256::from_slice(&ecdsa::to_eth_signed_message_hash(data_hash.0));
+        let operator_0 = ecdsa::generate_keypair();
+        let operator_1 = ecdsa::generate_keypair();
+        let operator_0_public = H160::from(H256::from_slice(keccak_256(&operator_0.0).as_slice()));
+        let operator_1_public = H160::from(H256::from_slice(keccak_256(&operator_1.0).as_slice()));
+        let sig_0 = ecdsa::sign_message(msg_hash, &operator_0.1);
+        let sig_1 = ecdsa::sign_message(msg_hash, &operator_1.1);
+        let operators = vec![
+            operator_0_public.to_fixed_bytes(),
+            operator_1_public.to_fixed_bytes(),
+        ];
+        let operators_hash = proof::operators_hash(
+            operators.iter().map(|x| (*x).into()).collect(),
+            vec![50u128, 50u128],
+            100u128,
+        );
+        EpochForHash::<Runtime>::insert(operators_hash, 1);
+        CurrentEpoch::<Runtime>::set(1);
+
+        let proof = |signatures: Vec<Vec<u8>>| {
+            proof::proof_tests::encode(operators.clone(), vec![50u128, 50u128], 100u128, signatures)
+        };
+
+        // A single operator's signature, twice, would meet the threshold if counted twice
+        assert_eq!(
+            AxelarGateway::validate_proof(data_hash, &proof(vec![sig_0.clone(), sig_0.clone()])),
+            Err(Error::<Runtime>::InvalidProof.into())
+        );
+        // Both signatures, out of order
+        assert_eq!(
+            AxelarGateway::validate_proof(data_hash, &proof(vec![sig_1.clone(), sig_0.clone()])),
+            Err(Error::<Runtime>::InvalidProof.into())
+        );
+        assert_eq!(
+            AxelarGateway::validate_proof(data_hash, &proof(vec![sig_0, sig_1])),
+            Ok(true)
+        );
+    });
+}
+
+#[test]
+fn validate_proof_reports_malformed_proofs() {
+    ExtBuilder::default().build().execute_with(|| {
+        let data_hash = H256::repeat_byte(1);
+        let operators = vec![[1u8; 20], [2u8; 20]];
+
+        assert_eq!(
+            AxelarGateway::validate_proof(data_hash, &[1, 2, 3]),
+            Err(Error::<Runtime>::FailedToDecodeProof.into())
+        );
+        assert_eq!(
+            AxelarGateway::validate_proof(
+                data_hash,
+                &proof::proof_tests::encode(operators.clone(), vec![1], 1, vec![vec![1u8; 65]])
+            ),
+            Err(Error::<Runtime>::ProofWeightsLengthMismatch.into())
+        );
+        assert_eq!(
+            AxelarGateway::validate_proof(
+                data_hash,
+                &proof::proof_tests::encode(operators, vec![1, 1], 1, vec![vec![1u8; 64]])
+            ),
+            Err(Error::<Runtime>::InvalidSignatureLength.into())
+        );
+        let overflowing_threshold = ethabi::encode(&[
+            Token::Array(vec![Token::Address([1u8; 20].into())]),
+            Token::Array(vec![Token::Uint(1.into())]),
+            Token::Uint(ethabi::Uint::MAX),
+            Token::Array(vec![Token::Bytes(vec![1u8; 65])]),
+        ]);
+        assert_eq!(
+            AxelarGateway::validate_proof(data_hash, &overflowing_threshold),
+            Err(Error::<Runtime>::ProofValueOverflow.into())
+        );
+    });
+}
+
+/// Two operators of the `P` key type sorted by identifier, along with their
+/// `public ++ signature` of `msg_hash`
+fn sign_with<P: sp_core::Pair<Seed = [u8; 32]>>(msg_hash: H256) -> (Vec<[u8; 20]>, Vec<Vec<u8>>) {
+    let mut signed: Vec<_> = (1..=2u8)
+        .map(|seed| {
+            let pair = P::from_seed(&[seed; 32]);
+            let public = pair.public();
+            let signature = pair.sign(msg_hash.as_bytes());
+            (
+                crate::traits::key_to_operator(public.as_ref()),
+                [public.as_ref(), signature.as_ref()].concat(),
+            )
+        })
+        .collect();
+    signed.sort_by_key(|(operator, _)| *operator);
+
+    signed.into_iter().unzip()
+}
+
+#[test]
+fn validate_proof_with_other_signature_schemes() {
+    let data_hash = H256(keccak_256(b"batch"));
+    let msg_hash = H256(ecdsa::to_eth_signed_message_hash(data_hash.0));
+
+    for (scheme, (operators, signatures)) in [
+        (
+            SignatureScheme::Ed25519,
+            sign_with::<sp_core::ed25519::Pair>(msg_hash),
+        ),
+        (
+            SignatureScheme::Sr25519,
+            sign_with::<sp_core::sr25519::Pair>(msg_hash),
+        ),
+    ] {
+        ExtBuilder::default()
+            .with_operators(operators.clone(), vec![1, 1], 2)
+            .with_signature_scheme(scheme)
+            .build()
+            .execute_with(|| {
+                let proof = |signatures: Vec<Vec<u8>>| {
+                    proof::proof_tests::encode(operators.clone(), vec![1, 1], 2, signatures)
+                };
+
+                assert_eq!(
+                    AxelarGateway::validate_proof(data_hash, &proof(signatures.clone())),
+                    Ok(true)
+                );
+
+                // Signed over something else
+                assert_noop!(
+                    AxelarGateway::validate_proof(H256::repeat_byte(1), &proof(signatures.clone())),
+                    Error::<Runtime>::InvalidProof,
+                );
+
+                // Signature of an operator under the key of the other one
+                let mut forged = signatures.clone();
+                forged[1][..32].copy_from_slice(&signatures[0][..32]);
+                assert_noop!(
+                    AxelarGateway::validate_proof(data_hash, &proof(forged)),
+                    Error::<Runtime>::InvalidProof,
+                );
+
+                // ECDSA signatures are of another length
+                assert_noop!(
+                    AxelarGateway::validate_proof(data_hash, &proof(vec![vec![1u8; 65]; 2])),
+                    Error::<Runtime>::InvalidSignatureLength,
+                );
+            });
+    }
+}
+
+#[test]
+fn weighted_signers_rotation() {
+    let weighted_signers =
+        |signers: Vec<[u8; 20]>, weights: Vec<u128>, threshold, nonce| proof::WeightedSigners {
+            signers: signers.into_iter().map(Into::into).zip(weights).collect(),
+            threshold,
+            nonce,
+        };
+    let mut keys: Vec<_> = (0..2)
+        .map(|_| {
+            let (public, secret) = ecdsa::generate_keypair();
+            let address = H160::from(H256::from_slice(keccak_256(&public).as_slice()));
+            (address.to_fixed_bytes(), secret)
+        })
+        .collect();
+    keys.sort_by_key(|(address, _)| *address);
+    let signers: Vec<[u8; 20]> = keys.iter().map(|(address, _)| *address).collect();
+    let sign = |msg_hash: H256| -> Vec<Vec<u8>> {
+        keys.iter()
+            .map(|(_, secret)| ecdsa::sign_message(msg_hash, secret))
+            .collect()
+    };
+    let nonce = H256::repeat_byte(1);
+
+    ExtBuilder::default()
+        .with_operators(signers.clone(), vec![1, 1], 2)
+        .with_weighted_signers(nonce)
+        .build()
+        .execute_with(|| {
+            let genesis_signers = weighted_signers(signers.clone(), vec![1, 1], 2, nonce);
+            assert_eq!(
+                AxelarGateway::current_operators_hash(),
+                genesis_signers.hash()
+            );
+
+            // Operator sets without a nonce are refused
+            assert_noop!(
+                AxelarGateway::transfer_operatorship(
+                    RawOrigin::Bridge.into(),
+                    vec![[4; 20]],
+                    vec![1],
+                    1
+                ),
+                Error::<Runtime>::UnsupportedProofVersion,
+            );
+
+            let new_nonce = H256::repeat_byte(2);
+            let inner_call = RuntimeCall::AxelarGateway(AxelarGatewayCall::rotate_signers {
+                new_signers: vec![[4; 20]],
+                new_weights: vec![1],
+                new_threshold: 1,
+                nonce: new_nonce,
+            });
+            let command_id = H256::repeat_byte(3);
+            let command = String::from("rotateSigners");
+            let data_hash = H256(keccak_256(&AxelarGateway::abi_encode_batch_params(
+                36,
+                vec![command_id],
+                vec![command.clone()],
+                vec![inner_call.clone()],
+            )));
+            let msg_hash = AxelarGateway::batch_message_hash(
+                36,
+                vec![command_id],
+                vec![command.clone()],
+                vec![inner_call.encode()],
+            );
+            assert_eq!(
+                msg_hash,
+                proof::message_hash(
+                    ProofVersion::WeightedSigners,
+                    DomainSeparator::get(),
+                    genesis_signers.hash(),
+                    data_hash
+                )
+            );
+
+            // Signatures of the legacy message don't count
+            let legacy_hash = H256(ecdsa::to_eth_signed_message_hash(data_hash.0));
+            assert_noop!(
+                AxelarGateway::validate_proof(
+                    data_hash,
+                    &proof::proof_tests::encode_weighted_signers(
+                        &genesis_signers,
+                        sign(legacy_hash)
+                    )
+                ),
+                Error::<Runtime>::InvalidProof,
+            );
+
+            assert_ok!(AxelarGateway::execute(
+                RuntimeOrigin::signed(ALICE),
+                bounded(proof::proof_tests::encode_weighted_signers(
+                    &genesis_signers,
+                    sign(msg_hash)
+                )),
+                36,
+                bounded(vec![command_id]),
+                command_names(vec![command]),
+                bounded(vec![inner_call])
+            ));
+
+            let new_hash = weighted_signers(vec![[4; 20]], vec![1], 1, new_nonce).hash();
+            event_exists(Event::<Runtime>::BatchCompleted {});
+            event_exists(Event::<Runtime>::OperatorshipTransferred {
+                new_operator_hash: new_hash,
+                new_epoch: 2,
+            });
+            assert_eq!(CurrentEpoch::<Runtime>::get(), 2);
+            assert_eq!(HashForEpoch::<Runtime>::get(2), new_hash);
+
+            // The same signer set comes back under a new nonce
+            assert_noop!(
+                AxelarGateway::rotate_signers(
+                    RawOrigin::Bridge.into(),
+                    vec![[4; 20]],
+                    vec![1],
+                    1,
+                    new_nonce
+                ),
+                Error::<Runtime>::DuplicateOperators,
+            );
+            assert_ok!(AxelarGateway::rotate_signers(
+                RawOrigin::Bridge.into(),
+                vec![[4; 20]],
+                vec![1],
+                1,
+                H256::repeat_byte(3)
+            ));
+            assert_noop!(
+                AxelarGateway::rotate_signers(
+                    RuntimeOrigin::signed(ALICE),
+                    vec![[4; 20]],
+                    vec![1],
+                    1,
+                    H256::repeat_byte(4)
+                ),
+                BadOrigin,
+            );
+        });
+
+    // Legacy gateways keep `transfer_operatorship`
+    ExtBuilder::default().build().execute_with(|| {
+        assert_noop!(
+            AxelarGateway::rotate_signers(
+                RawOrigin::Bridge.into(),
+                vec![[4; 20]],
+                vec![1],
+                1,
+                nonce
+            ),
+            Error::<Runtime>::UnsupportedProofVersion,
+        );
+    });
+}
+
+#[test]
+fn execute_simple_batch_invalid_operators() {
+    ExtBuilder::default().build().execute_with(|| {
+        // Invalid Operators
+        let inner_call = RuntimeCall::AxelarGateway(AxelarGatewayCall::transfer_operatorship {
+            new_operators: vec![],
+            new_weights: vec![],
+            new_threshold: 0u128,
+        });
+
+        let chain_id = 36_u32;
+        let command_id = H256::from_slice(
+            &hex::decode("eeccf7fbb3e9555e6b6111e1ccf0382b0ffa7c66cde0d69d1bdb1b80b6fc2ee3")
+                .expect(""),
+        );
+        let command_x: String = String::from("transferOperatorship");
+
+        let batch_msg: ethabi::Bytes = AxelarGateway::abi_encode_batch_params(
+            chain_id,
+            vec![command_id.clone()],
+            vec![command_x.clone()],
+            vec![inner_call.clone()],
+        );
+        let sign_msg = ecdsa::to_eth_signed_message_hash(keccak_256(batch_msg.as_slice()));
+
+        let operator_0 = ecdsa::generate_keypair();
+        let operator_1 = ecdsa::generate_keypair();
+        let operator_0_public = H160::from(H256::from_slice(keccak_256(&operator_0.0).as_slice()));
+        let operator_1_public = H160::from(H256::from_slice(keccak_256(&operator_1.0).as_slice()));
+        let sig_0 = ecdsa::sign_message(H256::from_slice(&sign_msg), &operator_0.1);
+        let sig_1 = ecdsa::sign_message(H256::from_slice(&sign_msg), &operator_1.1);
+        let proof_bytes = proof::proof_tests::encode(
+            vec![
+                operator_0_public.to_fixed_bytes(),
+                operator_1_public.to_fixed_bytes(),
+            ],
+            vec![50, 50],
+            50u128,
+            vec![sig_0, sig_1],
+        )
+        .to_vec();
+
+        assert_noop!(
+            AxelarGateway::execute(
+                RuntimeOrigin::signed(ALICE),
+                bounded(proof_bytes),
+                chain_id,
+                bounded(vec![command_id]),
+                command_names(vec![command_x]),
+                bounded(vec![inner_call])
+            ),
+            Error::<Runtime>::InvalidOperators
+        );
+    });
+}
+
+#[test]
+fn execute_simple_batch_error_invalid_order_operators() {
+    ExtBuilder::default().build().execute_with(|| {
+        // Prep new operators call
+        let operator_2 = ecdsa::generate_keypair();
+        let operator_3 = ecdsa::generate_keypair();
+        let operator_2_public = H160::from(H256::from_slice(keccak_256(&operator_2.0).as_slice()));
+        let operator_3_public = H160::from(H256::from_slice(keccak_256(&operator_3.0).as_slice()));
+        let mut new_operators = vec![
+            operator_2_public.to_fixed_bytes(),
+            operator_3_public.to_fixed_bytes(),
+        ];
+        // Sort descendent instead of ascendent
+        new_operators.sort_by(|a, b| b.cmp(a));
+        let inner_call = RuntimeCall::AxelarGateway(AxelarGatewayCall::transfer_operatorship {
+            new_operators,
+            new_weights: vec![40, 60],
+            new_threshold: 50u128,
+        });
+        //
+
+        let chain_id = 36_u32;
+        let command_id = H256::from_slice(
+            &hex::decode("eeccf7fbb3e9555e6b6111e1ccf0382b0ffa7c66cde0d69d1bdb1b80b6fc2ee3")
+                .expect(""),
+        );
+        let command_x: String = String::from("transferOperatorship");
+
+        let batch_msg: ethabi::Bytes = AxelarGateway::abi_encode_batch_params(
+            chain_id,
+            vec![command_id.clone()],
+            vec![command_x.clone()],
+            vec![inner_call.clone()],
+        );
+        let sign_msg = ecdsa::to_eth_signed_message_hash(keccak_256(batch_msg.as_slice()));
+
+        let operator_0 = ecdsa::generate_keypair();
+        let operator_1 = ecdsa::generate_keypair();
+        let operator_0_public = H160::from(H256::from_slice(keccak_256(&operator_0.0).as_slice()));
+        let operator_1_public = H160::from(H256::from_slice(keccak_256(&operator_1.0).as_slice()));
+        let sig_0 = ecdsa::sign_message(H256::from_slice(&sign_msg), &operator_0.1);
+        let sig_1 = ecdsa::sign_message(H256::from_slice(&sign_msg), &operator_1.1);
+        let proof_bytes = proof::proof_tests::encode(
+            vec![
+                operator_0_public.to_fixed_bytes(),
+                operator_1_public.to_fixed_bytes(),
+            ],
+            vec![50u128, 50u128],
             50u128,
             vec![sig_0, sig_1],
         )
@@ -579,11 +1713,11 @@ fn execute_simple_batch_error_invalid_order_operators() {
         CurrentEpoch::<Runtime>::set(100);
         assert_ok!(AxelarGateway::execute(
             RuntimeOrigin::signed(ALICE),
-            proof_bytes.clone(),
+            bounded(proof_bytes.clone()),
             chain_id,
-            vec![command_id],
-            vec![command_x.clone()],
-            vec![inner_call.clone()]
+            bounded(vec![command_id]),
+            command_names(vec![command_x.clone()]),
+            bounded(vec![inner_call.clone()])
         ));
 
         event_exists(Event::<Runtime>::BatchCompletedWithErrors {});
@@ -607,17 +1741,15 @@ fn forward_valid_approved_call() {
             AxelarGateway::forward_approved_call(
                 RuntimeOrigin::signed(ALICE),
                 command_id,
-                source_chain.clone(),
-                source_address.clone(),
+                bounded_str(&source_chain),
+                bounded_str(&source_address),
                 contract_address,
-                inner_call_bytes.clone(),
+                bounded(inner_call_bytes.clone()),
             ),
             Error::<Runtime>::ContractCallNotApproved
         );
 
-        let call_hash = H256::from_slice(&ecdsa::to_eth_signed_message_hash(keccak_256(
-            inner_call_bytes.as_slice(),
-        )));
+        let call_hash = H256(keccak_256(inner_call_bytes.as_slice()));
         let mut approved_call = command_id.encode();
         approved_call.append(&mut source_chain.encode());
         approved_call.append(&mut source_address.encode());
@@ -633,10 +1765,10 @@ fn forward_valid_approved_call() {
             AxelarGateway::forward_approved_call(
                 RuntimeOrigin::signed(ALICE),
                 command_id,
-                source_chain,
-                source_address,
+                bounded_str(&source_chain),
+                bounded_str(&source_address),
                 contract_address,
-                inner_call_bytes,
+                bounded(inner_call_bytes),
             ),
             BadOrigin
         );
@@ -654,9 +1786,7 @@ fn forward_invalid_approved_call() {
         let source_address = String::from("0x5f927395213ee6b95de97bddcb1b2b1c0f16844d");
         let contract_address = H160::random();
 
-        let call_hash = H256::from_slice(&ecdsa::to_eth_signed_message_hash(keccak_256(
-            inner_call_bytes.as_slice(),
-        )));
+        let call_hash = H256(keccak_256(inner_call_bytes.as_slice()));
         let mut approved_call = command_id.encode();
         approved_call.append(&mut source_chain.encode());
         approved_call.append(&mut source_address.encode());
@@ -667,14 +1797,27 @@ fn forward_invalid_approved_call() {
 
         ContractCallApproved::<Runtime>::set(approved_call_hash, ());
 
+        // Undecodable calls are only charged the forwarding itself
+        let call = RuntimeCall::AxelarGateway(AxelarGatewayCall::forward_approved_call {
+            command_id,
+            source_chain: bounded_str(&source_chain),
+            source_address: bounded_str(&source_address),
+            contract_address,
+            call: bounded(inner_call_bytes.clone()),
+        });
+        assert_eq!(
+            call.get_dispatch_info().weight,
+            <() as WeightInfo>::forward_approved_call(inner_call_bytes.len() as u32)
+        );
+
         assert_noop!(
             AxelarGateway::forward_approved_call(
                 RuntimeOrigin::signed(ALICE),
                 command_id,
-                source_chain,
-                source_address,
+                bounded_str(&source_chain),
+                bounded_str(&source_address),
                 contract_address,
-                inner_call_bytes,
+                bounded(inner_call_bytes),
             ),
             DispatchError::CannotLookup
         );
@@ -695,17 +1838,979 @@ fn contract_call_event_emitted() {
 
         assert_ok!(AxelarGateway::call_contract(
             RuntimeOrigin::signed(ALICE),
-            destination_chain.clone(),
-            destination_contract_address.clone(),
-            payload.clone()
+            bounded_str(&destination_chain),
+            bounded_str(&destination_contract_address),
+            bounded(payload.clone())
         ));
 
         event_exists(Event::<Runtime>::ContractCall {
+            nonce: 0,
             sender: ALICE,
             destination_chain,
             destination_contract_address,
             payload_hash: H256::from(keccak_256(payload.as_slice())),
             payload,
+            fee: 0,
+        });
+    });
+}
+
+#[test]
+fn call_contract_fees() {
+    ExtBuilder::default()
+        .with_fees(10, 2, Some(TREASURY))
+        .build()
+        .execute_with(|| {
+            let destination_chain = String::from("ethereum");
+            let destination_contract_address =
+                String::from("0x5f927395213ee6b95de97bddcb1b2b1c0f16844d");
+            let payload = vec![1u8; 32];
+            Balances::make_free_balance_be(&ALICE, 1_000);
+            assert_eq!(AxelarGateway::call_contract_fee(32), 74);
+
+            assert_ok!(AxelarGateway::call_contract(
+                RuntimeOrigin::signed(ALICE),
+                bounded_str(&destination_chain),
+                bounded_str(&destination_contract_address),
+                bounded(payload.clone())
+            ));
+
+            event_exists(Event::<Runtime>::ContractCall {
+                nonce: 0,
+                sender: ALICE,
+                destination_chain: destination_chain.clone(),
+                destination_contract_address: destination_contract_address.clone(),
+                payload_hash: H256::from(keccak_256(payload.as_slice())),
+                payload: payload.clone(),
+                fee: 74,
+            });
+            assert_eq!(Balances::free_balance(ALICE), 926);
+            assert_eq!(Balances::free_balance(TREASURY), 74);
+
+            // Burned without a fee destination
+            FeeDestination::set(&None);
+            let issuance = Balances::total_issuance();
+            assert_ok!(AxelarGateway::call_contract(
+                RuntimeOrigin::signed(ALICE),
+                bounded_str(&destination_chain),
+                bounded_str(&destination_contract_address),
+                bounded(payload.clone())
+            ));
+            assert_eq!(Balances::free_balance(ALICE), 852);
+            assert_eq!(Balances::free_balance(TREASURY), 74);
+            assert_eq!(Balances::total_issuance(), issuance - 74);
+
+            Balances::make_free_balance_be(&ALICE, 50);
+            assert_noop!(
+                AxelarGateway::call_contract(
+                    RuntimeOrigin::signed(ALICE),
+                    bounded_str(&destination_chain),
+                    bounded_str(&destination_contract_address),
+                    bounded(payload)
+                ),
+                pallet_balances::Error::<Runtime>::InsufficientBalance,
+            );
         });
+}
+
+#[test]
+fn inputs_exceeding_limits() {
+    ExtBuilder::default().build().execute_with(|| {
+        let chain = String::from("ethereum");
+        let address = String::from("0x5f927395213ee6b95de97bddcb1b2b1c0f16844d");
+        let long_chain = "a".repeat(33);
+        let long_address = "a".repeat(129);
+        let payload = vec![0u8; 16 * 1024];
+        let long_payload = vec![0u8; 16 * 1024 + 1];
+
+        // Oversize arguments are rejected when the extrinsic is decoded, before it is weighed
+        let decodes = |call_index: u8, args: Vec<u8>| {
+            let encoded = [vec![call_index], args].concat();
+            AxelarGatewayCall::<Runtime>::decode(&mut &encoded[..]).is_ok()
+        };
+
+        let call_contract = |chain: &str, address: &str, payload: &[u8]| {
+            decodes(0, (chain, address, payload).encode())
+        };
+        assert!(call_contract(&chain, &address, &payload));
+        assert!(!call_contract(&long_chain, &address, &payload));
+        assert!(!call_contract(&chain, &long_address, &payload));
+        assert!(!call_contract(&chain, &address, &long_payload));
+
+        let approve_contract_call = |chain: &str, address: &str| {
+            let approval = (
+                H160::random(),
+                H256::random(),
+                H256::random(),
+                U256::from(100),
+            );
+            decodes(3, (chain, address, approval, H256::random()).encode())
+        };
+        assert!(approve_contract_call(&chain, &address));
+        assert!(!approve_contract_call(&long_chain, &address));
+        assert!(!approve_contract_call(&chain, &long_address));
+
+        let forward_approved_call = |chain: &str, address: &str, call: &[u8]| {
+            decodes(
+                4,
+                (H256::random(), chain, address, H160::random(), call).encode(),
+            )
+        };
+        assert!(forward_approved_call(&chain, &address, &payload));
+        assert!(!forward_approved_call(&long_chain, &address, &payload));
+        assert!(!forward_approved_call(&chain, &long_address, &payload));
+        assert!(!forward_approved_call(&chain, &address, &long_payload));
+
+        let execute = |proof_len: usize, command_ids: usize, command: &str, calls: usize| {
+            let command_ids = vec![H256::random(); command_ids];
+            let commands = vec![command; command_ids.len()];
+            let calls = vec![RuntimeCall::System(SystemCall::remark { remark: vec![] }); calls];
+            decodes(
+                1,
+                (vec![0u8; proof_len], 36u32, command_ids, commands, calls).encode(),
+            )
+        };
+        let long_command = "a".repeat(MAX_COMMAND_NAME_LEN as usize + 1);
+        assert!(execute(16 * 1024, 100, "approveContractCall", 100));
+        assert!(!execute(16 * 1024 + 1, 100, "approveContractCall", 100));
+        assert!(!execute(16 * 1024, 101, "approveContractCall", 100));
+        assert!(!execute(16 * 1024, 100, "approveContractCall", 101));
+        assert!(!execute(16 * 1024, 100, &long_command, 100));
+
+        let execute_abi = |input_len: usize| decodes(14, vec![0u8; input_len].encode());
+        assert!(execute_abi(128 * 1024));
+        assert!(!execute_abi(128 * 1024 + 1));
+
+        // Bounded string arguments must still be UTF-8
+        assert_noop!(
+            AxelarGateway::call_contract(
+                RuntimeOrigin::signed(ALICE),
+                bounded_str(&chain),
+                bounded(vec![0xff]),
+                bounded(vec![])
+            ),
+            Error::<Runtime>::InvalidDestinationAddress,
+        );
+        assert_noop!(
+            AxelarGateway::execute(
+                RuntimeOrigin::signed(ALICE),
+                bounded(vec![]),
+                36,
+                bounded(vec![H256::random()]),
+                bounded(vec![bounded(vec![0xff])]),
+                bounded(vec![RuntimeCall::System(SystemCall::remark {
+                    remark: vec![]
+                })]),
+            ),
+            Error::<Runtime>::UnknownCommand,
+        );
+    });
+}
+
+#[test]
+fn pause_and_unpause() {
+    ExtBuilder::default().build().execute_with(|| {
+        let chain = String::from("ethereum");
+        let address = String::from("0x5f927395213ee6b95de97bddcb1b2b1c0f16844d");
+
+        assert_noop!(
+            AxelarGateway::pause(RuntimeOrigin::signed(ALICE), Traffic::Inbound),
+            BadOrigin,
+        );
+
+        assert_ok!(AxelarGateway::pause(
+            RuntimeOrigin::root(),
+            Traffic::Inbound
+        ));
+        event_exists(Event::<Runtime>::Paused {
+            traffic: Traffic::Inbound,
+        });
+        assert!(AxelarGateway::paused(Traffic::Inbound));
+        assert!(!AxelarGateway::paused(Traffic::Outbound));
+
+        assert_noop!(
+            AxelarGateway::execute(
+                RuntimeOrigin::signed(ALICE),
+                bounded(vec![]),
+                36,
+                bounded(vec![]),
+                command_names(vec![]),
+                bounded(vec![]),
+            ),
+            Error::<Runtime>::GatewayPaused,
+        );
+        assert_noop!(
+            AxelarGateway::forward_approved_call(
+                RuntimeOrigin::signed(ALICE),
+                H256::random(),
+                bounded_str(&chain),
+                bounded_str(&address),
+                H160::random(),
+                bounded(vec![]),
+            ),
+            Error::<Runtime>::GatewayPaused,
+        );
+        assert_eq!(
+            AxelarGateway::validate_batch(&[], 36, vec![], vec![], vec![]).error,
+            Some(Error::<Runtime>::GatewayPaused.into())
+        );
+        assert_ok!(AxelarGateway::call_contract(
+            RuntimeOrigin::signed(ALICE),
+            bounded_str(&chain),
+            bounded_str(&address),
+            bounded(vec![1])
+        ));
+
+        assert_ok!(AxelarGateway::pause(
+            RuntimeOrigin::root(),
+            Traffic::Outbound
+        ));
+        assert_noop!(
+            AxelarGateway::call_contract(
+                RuntimeOrigin::signed(ALICE),
+                bounded_str(&chain),
+                bounded_str(&address),
+                bounded(vec![1])
+            ),
+            Error::<Runtime>::GatewayPaused,
+        );
+
+        assert_noop!(
+            AxelarGateway::unpause(RuntimeOrigin::signed(ALICE), Traffic::Outbound),
+            BadOrigin,
+        );
+        assert_ok!(AxelarGateway::unpause(
+            RuntimeOrigin::root(),
+            Traffic::Outbound
+        ));
+        event_exists(Event::<Runtime>::Unpaused {
+            traffic: Traffic::Outbound,
+        });
+        assert!(!AxelarGateway::paused(Traffic::Outbound));
+        assert!(AxelarGateway::paused(Traffic::Inbound));
+        assert_ok!(AxelarGateway::call_contract(
+            RuntimeOrigin::signed(ALICE),
+            bounded_str(&chain),
+            bounded_str(&address),
+            bounded(vec![1])
+        ));
+
+        assert_ok!(AxelarGateway::unpause(
+            RuntimeOrigin::root(),
+            Traffic::Inbound
+        ));
+        assert_noop!(
+            AxelarGateway::execute(
+                RuntimeOrigin::signed(ALICE),
+                bounded(vec![]),
+                36,
+                bounded(vec![]),
+                command_names(vec![]),
+                bounded(vec![]),
+            ),
+            Error::<Runtime>::FailedToDecodeProof,
+        );
+    });
+}
+
+#[test]
+fn trusted_source_registry() {
+    ExtBuilder::default()
+        .with_source_chains(vec![], vec![])
+        .build()
+        .execute_with(|| {
+            let chain = String::from("moonbeam");
+            let address = String::from("0x5f927395213ee6b95de97bddcb1b2b1c0f16844d");
+            let other_address = String::from("0x0000000000000000000000000000000000000001");
+            let approve = |source_address: &String| {
+                AxelarGateway::approve_contract_call(
+                    RawOrigin::Bridge.into(),
+                    bounded_str(&chain),
+                    bounded_str(source_address),
+                    H160::random(),
+                    H256::random(),
+                    H256::random(),
+                    U256::from(100),
+                    H256::random(),
+                )
+            };
+
+            assert_noop!(approve(&address), Error::<Runtime>::UntrustedSourceChain);
+
+            assert_noop!(
+                AxelarGateway::add_source_chain(
+                    RuntimeOrigin::signed(ALICE),
+                    chain.clone(),
+                    AddressPolicy::Any
+                ),
+                BadOrigin,
+            );
+            assert_ok!(AxelarGateway::add_source_chain(
+                RuntimeOrigin::root(),
+                chain.clone(),
+                AddressPolicy::Any
+            ));
+            event_exists(Event::<Runtime>::SourceChainAdded {
+                chain: chain.clone(),
+                address_policy: AddressPolicy::Any,
+            });
+            assert_ok!(approve(&address));
+            assert_ok!(approve(&other_address));
+
+            // Restrict the chain to its registered addresses
+            assert_ok!(AxelarGateway::add_source_chain(
+                RuntimeOrigin::root(),
+                chain.clone(),
+                AddressPolicy::TrustedOnly
+            ));
+            assert_noop!(approve(&address), Error::<Runtime>::UntrustedSourceAddress);
+
+            assert_noop!(
+                AxelarGateway::add_source_address(
+                    RuntimeOrigin::signed(ALICE),
+                    chain.clone(),
+                    address.clone()
+                ),
+                BadOrigin,
+            );
+            assert_ok!(AxelarGateway::add_source_address(
+                RuntimeOrigin::root(),
+                chain.clone(),
+                address.clone()
+            ));
+            event_exists(Event::<Runtime>::SourceAddressAdded {
+                chain: chain.clone(),
+                address: address.clone(),
+            });
+            assert_ok!(approve(&address));
+            assert_noop!(
+                approve(&other_address),
+                Error::<Runtime>::UntrustedSourceAddress
+            );
+
+            // Approved while the chain is trusted, forwarded once it no longer is
+            let command_id = H256::random();
+            let contract_address = H160::random();
+            let call = RuntimeCall::AxelarGateway(AxelarGatewayCall::transfer_operatorship {
+                new_operators: vec![[1; 20]],
+                new_weights: vec![1],
+                new_threshold: 1,
+            })
+            .encode();
+            assert_ok!(AxelarGateway::approve_contract_call(
+                RawOrigin::Bridge.into(),
+                bounded_str(&chain),
+                bounded_str(&address),
+                contract_address,
+                H256(keccak_256(&call)),
+                H256::random(),
+                U256::from(100),
+                command_id,
+            ));
+
+            assert_ok!(AxelarGateway::remove_source_address(
+                RuntimeOrigin::root(),
+                chain.clone(),
+                address.clone()
+            ));
+            event_exists(Event::<Runtime>::SourceAddressRemoved {
+                chain: chain.clone(),
+                address: address.clone(),
+            });
+            assert_noop!(approve(&address), Error::<Runtime>::UntrustedSourceAddress);
+            assert_noop!(
+                AxelarGateway::remove_source_address(
+                    RuntimeOrigin::root(),
+                    chain.clone(),
+                    address.clone()
+                ),
+                Error::<Runtime>::UnknownSourceAddress,
+            );
+
+            assert_ok!(AxelarGateway::remove_source_chain(
+                RuntimeOrigin::root(),
+                chain.clone()
+            ));
+            event_exists(Event::<Runtime>::SourceChainRemoved {
+                chain: chain.clone(),
+            });
+            assert_noop!(approve(&address), Error::<Runtime>::UntrustedSourceChain);
+            assert_noop!(
+                AxelarGateway::remove_source_chain(RuntimeOrigin::root(), chain.clone()),
+                Error::<Runtime>::UnknownSourceChain,
+            );
+
+            assert_ok!(AxelarGateway::forward_approved_call(
+                RuntimeOrigin::signed(ALICE),
+                command_id,
+                bounded_str(&chain),
+                bounded_str(&address),
+                contract_address,
+                bounded(call),
+            ));
+            assert_eq!(AxelarGateway::current_epoch(), 1);
+        });
+}
+
+#[test]
+fn destination_chain_registry() {
+    ExtBuilder::default().build().execute_with(|| {
+        let chain = String::from("osmosis");
+        let address = String::from("osmo1qs4g4q9x4nx5e7t6qxqqdwwl4w6ngmnkh54nqc");
+        let call_contract = |destination_chain: &str, destination_contract_address: &str| {
+            AxelarGateway::call_contract(
+                RuntimeOrigin::signed(ALICE),
+                bounded_str(destination_chain),
+                bounded_str(destination_contract_address),
+                bounded(vec![1]),
+            )
+        };
+
+        assert_eq!(
+            AxelarGateway::destination_chain(AxelarGateway::chain_name("ethereum").unwrap()),
+            Some(AddressFormat::EvmHex)
+        );
+        assert_ok!(call_contract(
+            "ethereum",
+            "0x5f927395213ee6b95de97bddcb1b2b1c0f16844d"
+        ));
+        assert_noop!(
+            call_contract("ethereum", &address),
+            Error::<Runtime>::InvalidDestinationAddress
+        );
+        // Typo in the chain name
+        assert_noop!(
+            call_contract("etherum", "0x5f927395213ee6b95de97bddcb1b2b1c0f16844d"),
+            Error::<Runtime>::UnsupportedDestinationChain
+        );
+        assert_noop!(
+            call_contract(&chain, &address),
+            Error::<Runtime>::UnsupportedDestinationChain
+        );
+
+        assert_noop!(
+            AxelarGateway::add_destination_chain(
+                RuntimeOrigin::signed(ALICE),
+                chain.clone(),
+                AddressFormat::Bech32
+            ),
+            BadOrigin,
+        );
+        assert_ok!(AxelarGateway::add_destination_chain(
+            RuntimeOrigin::root(),
+            chain.clone(),
+            AddressFormat::Bech32
+        ));
+        event_exists(Event::<Runtime>::DestinationChainAdded {
+            chain: chain.clone(),
+            address_format: AddressFormat::Bech32,
+        });
+        assert_ok!(call_contract(&chain, &address));
+        assert_noop!(
+            call_contract(&chain, "0x5f927395213ee6b95de97bddcb1b2b1c0f16844d"),
+            Error::<Runtime>::InvalidDestinationAddress
+        );
+
+        assert_noop!(
+            AxelarGateway::remove_destination_chain(RuntimeOrigin::signed(ALICE), chain.clone()),
+            BadOrigin,
+        );
+        assert_ok!(AxelarGateway::remove_destination_chain(
+            RuntimeOrigin::root(),
+            chain.clone()
+        ));
+        event_exists(Event::<Runtime>::DestinationChainRemoved {
+            chain: chain.clone(),
+        });
+        assert_noop!(
+            call_contract(&chain, &address),
+            Error::<Runtime>::UnsupportedDestinationChain
+        );
+        assert_noop!(
+            AxelarGateway::remove_destination_chain(RuntimeOrigin::root(), chain),
+            Error::<Runtime>::UnknownDestinationChain,
+        );
+    });
+}
+
+#[test]
+fn outbound_message_queue() {
+    ExtBuilder::default().build().execute_with(|| {
+        let destination_chain = String::from("ethereum");
+        let destination_contract_address =
+            String::from("0x5f927395213ee6b95de97bddcb1b2b1c0f16844d");
+        let call_contract = |payload: Vec<u8>| {
+            AxelarGateway::call_contract(
+                RuntimeOrigin::signed(ALICE),
+                bounded_str(&destination_chain),
+                bounded_str(&destination_contract_address),
+                bounded(payload),
+            )
+        };
+
+        assert_ok!(call_contract(vec![0]));
+        assert_ok!(call_contract(vec![1]));
+        AxelarGateway::on_finalize(1);
+        System::set_block_number(2);
+        assert_ok!(call_contract(vec![2]));
+        AxelarGateway::on_finalize(2);
+        event_exists(Event::<Runtime>::ContractCall {
+            nonce: 2,
+            sender: ALICE,
+            destination_chain: destination_chain.clone(),
+            destination_contract_address: destination_contract_address.clone(),
+            payload_hash: H256::from(keccak_256(&[2])),
+            payload: vec![2],
+            fee: 0,
+        });
+
+        assert_eq!(AxelarGateway::next_outbound_nonce(), 3);
+        assert_eq!(
+            AxelarGateway::outbound_message(2),
+            Some(OutboundMessage {
+                sender: ALICE,
+                destination_chain: AxelarGateway::chain_name(&destination_chain).unwrap(),
+                destination_contract_address: AxelarGateway::address(&destination_contract_address)
+                    .unwrap(),
+                payload_hash: H256::from(keccak_256(&[2])),
+                block_number: 2,
+            })
+        );
+
+        // Paging
+        let nonces = |page: Vec<(u64, OutboundMessageOf<Runtime>)>| {
+            page.into_iter().map(|(nonce, _)| nonce).collect::<Vec<_>>()
+        };
+        assert_eq!(nonces(AxelarGateway::outbound_messages(0, 2)), vec![0, 1]);
+        assert_eq!(nonces(AxelarGateway::outbound_messages(2, 2)), vec![2]);
+        assert_eq!(
+            nonces(AxelarGateway::outbound_messages(3, 2)),
+            Vec::<u64>::new()
+        );
+        assert_eq!(
+            nonces(AxelarGateway::outbound_messages(0, 0)),
+            Vec::<u64>::new()
+        );
+
+        // Nothing expired yet
+        assert_eq!(
+            AxelarGateway::on_idle(100, Weight::MAX),
+            <() as WeightInfo>::prune_outbound_messages(0)
+        );
+        assert_eq!(AxelarGateway::oldest_outbound_nonce(), 0);
+
+        // Not enough weight left to prune anything
+        assert_eq!(
+            AxelarGateway::on_idle(101, <() as WeightInfo>::prune_outbound_messages(0)),
+            <() as WeightInfo>::prune_outbound_messages(0)
+        );
+        assert_eq!(AxelarGateway::on_idle(101, Weight::zero()), Weight::zero());
+        assert_eq!(AxelarGateway::oldest_outbound_nonce(), 0);
+
+        // Enough weight left for a single message
+        assert_eq!(
+            AxelarGateway::on_idle(101, <() as WeightInfo>::prune_outbound_messages(1)),
+            <() as WeightInfo>::prune_outbound_messages(1)
+        );
+        assert_eq!(AxelarGateway::oldest_outbound_nonce(), 1);
+        assert!(AxelarGateway::outbound_message(0).is_none());
+        assert!(AxelarGateway::outbound_commitment(1).is_some());
+        assert_eq!(nonces(AxelarGateway::outbound_messages(0, 2)), vec![1, 2]);
+
+        // Only the messages of block 1 are past the retention
+        AxelarGateway::on_idle(101, Weight::MAX);
+        assert_eq!(AxelarGateway::oldest_outbound_nonce(), 2);
+        assert!(AxelarGateway::outbound_message(1).is_none());
+        assert!(AxelarGateway::outbound_message(2).is_some());
+        assert!(AxelarGateway::outbound_commitment(1).is_none());
+        assert!(AxelarGateway::outbound_commitment(2).is_some());
+
+        OutboundRetention::set(&50);
+        AxelarGateway::on_idle(101, Weight::MAX);
+        assert_eq!(AxelarGateway::oldest_outbound_nonce(), 3);
+        assert_eq!(
+            nonces(AxelarGateway::outbound_messages(0, 10)),
+            Vec::<u64>::new()
+        );
+
+        // Nonces keep increasing after pruning, and messages are only pruned once committed
+        assert_ok!(call_contract(vec![3]));
+        AxelarGateway::on_idle(1_000, Weight::MAX);
+        assert_eq!(nonces(AxelarGateway::outbound_messages(0, 10)), vec![3]);
+    });
+}
+
+#[test]
+fn outbound_message_commitment() {
+    ExtBuilder::default().build().execute_with(|| {
+        let call_contract = |payload: Vec<u8>| {
+            AxelarGateway::call_contract(
+                RuntimeOrigin::signed(ALICE),
+                bounded_str("ethereum"),
+                bounded_str("0x5f927395213ee6b95de97bddcb1b2b1c0f16844d"),
+                bounded(payload),
+            )
+        };
+        let commitment_logs = || {
+            System::digest()
+                .logs
+                .iter()
+                .filter_map(|log| {
+                    log.consensus_try_to::<OutboundCommitment>(&OUTBOUND_COMMITMENT_ENGINE_ID)
+                })
+                .collect::<Vec<_>>()
+        };
+
+        // Nothing is committed for blocks without messages
+        AxelarGateway::on_finalize(1);
+        assert!(commitment_logs().is_empty());
+        assert!(AxelarGateway::outbound_commitment(1).is_none());
+
+        System::initialize(&2, &Default::default(), &Default::default());
+        assert_ok!(call_contract(vec![0]));
+        AxelarGateway::on_finalize(2);
+
+        System::initialize(&3, &Default::default(), &Default::default());
+        for payload in 1..4 {
+            assert_ok!(call_contract(vec![payload]));
+        }
+        // Not committed before the block is finalized
+        assert_eq!(AxelarGateway::outbound_message_proof(1), None);
+        AxelarGateway::on_finalize(3);
+
+        let commitment = OutboundCommitment {
+            first_nonce: 1,
+            message_count: 3,
+            root: merkle::merkle_root(
+                &(1..4)
+                    .map(|nonce| {
+                        merkle::leaf_hash(nonce, &AxelarGateway::outbound_message(nonce).unwrap())
+                    })
+                    .collect::<Vec<_>>(),
+            ),
+        };
+        assert_eq!(commitment_logs(), vec![commitment.clone()]);
+        assert_eq!(
+            AxelarGateway::outbound_commitment(3),
+            Some(commitment.clone())
+        );
+        assert_eq!(AxelarGateway::committed_outbound_nonce(), 4);
+
+        // Every message checks against the root of its block alone
+        for nonce in 1..4 {
+            let message = AxelarGateway::outbound_message(nonce).unwrap();
+            let proof = AxelarGateway::outbound_message_proof(nonce).unwrap();
+            assert_eq!(proof.leaf, merkle::leaf_hash(nonce, &message));
+            assert_eq!(proof.leaf_index as u64, nonce - commitment.first_nonce);
+            assert_eq!(proof.leaf_count, commitment.message_count);
+            assert!(merkle::verify_proof(
+                commitment.root,
+                proof.leaf,
+                proof.leaf_index,
+                proof.leaf_count,
+                &proof.proof
+            ));
+        }
+        let proof = AxelarGateway::outbound_message_proof(0).unwrap();
+        assert_eq!((proof.leaf_index, proof.leaf_count), (0, 1));
+        assert!(proof.proof.is_empty());
+        assert_eq!(AxelarGateway::outbound_message_proof(4), None);
+
+        // No proof once the messages of the block start being pruned
+        AxelarGateway::on_idle(103, <() as WeightInfo>::prune_outbound_messages(2));
+        assert_eq!(AxelarGateway::oldest_outbound_nonce(), 2);
+        assert_eq!(AxelarGateway::outbound_message_proof(2), None);
+    });
+}
+
+#[test]
+fn execute_abi_batch() {
+    ExtBuilder::default().build().execute_with(|| {
+        // Current operators
+        let operator = ecdsa::generate_keypair();
+        let operator_address = H160::from(H256::from_slice(keccak_256(&operator.0).as_slice()));
+        let operators_hash =
+            proof::operators_hash(vec![operator_address.to_fixed_bytes().into()], vec![1], 1);
+        CurrentEpoch::<Runtime>::set(1);
+        HashForEpoch::<Runtime>::set(1, operators_hash);
+        EpochForHash::<Runtime>::set(operators_hash, 1);
+        let sign = |data: &Vec<u8>| {
+            let msg_hash = H256::from_slice(&ecdsa::to_eth_signed_message_hash(keccak_256(data)));
+            abi::encode::input(
+                data.clone(),
+                proof::proof_tests::encode(
+                    vec![operator_address.to_fixed_bytes()],
+                    vec![1],
+                    1,
+                    vec![ecdsa::sign_message(msg_hash, &operator.1)],
+                ),
+            )
+        };
+
+        let source_address = "0x5f927395213ee6b95de97bddcb1b2b1c0f16844d";
+        let contract_address = H160::repeat_byte(1);
+        let payload_hash = H256::repeat_byte(2);
+        let approve = |source_chain: &str| {
+            abi::encode::approve_contract_call(
+                source_chain,
+                source_address,
+                contract_address,
+                payload_hash,
+                H256::repeat_byte(3),
+                U256::from(4),
+            )
+        };
+        let command_ids: Vec<H256> = (1..=5).map(H256::from_low_u64_be).collect();
+        let data = abi::encode::data(
+            36,
+            command_ids.clone(),
+            vec![
+                "approveContractCall",
+                "transferOperatorship",
+                "mintToken",
+                "approveContractCall",
+                "approveContractCall",
+            ],
+            vec![
+                approve("ethereum"),
+                abi::encode::transfer_operatorship(vec![[1; 20], [2; 20]], vec![1, 1], 2),
+                vec![],
+                approve("moonbeam"),
+                vec![1, 2, 3],
+            ],
+        );
+        let input = sign(&data);
+
+        assert_ok!(AxelarGateway::execute_abi(
+            RuntimeOrigin::signed(ALICE),
+            bounded(input.clone())
+        ));
+
+        assert!(AxelarGateway::is_contract_call_approved(
+            command_ids[0],
+            b"ethereum",
+            source_address.as_bytes(),
+            contract_address,
+            payload_hash,
+        ));
+        assert_eq!(AxelarGateway::current_epoch(), 2);
+        assert_eq!(
+            AxelarGateway::current_operators_hash(),
+            proof::operators_hash(vec![[1; 20].into(), [2; 20].into()], vec![1, 1], 2)
+        );
+        assert!(AxelarGateway::is_command_executed(command_ids[0]));
+        assert!(AxelarGateway::is_command_executed(command_ids[1]));
+        // Unknown commands fail
+        assert!(!AxelarGateway::is_command_executed(command_ids[2]));
+        event_exists(Event::<Runtime>::ItemFailed {
+            index: 2,
+            error: Error::<Runtime>::UnknownCommand.into(),
+        });
+        event_exists(Event::<Runtime>::ItemFailed {
+            index: 3,
+            error: Error::<Runtime>::UntrustedSourceChain.into(),
+        });
+        event_exists(Event::<Runtime>::ItemFailed {
+            index: 4,
+            error: Error::<Runtime>::FailedToDecodeCommandParams.into(),
+        });
+        assert!(!AxelarGateway::is_command_executed(command_ids[3]));
+        assert!(!AxelarGateway::is_command_executed(command_ids[4]));
+        event_exists(Event::<Runtime>::BatchCompletedWithErrors);
+
+        // Executed commands are skipped when the batch is replayed
+        System::reset_events();
+        assert_ok!(AxelarGateway::execute_abi(
+            RuntimeOrigin::signed(ALICE),
+            bounded(input)
+        ));
+        assert_eq!(AxelarGateway::current_epoch(), 2);
+        assert_eq!(
+            System::events()
+                .iter()
+                .filter(|e| matches!(e.event, RuntimeEvent::AxelarGateway(Event::ItemCompleted)))
+                .count(),
+            0
+        );
+
+        // Operatorship transfers only run when signed by the current operators
+        let data = abi::encode::data(
+            36,
+            vec![H256::from_low_u64_be(6)],
+            vec!["transferOperatorship"],
+            vec![abi::encode::transfer_operatorship(
+                vec![[3; 20]],
+                vec![1],
+                1,
+            )],
+        );
+        assert_ok!(AxelarGateway::execute_abi(
+            RuntimeOrigin::signed(ALICE),
+            bounded(sign(&data))
+        ));
+        assert_eq!(AxelarGateway::current_epoch(), 2);
+        assert!(!AxelarGateway::is_command_executed(H256::from_low_u64_be(
+            6
+        )));
+    });
+}
+
+#[test]
+fn forward_call_approved_by_abi_batch() {
+    ExtBuilder::default().build().execute_with(|| {
+        let operator = ecdsa::generate_keypair();
+        let operator_address = H160::from(H256::from_slice(keccak_256(&operator.0).as_slice()));
+        let operators_hash =
+            proof::operators_hash(vec![operator_address.to_fixed_bytes().into()], vec![1], 1);
+        CurrentEpoch::<Runtime>::set(1);
+        HashForEpoch::<Runtime>::set(1, operators_hash);
+        EpochForHash::<Runtime>::set(operators_hash, 1);
+
+        // The forwarded call approves another contract call, which the bridge origin may do
+        let inner_command_id = H256::repeat_byte(9);
+        let inner_call = RuntimeCall::AxelarGateway(AxelarGatewayCall::approve_contract_call {
+            source_chain: bounded_str("ethereum"),
+            source_address: bounded_str("0x5f927395213ee6b95de97bddcb1b2b1c0f16844d"),
+            contract_address: H160::repeat_byte(5),
+            payload_hash: H256::repeat_byte(6),
+            source_tx_hash: H256::repeat_byte(7),
+            source_event_index: U256::from(8),
+            command_id: inner_command_id,
+        });
+        let call = inner_call.encode();
+
+        // Approved under `keccak256(payload)`, as `validateContractCall` on EVM gateways
+        let command_id = H256::from_low_u64_be(1);
+        let source_address = "0x5f927395213ee6b95de97bddcb1b2b1c0f16844d";
+        let contract_address = H160::repeat_byte(1);
+        let data = abi::encode::data(
+            36,
+            vec![command_id],
+            vec!["approveContractCall"],
+            vec![abi::encode::approve_contract_call(
+                "ethereum",
+                source_address,
+                contract_address,
+                H256(keccak_256(&call)),
+                H256::repeat_byte(3),
+                U256::from(4),
+            )],
+        );
+        let msg_hash = H256::from_slice(&ecdsa::to_eth_signed_message_hash(keccak_256(&data)));
+        let input = abi::encode::input(
+            data,
+            proof::proof_tests::encode(
+                vec![operator_address.to_fixed_bytes()],
+                vec![1],
+                1,
+                vec![ecdsa::sign_message(msg_hash, &operator.1)],
+            ),
+        );
+        assert_ok!(AxelarGateway::execute_abi(
+            RuntimeOrigin::signed(ALICE),
+            bounded(input)
+        ));
+
+        assert_ok!(AxelarGateway::forward_approved_call(
+            RuntimeOrigin::signed(ALICE),
+            command_id,
+            bounded_str("ethereum"),
+            bounded_str(source_address),
+            contract_address,
+            bounded(call.clone()),
+        ));
+        assert!(AxelarGateway::is_contract_call_approved(
+            inner_command_id,
+            b"ethereum",
+            source_address.as_bytes(),
+            H160::repeat_byte(5),
+            H256::repeat_byte(6),
+        ));
+
+        // The approval is consumed
+        assert_noop!(
+            AxelarGateway::forward_approved_call(
+                RuntimeOrigin::signed(ALICE),
+                command_id,
+                bounded_str("ethereum"),
+                bounded_str(source_address),
+                contract_address,
+                bounded(call),
+            ),
+            Error::<Runtime>::ContractCallNotApproved
+        );
+    });
+}
+
+#[test]
+fn execute_abi_rejects_invalid_batches() {
+    ExtBuilder::default().build().execute_with(|| {
+        let operator = ecdsa::generate_keypair();
+        let operator_address = H160::from(H256::from_slice(keccak_256(&operator.0).as_slice()));
+        let operators_hash =
+            proof::operators_hash(vec![operator_address.to_fixed_bytes().into()], vec![1], 1);
+        CurrentEpoch::<Runtime>::set(1);
+        HashForEpoch::<Runtime>::set(1, operators_hash);
+        EpochForHash::<Runtime>::set(operators_hash, 1);
+        let sign = |signed: &Vec<u8>, data: &Vec<u8>| {
+            let msg_hash = H256::from_slice(&ecdsa::to_eth_signed_message_hash(keccak_256(signed)));
+            abi::encode::input(
+                data.clone(),
+                proof::proof_tests::encode(
+                    vec![operator_address.to_fixed_bytes()],
+                    vec![1],
+                    1,
+                    vec![ecdsa::sign_message(msg_hash, &operator.1)],
+                ),
+            )
+        };
+        let data = |chain_id: u32, commands: Vec<&str>| {
+            abi::encode::data(chain_id, vec![H256::repeat_byte(1)], commands, vec![vec![]])
+        };
+        let execute_abi = |input: Vec<u8>| {
+            AxelarGateway::execute_abi(RuntimeOrigin::signed(ALICE), bounded(input))
+        };
+
+        assert_noop!(
+            execute_abi(vec![1, 2, 3]),
+            Error::<Runtime>::FailedToDecodeInput
+        );
+        assert_noop!(
+            execute_abi(abi::encode::input(vec![1, 2, 3], vec![])),
+            Error::<Runtime>::FailedToDecodeInput
+        );
+
+        let wrong_chain = data(37, vec!["mintToken"]);
+        assert_noop!(
+            execute_abi(sign(&wrong_chain, &wrong_chain)),
+            Error::<Runtime>::WrongChainId
+        );
+
+        let mismatch = data(36, vec!["mintToken", "mintToken"]);
+        assert_noop!(
+            execute_abi(sign(&mismatch, &mismatch)),
+            Error::<Runtime>::CommandIdsLengthMismatch
+        );
+
+        // Signed over different data
+        let batch = data(36, vec!["mintToken"]);
+        assert_noop!(
+            execute_abi(sign(&data(36, vec!["burnToken"]), &batch)),
+            Error::<Runtime>::InvalidProof
+        );
+
+        assert_ok!(AxelarGateway::pause(
+            RuntimeOrigin::root(),
+            Traffic::Inbound
+        ));
+        assert_noop!(
+            execute_abi(sign(&batch, &batch)),
+            Error::<Runtime>::GatewayPaused
+        );
+        assert_ok!(AxelarGateway::unpause(
+            RuntimeOrigin::root(),
+            Traffic::Inbound
+        ));
+        assert_ok!(execute_abi(sign(&batch, &batch)));
     });
 }
@@ -7,12 +7,11 @@
 use std::marker::PhantomData;
 // Frame, system and frame primitives
 use crate::Error::ErrorForwarding;
-use crate::{pallet, Config};
+use crate::{pallet, proof, Config};
 use codec::Decode;
 use frame_support::dispatch::DispatchResult;
-use frame_support::weights::Weight;
 use frame_system::pallet_prelude::OriginFor;
-use sp_core::H160;
+use sp_core::{ed25519, keccak_256, sr25519, H160, H256};
 use sp_runtime::{traits::Dispatchable, DispatchError};
 use xcm::latest::prelude::*;
 use xcm::latest::Xcm;
@@ -22,42 +21,8 @@ use xcm::prelude::DescendOrigin;
 // Traits declaration
 // ----------------------------------------------------------------------------
 
-/// Weight information for pallet extrinsics
-///
-/// Weights are calculated using runtime benchmarking features.
-/// See [`benchmarking`] module for more information.
-pub trait WeightInfo {
-    fn execute(c: u32) -> Weight;
-    fn transfer_operatorship(c: u32) -> Weight;
-    fn approve_contract_call() -> Weight;
-    fn forward_approved_call() -> Weight;
-    fn call_contract() -> Weight;
-}
-
-// For backwards compatibility and tests
-impl WeightInfo for () {
-    fn execute(c: u32) -> Weight {
-        // Minimum execution time: 13_885 nanoseconds.
-        Weight::from_ref_time(20_147_978 as u64)
-            // Standard Error: 2_232
-            .saturating_add(Weight::from_ref_time(3_516_969 as u64).saturating_mul(c as u64))
-    }
-    fn transfer_operatorship(c: u32) -> Weight {
-        // Minimum execution time: 14_470 nanoseconds.
-        Weight::from_ref_time(17_443_346 as u64)
-            // Standard Error: 2_037
-            .saturating_add(Weight::from_ref_time(3_510_555 as u64).saturating_mul(c as u64))
-    }
-    fn approve_contract_call() -> Weight {
-        Weight::from_ref_time(17_443_346 as u64)
-    }
-    fn forward_approved_call() -> Weight {
-        Weight::from_ref_time(17_443_346 as u64)
-    }
-    fn call_contract() -> Weight {
-        Weight::from_ref_time(17_443_346 as u64)
-    }
-}
+// Kept here so runtimes importing it from `traits` keep compiling
+pub use crate::weights::WeightInfo;
 
 pub trait CallForwarder<T: pallet::Config> {
     fn is_local() -> bool;
@@ -166,3 +131,86 @@ impl<T: Config, XcmSender: SendXcm> CallForwarder<T> for RemoteCallForwarder<Xcm
         Ok(())
     }
 }
+
+/// Checks the signatures of the operators in proofs. Operators are identified by 20 bytes
+/// whatever their key type, see `key_to_operator`.
+pub trait SignatureVerifier {
+    /// Length of the signatures in proofs
+    fn signature_len() -> usize;
+
+    /// Operator whose key made `signature` over `msg_hash`, if the signature is valid
+    fn signer(msg_hash: &H256, signature: &[u8]) -> Option<[u8; 20]>;
+}
+
+/// Length of the `public ++ signature` of ed25519 and sr25519 operators
+pub const PUBLIC_SIGNATURE_LEN: usize = 96;
+
+/// Operator identifier of a public key, the last 20 bytes of its keccak256 hash as for
+/// Ethereum addresses
+pub fn key_to_operator(public: &[u8]) -> [u8; 20] {
+    H160::from(H256(keccak_256(public))).to_fixed_bytes()
+}
+
+/// Recovers the secp256k1 ECDSA signatures `r ++ s ++ v` of Ethereum keys, as Axelar operators use
+pub struct Secp256k1Verifier;
+impl SignatureVerifier for Secp256k1Verifier {
+    fn signature_len() -> usize {
+        proof::SIGNATURE_LEN
+    }
+
+    fn signer(msg_hash: &H256, signature: &[u8]) -> Option<[u8; 20]> {
+        ecdsa::recover(*msg_hash, signature.to_vec())
+            .ok()
+            .map(|signer| signer.0)
+    }
+}
+
+/// Verifies the ed25519 signatures of operators, carried as `public ++ signature`
+pub struct Ed25519Verifier;
+impl SignatureVerifier for Ed25519Verifier {
+    fn signature_len() -> usize {
+        PUBLIC_SIGNATURE_LEN
+    }
+
+    fn signer(msg_hash: &H256, signature: &[u8]) -> Option<[u8; 20]> {
+        let (public, signature) = split_public(signature)?;
+
+        sp_io::crypto::ed25519_verify(
+            &ed25519::Signature::from_raw(signature),
+            msg_hash.as_bytes(),
+            &ed25519::Public::from_raw(public),
+        )
+        .then(|| key_to_operator(&public))
+    }
+}
+
+/// Verifies the sr25519 signatures of operators, carried as `public ++ signature`
+pub struct Sr25519Verifier;
+impl SignatureVerifier for Sr25519Verifier {
+    fn signature_len() -> usize {
+        PUBLIC_SIGNATURE_LEN
+    }
+
+    fn signer(msg_hash: &H256, signature: &[u8]) -> Option<[u8; 20]> {
+        let (public, signature) = split_public(signature)?;
+
+        sp_io::crypto::sr25519_verify(
+            &sr25519::Signature::from_raw(signature),
+            msg_hash.as_bytes(),
+            &sr25519::Public::from_raw(public),
+        )
+        .then(|| key_to_operator(&public))
+    }
+}
+
+/// Splits a `public ++ signature` into the 32 bytes public key and the 64 bytes signature.
+fn split_public(signature: &[u8]) -> Option<([u8; 32], [u8; 64])> {
+    if signature.len() != PUBLIC_SIGNATURE_LEN {
+        return None;
+    }
+
+    Some((
+        signature[..32].try_into().ok()?,
+        signature[32..].try_into().ok()?,
+    ))
+}
@@ -0,0 +1,246 @@
+//! Weights for axelar_cgp
+//!
+//! These are round placeholders, not measurements: no runtime including the pallet has been
+//! benchmarked yet. They only charge the storage accesses of each extrinsic and an order of
+//! magnitude of its computation. Runtimes should replace them with the output of
+//! `benchmark pallet` over the benchmarks in [`crate::benchmarking`].
+
+use frame_support::traits::Get;
+use frame_support::weights::{constants::RocksDbWeight, Weight};
+use sp_std::marker::PhantomData;
+
+/// Weight functions needed for axelar_cgp.
+///
+/// The implementations in this module are round placeholders, see the module docs.
+pub trait WeightInfo {
+    fn execute(c: u32, s: u32, o: u32, b: u32) -> Weight;
+    fn execute_abi(c: u32, s: u32, o: u32, n: u32) -> Weight;
+    fn validate_proof(s: u32, o: u32) -> Weight;
+    fn transfer_operatorship(o: u32) -> Weight;
+    fn force_set_operators(o: u32) -> Weight;
+    fn approve_contract_call(p: u32) -> Weight;
+    fn forward_approved_call(p: u32) -> Weight;
+    fn call_contract(p: u32) -> Weight;
+    fn pause() -> Weight;
+    fn unpause() -> Weight;
+    fn add_source_chain() -> Weight;
+    fn remove_source_chain() -> Weight;
+    fn add_source_address() -> Weight;
+    fn remove_source_address() -> Weight;
+    fn add_destination_chain() -> Weight;
+    fn remove_destination_chain() -> Weight;
+    fn commit_outbound_messages(n: u32) -> Weight;
+    fn prune_outbound_messages(n: u32) -> Weight;
+}
+
+/// Placeholder weights for axelar_cgp, with the storage accesses charged at the runtime
+/// `DbWeight`.
+pub struct SubstrateWeight<T>(PhantomData<T>);
+impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
+    fn execute(c: u32, s: u32, o: u32, b: u32) -> Weight {
+        Weight::from_ref_time(20_000_000)
+            .saturating_add(Weight::from_ref_time(5_000_000).saturating_mul(c as u64))
+            .saturating_add(Weight::from_ref_time(50_000_000).saturating_mul(s as u64))
+            .saturating_add(Weight::from_ref_time(250_000).saturating_mul(o as u64))
+            .saturating_add(Weight::from_ref_time(5_000).saturating_mul(b as u64))
+            .saturating_add(T::DbWeight::get().reads(3))
+            .saturating_add(T::DbWeight::get().reads(c as u64))
+            .saturating_add(T::DbWeight::get().writes(2u64.saturating_mul(c as u64)))
+    }
+    fn execute_abi(c: u32, s: u32, o: u32, n: u32) -> Weight {
+        Weight::from_ref_time(25_000_000)
+            .saturating_add(Weight::from_ref_time(25_000_000).saturating_mul(c as u64))
+            .saturating_add(Weight::from_ref_time(50_000_000).saturating_mul(s as u64))
+            .saturating_add(Weight::from_ref_time(250_000).saturating_mul(o as u64))
+            .saturating_add(Weight::from_ref_time(5_000).saturating_mul(n as u64))
+            .saturating_add(T::DbWeight::get().reads(3))
+            .saturating_add(T::DbWeight::get().reads(3u64.saturating_mul(c as u64)))
+            .saturating_add(T::DbWeight::get().writes(2u64.saturating_mul(c as u64)))
+    }
+    fn validate_proof(s: u32, o: u32) -> Weight {
+        Weight::from_ref_time(15_000_000)
+            .saturating_add(Weight::from_ref_time(50_000_000).saturating_mul(s as u64))
+            .saturating_add(Weight::from_ref_time(250_000).saturating_mul(o as u64))
+            .saturating_add(T::DbWeight::get().reads(2))
+    }
+    fn transfer_operatorship(o: u32) -> Weight {
+        Weight::from_ref_time(25_000_000)
+            .saturating_add(Weight::from_ref_time(5_000_000).saturating_mul(o as u64))
+            .saturating_add(T::DbWeight::get().reads(4))
+            .saturating_add(T::DbWeight::get().writes(6))
+    }
+    fn force_set_operators(o: u32) -> Weight {
+        Weight::from_ref_time(40_000_000)
+            .saturating_add(Weight::from_ref_time(5_000_000).saturating_mul(o as u64))
+            .saturating_add(T::DbWeight::get().reads(4))
+            .saturating_add(T::DbWeight::get().writes(6))
+    }
+    fn approve_contract_call(p: u32) -> Weight {
+        Weight::from_ref_time(25_000_000)
+            .saturating_add(Weight::from_ref_time(2_000).saturating_mul(p as u64))
+            .saturating_add(T::DbWeight::get().reads(2))
+            .saturating_add(T::DbWeight::get().writes(1))
+    }
+    fn forward_approved_call(p: u32) -> Weight {
+        Weight::from_ref_time(25_000_000)
+            .saturating_add(Weight::from_ref_time(5_000).saturating_mul(p as u64))
+            .saturating_add(T::DbWeight::get().reads(3))
+            .saturating_add(T::DbWeight::get().writes(2))
+    }
+    fn call_contract(p: u32) -> Weight {
+        Weight::from_ref_time(50_000_000)
+            .saturating_add(Weight::from_ref_time(2_000).saturating_mul(p as u64))
+            .saturating_add(T::DbWeight::get().reads(5))
+            .saturating_add(T::DbWeight::get().writes(4))
+    }
+    fn pause() -> Weight {
+        Weight::from_ref_time(15_000_000).saturating_add(T::DbWeight::get().writes(1))
+    }
+    fn unpause() -> Weight {
+        Weight::from_ref_time(15_000_000).saturating_add(T::DbWeight::get().writes(1))
+    }
+    fn add_source_chain() -> Weight {
+        Weight::from_ref_time(15_000_000).saturating_add(T::DbWeight::get().writes(1))
+    }
+    fn remove_source_chain() -> Weight {
+        Weight::from_ref_time(20_000_000)
+            .saturating_add(T::DbWeight::get().reads(1))
+            .saturating_add(T::DbWeight::get().writes(1))
+    }
+    fn add_source_address() -> Weight {
+        Weight::from_ref_time(15_000_000).saturating_add(T::DbWeight::get().writes(1))
+    }
+    fn remove_source_address() -> Weight {
+        Weight::from_ref_time(20_000_000)
+            .saturating_add(T::DbWeight::get().reads(1))
+            .saturating_add(T::DbWeight::get().writes(1))
+    }
+    fn add_destination_chain() -> Weight {
+        Weight::from_ref_time(15_000_000).saturating_add(T::DbWeight::get().writes(1))
+    }
+    fn remove_destination_chain() -> Weight {
+        Weight::from_ref_time(20_000_000)
+            .saturating_add(T::DbWeight::get().reads(1))
+            .saturating_add(T::DbWeight::get().writes(1))
+    }
+    fn commit_outbound_messages(n: u32) -> Weight {
+        Weight::from_ref_time(5_000_000)
+            .saturating_add(Weight::from_ref_time(10_000_000).saturating_mul(n as u64))
+            .saturating_add(T::DbWeight::get().reads(3))
+            .saturating_add(T::DbWeight::get().reads(n as u64))
+            .saturating_add(T::DbWeight::get().writes(3))
+    }
+    fn prune_outbound_messages(n: u32) -> Weight {
+        Weight::from_ref_time(10_000_000)
+            .saturating_add(Weight::from_ref_time(10_000_000).saturating_mul(n as u64))
+            .saturating_add(T::DbWeight::get().reads(3))
+            .saturating_add(T::DbWeight::get().reads(2u64.saturating_mul(n as u64)))
+            .saturating_add(T::DbWeight::get().writes(1))
+            .saturating_add(T::DbWeight::get().writes(2u64.saturating_mul(n as u64)))
+    }
+}
+
+// For backwards compatibility and tests
+impl WeightInfo for () {
+    fn execute(c: u32, s: u32, o: u32, b: u32) -> Weight {
+        Weight::from_ref_time(20_000_000)
+            .saturating_add(Weight::from_ref_time(5_000_000).saturating_mul(c as u64))
+            .saturating_add(Weight::from_ref_time(50_000_000).saturating_mul(s as u64))
+            .saturating_add(Weight::from_ref_time(250_000).saturating_mul(o as u64))
+            .saturating_add(Weight::from_ref_time(5_000).saturating_mul(b as u64))
+            .saturating_add(RocksDbWeight::get().reads(3))
+            .saturating_add(RocksDbWeight::get().reads(c as u64))
+            .saturating_add(RocksDbWeight::get().writes(2u64.saturating_mul(c as u64)))
+    }
+    fn execute_abi(c: u32, s: u32, o: u32, n: u32) -> Weight {
+        Weight::from_ref_time(25_000_000)
+            .saturating_add(Weight::from_ref_time(25_000_000).saturating_mul(c as u64))
+            .saturating_add(Weight::from_ref_time(50_000_000).saturating_mul(s as u64))
+            .saturating_add(Weight::from_ref_time(250_000).saturating_mul(o as u64))
+            .saturating_add(Weight::from_ref_time(5_000).saturating_mul(n as u64))
+            .saturating_add(RocksDbWeight::get().reads(3))
+            .saturating_add(RocksDbWeight::get().reads(3u64.saturating_mul(c as u64)))
+            .saturating_add(RocksDbWeight::get().writes(2u64.saturating_mul(c as u64)))
+    }
+    fn validate_proof(s: u32, o: u32) -> Weight {
+        Weight::from_ref_time(15_000_000)
+            .saturating_add(Weight::from_ref_time(50_000_000).saturating_mul(s as u64))
+            .saturating_add(Weight::from_ref_time(250_000).saturating_mul(o as u64))
+            .saturating_add(RocksDbWeight::get().reads(2))
+    }
+    fn transfer_operatorship(o: u32) -> Weight {
+        Weight::from_ref_time(25_000_000)
+            .saturating_add(Weight::from_ref_time(5_000_000).saturating_mul(o as u64))
+            .saturating_add(RocksDbWeight::get().reads(4))
+            .saturating_add(RocksDbWeight::get().writes(6))
+    }
+    fn force_set_operators(o: u32) -> Weight {
+        Weight::from_ref_time(40_000_000)
+            .saturating_add(Weight::from_ref_time(5_000_000).saturating_mul(o as u64))
+            .saturating_add(RocksDbWeight::get().reads(4))
+            .saturating_add(RocksDbWeight::get().writes(6))
+    }
+    fn approve_contract_call(p: u32) -> Weight {
+        Weight::from_ref_time(25_000_000)
+            .saturating_add(Weight::from_ref_time(2_000).saturating_mul(p as u64))
+            .saturating_add(RocksDbWeight::get().reads(2))
+            .saturating_add(RocksDbWeight::get().writes(1))
+    }
+    fn forward_approved_call(p: u32) -> Weight {
+        Weight::from_ref_time(25_000_000)
+            .saturating_add(Weight::from_ref_time(5_000).saturating_mul(p as u64))
+            .saturating_add(RocksDbWeight::get().reads(3))
+            .saturating_add(RocksDbWeight::get().writes(2))
+    }
+    fn call_contract(p: u32) -> Weight {
+        Weight::from_ref_time(50_000_000)
+            .saturating_add(Weight::from_ref_time(2_000).saturating_mul(p as u64))
+            .saturating_add(RocksDbWeight::get().reads(5))
+            .saturating_add(RocksDbWeight::get().writes(4))
+    }
+    fn pause() -> Weight {
+        Weight::from_ref_time(15_000_000).saturating_add(RocksDbWeight::get().writes(1))
+    }
+    fn unpause() -> Weight {
+        Weight::from_ref_time(15_000_000).saturating_add(RocksDbWeight::get().writes(1))
+    }
+    fn add_source_chain() -> Weight {
+        Weight::from_ref_time(15_000_000).saturating_add(RocksDbWeight::get().writes(1))
+    }
+    fn remove_source_chain() -> Weight {
+        Weight::from_ref_time(20_000_000)
+            .saturating_add(RocksDbWeight::get().reads(1))
+            .saturating_add(RocksDbWeight::get().writes(1))
+    }
+    fn add_source_address() -> Weight {
+        Weight::from_ref_time(15_000_000).saturating_add(RocksDbWeight::get().writes(1))
+    }
+    fn remove_source_address() -> Weight {
+        Weight::from_ref_time(20_000_000)
+            .saturating_add(RocksDbWeight::get().reads(1))
+            .saturating_add(RocksDbWeight::get().writes(1))
+    }
+    fn add_destination_chain() -> Weight {
+        Weight::from_ref_time(15_000_000).saturating_add(RocksDbWeight::get().writes(1))
+    }
+    fn remove_destination_chain() -> Weight {
+        Weight::from_ref_time(20_000_000)
+            .saturating_add(RocksDbWeight::get().reads(1))
+            .saturating_add(RocksDbWeight::get().writes(1))
+    }
+    fn commit_outbound_messages(n: u32) -> Weight {
+        Weight::from_ref_time(5_000_000)
+            .saturating_add(Weight::from_ref_time(10_000_000).saturating_mul(n as u64))
+            .saturating_add(RocksDbWeight::get().reads(3))
+            .saturating_add(RocksDbWeight::get().reads(n as u64))
+            .saturating_add(RocksDbWeight::get().writes(3))
+    }
+    fn prune_outbound_messages(n: u32) -> Weight {
+        Weight::from_ref_time(10_000_000)
+            .saturating_add(Weight::from_ref_time(10_000_000).saturating_mul(n as u64))
+            .saturating_add(RocksDbWeight::get().reads(3))
+            .saturating_add(RocksDbWeight::get().reads(2u64.saturating_mul(n as u64)))
+            .saturating_add(RocksDbWeight::get().writes(1))
+            .saturating_add(RocksDbWeight::get().writes(2u64.saturating_mul(n as u64)))
+    }
+}